@@ -0,0 +1,142 @@
+//! Evolutionary breeding of sounds
+//!
+//! Children are generated from the parents by crossover of complete sections
+//! followed by a mutation of the resulting values. Kept children become the
+//! parents of the next generation. The mutation only varies the values, the
+//! constraints of the randomizer are not applied to bred sounds.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::params::{GetValue, SoundParameterValues, SoundSection};
+use crate::randomizer;
+
+/// Number of children per generation
+pub const POPULATION_SIZE: usize = 8;
+
+/// Minimum number of parents needed for breeding
+pub const MIN_PARENTS: usize = 2;
+
+/// A sound taking part in the breeding
+#[derive(Debug, Clone)]
+pub struct Individual {
+    /// Name shown in the user interface and used when saving
+    pub name: String,
+
+    /// Sound parameters
+    pub values: SoundParameterValues,
+
+    /// Preset dump the sound is based on, holding the device settings not bred
+    pub dump: Vec<u8>,
+}
+
+/// A child of the current generation
+#[derive(Debug, Clone)]
+pub struct Child {
+    /// The generated sound
+    pub individual: Individual,
+
+    /// Flag for a child kept as parent of the next generation
+    pub keep: bool,
+}
+
+/// State of the breeding
+pub struct Breeder {
+    /// Parents of the current generation
+    pub parents: Vec<Individual>,
+
+    /// Children of the current generation
+    pub children: Vec<Child>,
+
+    /// Amount of mutation in percent
+    pub mutation: i32,
+
+    /// Number of the current generation
+    pub generation: u32,
+
+    /// Index of the child currently loaded into the device
+    pub auditioned: Option<usize>,
+}
+
+impl Breeder {
+    /// Constructs a new instance
+    pub fn new() -> Self {
+        Self {
+            parents: Vec::new(),
+            children: Vec::new(),
+            mutation: 10,
+            generation: 0,
+            auditioned: None,
+        }
+    }
+
+    /// Return true if there are enough parents for breeding
+    pub fn can_breed(&self) -> bool {
+        self.parents.len() >= MIN_PARENTS
+    }
+
+    /// Return true if enough children are kept for the next generation
+    pub fn can_advance(&self) -> bool {
+        self.children.iter().filter(|child| child.keep).count() >= MIN_PARENTS
+    }
+
+    /// Generate a new population from the parents, does nothing with too few parents
+    pub fn breed(&mut self, rng: &mut impl Rng) {
+        if !self.can_breed() {
+            return;
+        }
+
+        self.generation += 1;
+        self.auditioned = None;
+        self.children = (0..POPULATION_SIZE)
+            .map(|index| {
+                let mut values = SoundParameterValues::with_capacity(128);
+
+                // Settings not bred are taken over from one of the parents
+                let base = self.parents.choose(rng).unwrap();
+
+                // Crossover of complete sections keeps related parameters together
+                for section in SoundSection::ALL {
+                    let parent = self.parents.choose(rng).unwrap();
+                    for param in section.params() {
+                        values.insert(param, parent.values.get_value(param));
+                    }
+                }
+
+                if self.mutation > 0 {
+                    values = randomizer::mutate(&values, self.mutation, rng);
+                }
+
+                Child {
+                    individual: Individual {
+                        name: format!("Gen {} Child {}", self.generation, index + 1),
+                        values,
+                        dump: base.dump.clone(),
+                    },
+                    keep: false,
+                }
+            })
+            .collect();
+    }
+
+    /// Make the kept children the parents and generate the next population
+    ///
+    /// Returns false if fewer than `MIN_PARENTS` children are kept.
+    pub fn next_generation(&mut self, rng: &mut impl Rng) -> bool {
+        if !self.can_advance() {
+            return false;
+        }
+
+        let kept: Vec<Individual> = self
+            .children
+            .iter()
+            .filter(|child| child.keep)
+            .map(|child| child.individual.clone())
+            .collect();
+
+        self.parents = kept;
+        self.breed(rng);
+
+        true
+    }
+}
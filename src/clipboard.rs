@@ -0,0 +1,57 @@
+//! Section-level copy and swap operations
+//!
+//! All operations return the resulting sound, which is then loaded into
+//! the edit buffer of the part.
+
+use crate::params::{GetValue, SoundParameterValues, SoundSection};
+
+/// Return the sound with the section copied to its twin
+///
+/// - `values`      Sound parameters of the part
+/// - `section`     Source section
+pub fn copy_to_twin(values: &SoundParameterValues, section: SoundSection) -> SoundParameterValues {
+    let mut result = values.clone();
+
+    for (param, twin) in section.twin_params() {
+        result.insert(twin, values.get_value(param));
+    }
+
+    result
+}
+
+/// Return the sound with the section and its twin exchanged
+///
+/// - `values`      Sound parameters of the part
+/// - `section`     One of the sections to be exchanged
+pub fn swap_with_twin(
+    values: &SoundParameterValues,
+    section: SoundSection,
+) -> SoundParameterValues {
+    let mut result = values.clone();
+
+    for (param, twin) in section.twin_params() {
+        result.insert(twin, values.get_value(param));
+        result.insert(param, values.get_value(twin));
+    }
+
+    result
+}
+
+/// Return the target sound with a section taken from the source sound
+///
+/// - `source`      Sound parameters the section is copied from
+/// - `target`      Sound parameters the section is copied to
+/// - `section`     Copied section
+pub fn copy_section(
+    source: &SoundParameterValues,
+    target: &SoundParameterValues,
+    section: SoundSection,
+) -> SoundParameterValues {
+    let mut result = target.clone();
+
+    for param in section.params() {
+        result.insert(param, source.get_value(param));
+    }
+
+    result
+}
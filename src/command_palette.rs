@@ -0,0 +1,232 @@
+//! Command palette searching parameters, sections, pages and editor actions
+//!
+//! The query is matched word by word, each word must appear in the name as
+//! a sequence of characters in the same order. A trailing value like in
+//! `cutoff 200` is applied to the best matching parameter.
+
+use crate::key_bindings::KeyAction;
+use crate::params::{MultiParameter, SoundParameter, SoundSection};
+use crate::ui::elements::page_list::Page;
+use crate::units;
+
+/// Maximum number of results shown
+const MAX_RESULTS: usize = 12;
+
+/// Editor actions offered in the palette
+const ACTIONS: [KeyAction; 9] = [
+    KeyAction::Part1,
+    KeyAction::Part2,
+    KeyAction::Part3,
+    KeyAction::Part4,
+    KeyAction::UpdateFromDevice,
+    KeyAction::LoadSysexFile,
+    KeyAction::SavePresetSysexFile,
+    KeyAction::Undo,
+    KeyAction::Redo,
+];
+
+/// Entry that can be found in the palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteItem {
+    Sound(SoundParameter),
+    Multi(MultiParameter),
+    Section(SoundSection),
+    Page(Page),
+    Action(KeyAction),
+}
+
+impl PaletteItem {
+    /// Return all entries
+    fn all() -> Vec<PaletteItem> {
+        SoundParameter::ALL
+            .iter()
+            .map(|param| PaletteItem::Sound(*param))
+            .chain(
+                MultiParameter::ALL
+                    .iter()
+                    .map(|param| PaletteItem::Multi(*param)),
+            )
+            .chain(
+                SoundSection::ALL
+                    .iter()
+                    .map(|section| PaletteItem::Section(*section)),
+            )
+            .chain(Page::ALL.iter().map(|page| PaletteItem::Page(*page)))
+            .chain(ACTIONS.iter().map(|action| PaletteItem::Action(*action)))
+            .collect()
+    }
+
+    /// Return the name the query is matched against
+    pub fn get_name(&self) -> String {
+        match self {
+            PaletteItem::Sound(param) => param.get_name().to_string(),
+            PaletteItem::Multi(param) => param.get_name().to_string(),
+            PaletteItem::Section(section) => format!("{} section", section),
+            PaletteItem::Page(page) => format!("{} page", page),
+            PaletteItem::Action(action) => action.get_name().to_string(),
+        }
+    }
+
+    /// Return the kind of the entry
+    pub fn get_kind(&self) -> &'static str {
+        match self {
+            PaletteItem::Sound(_) => "Sound parameter",
+            PaletteItem::Multi(_) => "Multi parameter",
+            PaletteItem::Section(_) => "Section",
+            PaletteItem::Page(_) => "Page",
+            PaletteItem::Action(_) => "Action",
+        }
+    }
+
+    /// Return the raw value of a text in the unit of a parameter
+    fn parse_value(&self, text: &str) -> Option<i32> {
+        match *self {
+            PaletteItem::Sound(param) => units::parse_sound_value(param, text),
+            PaletteItem::Multi(param) => units::parse_multi_value(param, text),
+            _ => None,
+        }
+    }
+
+    /// Return a value in the unit of a parameter
+    pub fn format_value(&self, value: i32) -> String {
+        match *self {
+            PaletteItem::Sound(param) => units::format_sound_value(param, value),
+            PaletteItem::Multi(param) => units::format_multi_value(param, value),
+            _ => value.to_string(),
+        }
+    }
+}
+
+/// Search result
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaletteMatch {
+    pub item: PaletteItem,
+
+    /// Value to be set, `None` to jump to the item only
+    pub value: Option<i32>,
+}
+
+/// State of the open palette
+#[derive(Debug, Clone, Default)]
+pub struct CommandPalette {
+    /// Entered search text
+    pub query: String,
+
+    /// Results of the query, best match first
+    pub matches: Vec<PaletteMatch>,
+
+    /// Index of the highlighted result
+    pub selected: usize,
+}
+
+impl CommandPalette {
+    /// Change the query and update the results
+    pub fn set_query(&mut self, query: String) {
+        self.matches = search(&query);
+        self.selected = 0;
+        self.query = query;
+    }
+
+    /// Move the highlight up or down, wrapping around at the ends
+    pub fn move_selection(&mut self, offset: isize) {
+        if !self.matches.is_empty() {
+            let count = self.matches.len() as isize;
+            self.selected = (self.selected as isize + offset).rem_euclid(count) as usize;
+        }
+    }
+
+    /// Return the highlighted result
+    pub fn selected_match(&self) -> Option<PaletteMatch> {
+        self.matches.get(self.selected).copied()
+    }
+}
+
+/// Return the results of a query, best match first
+///
+/// If the whole query does not match anything, it is split into a name and
+/// a value at each word boundary. The first split with a value valid for the
+/// best matching parameter is used.
+fn search(query: &str) -> Vec<PaletteMatch> {
+    let items = PaletteItem::all();
+    let words: Vec<&str> = query.split_whitespace().collect();
+
+    if words.is_empty() {
+        return Vec::new();
+    }
+
+    let matches = rank(&items, &words);
+    if !matches.is_empty() {
+        return matches
+            .into_iter()
+            .map(|item| PaletteMatch { item, value: None })
+            .collect();
+    }
+
+    for split in 1..words.len() {
+        let value_text = words[split..].join(" ");
+        let found: Vec<PaletteMatch> = rank(&items, &words[..split])
+            .into_iter()
+            .filter_map(|item| {
+                item.parse_value(&value_text).map(|value| PaletteMatch {
+                    item,
+                    value: Some(value),
+                })
+            })
+            .collect();
+
+        if !found.is_empty() {
+            return found;
+        }
+    }
+
+    Vec::new()
+}
+
+/// Return the items matching all words, best match first
+fn rank(items: &[PaletteItem], words: &[&str]) -> Vec<PaletteItem> {
+    let mut scored: Vec<(i32, PaletteItem)> = items
+        .iter()
+        .filter_map(|item| {
+            let name = item.get_name().to_lowercase();
+            words
+                .iter()
+                .map(|word| fuzzy_score(&word.to_lowercase(), &name))
+                .sum::<Option<i32>>()
+                .map(|score| (score, *item))
+        })
+        .collect();
+
+    // The sort is stable, items with equal scores keep their order
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored.truncate(MAX_RESULTS);
+
+    scored.into_iter().map(|(_, item)| item).collect()
+}
+
+/// Return the score of a word found as subsequence of a name, `None` if not found
+///
+/// Characters following the previous match and characters at the start of a
+/// word in the name score higher.
+fn fuzzy_score(word: &str, name: &str) -> Option<i32> {
+    let name: Vec<char> = name.chars().collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous: Option<usize> = None;
+
+    for c in word.chars() {
+        let found = position + name[position..].iter().position(|n| *n == c)?;
+
+        score += 1;
+        if previous.map_or(false, |p| p + 1 == found) {
+            score += 3;
+        }
+        if found == 0 || name[found - 1] == ' ' {
+            score += 5;
+        }
+
+        previous = Some(found);
+        position = found + 1;
+    }
+
+    Some(score)
+}
@@ -0,0 +1,150 @@
+//! A/B slots for comparing two versions of a sound
+//!
+//! The edit buffer of a part always represents the active slot, the other
+//! slot holds the values stored when switching away from it. Starting a
+//! comparison stores the sound in slot A and continues editing in slot B, so
+//! A keeps the sound as it was.
+
+use crate::params::{GetValue, SoundParameter, SoundParameterValues};
+
+/// Identifier of a compare slot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slot {
+    /// Sound as it was when the comparison started
+    A,
+
+    /// Sound edited during the comparison
+    B,
+}
+
+impl std::fmt::Display for Slot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Slot::A => "A",
+                Slot::B => "B",
+            }
+        )
+    }
+}
+
+/// Compare slots of a single part
+#[derive(Debug, Clone)]
+pub struct CompareSlots {
+    /// Stored values of slot A, `None` if not stored yet
+    a: Option<SoundParameterValues>,
+
+    /// Stored values of slot B, `None` if not stored yet
+    b: Option<SoundParameterValues>,
+
+    /// Slot represented by the edit buffer
+    active: Slot,
+}
+
+impl CompareSlots {
+    /// Constructs a new instance with slot A active
+    pub fn new() -> Self {
+        Self {
+            a: None,
+            b: None,
+            active: Slot::A,
+        }
+    }
+
+    /// Return the slot represented by the edit buffer
+    pub fn active(&self) -> Slot {
+        self.active
+    }
+
+    /// Return if a comparison has been started
+    pub fn is_started(&self) -> bool {
+        self.a.is_some() || self.b.is_some()
+    }
+
+    /// Start a comparison by storing the edit buffer in slot A and activating slot B
+    ///
+    /// Does nothing if a comparison has already been started.
+    ///
+    /// - `current`     Values of the edit buffer
+    pub fn start(&mut self, current: &SoundParameterValues) {
+        if !self.is_started() {
+            self.a = Some(current.clone());
+            self.active = Slot::B;
+        }
+    }
+
+    /// Return the values of a slot
+    ///
+    /// - `slot`        Requested slot
+    /// - `current`     Values of the edit buffer
+    pub fn values(&self, slot: Slot, current: &SoundParameterValues) -> SoundParameterValues {
+        if slot == self.active {
+            return current.clone();
+        }
+
+        let stored = match slot {
+            Slot::A => &self.a,
+            Slot::B => &self.b,
+        };
+
+        stored.clone().unwrap_or_else(|| current.clone())
+    }
+
+    /// Switch to another slot and return the values to be loaded into the edit buffer
+    ///
+    /// An empty slot is initialized with the values of the edit buffer.
+    ///
+    /// - `slot`        Slot to be activated
+    /// - `current`     Values of the edit buffer
+    pub fn switch(
+        &mut self,
+        slot: Slot,
+        current: &SoundParameterValues,
+    ) -> Option<SoundParameterValues> {
+        self.start(current);
+        if slot == self.active {
+            return None;
+        }
+
+        let values = self.values(slot, current);
+
+        match self.active {
+            Slot::A => self.a = Some(current.clone()),
+            Slot::B => self.b = Some(current.clone()),
+        }
+        self.active = slot;
+
+        Some(values)
+    }
+
+    /// Copy slot A to slot B and return the values to be loaded into the edit
+    /// buffer if slot B is active
+    ///
+    /// - `current`     Values of the edit buffer
+    pub fn copy_a_to_b(&mut self, current: &SoundParameterValues) -> Option<SoundParameterValues> {
+        self.start(current);
+        let values = self.values(Slot::A, current);
+        self.b = Some(values.clone());
+
+        match self.active {
+            Slot::A => None,
+            Slot::B => Some(values),
+        }
+    }
+
+    /// Return all parameters that differ between the slots with the values of A and B
+    ///
+    /// - `current`     Values of the edit buffer
+    pub fn differences(&self, current: &SoundParameterValues) -> Vec<(SoundParameter, i32, i32)> {
+        let a = self.values(Slot::A, current);
+        let b = self.values(Slot::B, current);
+
+        SoundParameter::ALL
+            .iter()
+            .map(|param| (*param, a.get_value(*param), b.get_value(*param)))
+            .filter(|(_, value_a, value_b)| value_a != value_b)
+            .collect()
+    }
+}
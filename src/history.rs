@@ -0,0 +1,256 @@
+//! Undo/redo history of parameter edits
+//!
+//! Every edit is recorded as a step containing the values before and after
+//! the change. Consecutive changes of the same parameters are collapsed into
+//! a single step until the step is closed, e.g. when a slider is released.
+//! Edits made by a single action, like a choice from a list or a wheel step,
+//! close their step right away.
+
+use crate::params::{
+    GetValue, MultiParameter, MultiParameterValues, SoundParameter, SoundParameterValues,
+};
+
+/// Maximum number of steps kept in the history
+const MAX_STEPS: usize = 200;
+
+/// A single change of the editor state
+#[derive(Debug, Clone)]
+pub enum Change {
+    /// Change of a sound parameter of a part
+    Sound {
+        /// Part id 0-3
+        part_id: u8,
+
+        /// Changed parameter
+        param: SoundParameter,
+
+        /// Value before the change
+        before: i32,
+
+        /// Value after the change
+        after: i32,
+    },
+
+    /// Change of a multi parameter
+    Multi {
+        /// Changed parameter
+        param: MultiParameter,
+
+        /// Value before the change
+        before: i32,
+
+        /// Value after the change
+        after: i32,
+    },
+
+    /// Replacement of the complete sound of a part, e.g. by loading a file
+    Preset {
+        /// Part id 0-3
+        part_id: u8,
+
+        /// Preset dump of the part before the change
+        before: Vec<u8>,
+
+        /// Preset dump of the part after the change
+        after: Vec<u8>,
+    },
+
+    /// Replacement of all multi parameters, e.g. by loading a file
+    MultiDump {
+        /// Values before the change
+        before: MultiParameterValues,
+
+        /// Values after the change
+        after: MultiParameterValues,
+    },
+}
+
+impl Change {
+    /// Return the changes of the sound parameters differing between two sounds of a part
+    ///
+    /// - `part_id`     Part id 0-3
+    /// - `before`      Sound before the change
+    /// - `after`       Sound after the change
+    pub fn sound_diff(
+        part_id: u8,
+        before: &SoundParameterValues,
+        after: &SoundParameterValues,
+    ) -> Vec<Change> {
+        SoundParameter::ALL
+            .iter()
+            .filter_map(|param| {
+                let before = before.get_value(*param);
+                let after = after.get_value(*param);
+                (before != after).then_some(Change::Sound {
+                    part_id,
+                    param: *param,
+                    before,
+                    after,
+                })
+            })
+            .collect()
+    }
+
+    /// Return true if both changes affect the same parameter
+    fn same_target(&self, other: &Change) -> bool {
+        match (self, other) {
+            (
+                Change::Sound {
+                    part_id: part_a,
+                    param: param_a,
+                    ..
+                },
+                Change::Sound {
+                    part_id: part_b,
+                    param: param_b,
+                    ..
+                },
+            ) => part_a == part_b && param_a == param_b,
+            (Change::Multi { param: param_a, .. }, Change::Multi { param: param_b, .. }) => {
+                param_a == param_b
+            }
+            _ => false,
+        }
+    }
+
+    /// Take over the value after the change from another change
+    fn merge(&mut self, other: Change) {
+        match (self, other) {
+            (Change::Sound { after, .. }, Change::Sound { after: other, .. }) => *after = other,
+            (Change::Multi { after, .. }, Change::Multi { after: other, .. }) => *after = other,
+            _ => {}
+        }
+    }
+
+    /// Return the change with before and after values exchanged
+    pub fn inverted(&self) -> Change {
+        match self.clone() {
+            Change::Sound {
+                part_id,
+                param,
+                before,
+                after,
+            } => Change::Sound {
+                part_id,
+                param,
+                before: after,
+                after: before,
+            },
+            Change::Multi {
+                param,
+                before,
+                after,
+            } => Change::Multi {
+                param,
+                before: after,
+                after: before,
+            },
+            Change::Preset {
+                part_id,
+                before,
+                after,
+            } => Change::Preset {
+                part_id,
+                before: after,
+                after: before,
+            },
+            Change::MultiDump { before, after } => Change::MultiDump {
+                before: after,
+                after: before,
+            },
+        }
+    }
+}
+
+/// Stack of undoable and redoable steps
+pub struct History {
+    /// Steps that can be undone, most recent last
+    undo_steps: Vec<Vec<Change>>,
+
+    /// Steps that can be redone, most recent last
+    redo_steps: Vec<Vec<Change>>,
+
+    /// Flag for the most recent step accepting further changes
+    open: bool,
+}
+
+impl History {
+    /// Constructs a new instance
+    pub fn new() -> Self {
+        Self {
+            undo_steps: Vec::new(),
+            redo_steps: Vec::new(),
+            open: false,
+        }
+    }
+
+    /// Record a step consisting of one or more changes
+    ///
+    /// If the most recent step is still open and affects the same parameters,
+    /// the changes are collapsed into it.
+    pub fn record(&mut self, changes: Vec<Change>) {
+        if changes.is_empty() {
+            return;
+        }
+
+        self.redo_steps.clear();
+
+        if self.open {
+            if let Some(last_step) = self.undo_steps.last_mut() {
+                let collapsible = last_step.len() == changes.len()
+                    && last_step
+                        .iter()
+                        .zip(changes.iter())
+                        .all(|(last, change)| last.same_target(change));
+                if collapsible {
+                    for (last, change) in last_step.iter_mut().zip(changes) {
+                        last.merge(change);
+                    }
+                    return;
+                }
+            }
+        }
+
+        if self.undo_steps.len() >= MAX_STEPS {
+            self.undo_steps.remove(0);
+        }
+
+        self.undo_steps.push(changes);
+        self.open = true;
+    }
+
+    /// Close the most recent step, so that further changes start a new one
+    pub fn close_step(&mut self) {
+        self.open = false;
+    }
+
+    /// Return the changes to be applied for undoing the most recent step
+    pub fn undo(&mut self) -> Option<Vec<Change>> {
+        let step = self.undo_steps.pop()?;
+        let changes = step.iter().rev().map(Change::inverted).collect();
+        self.redo_steps.push(step);
+        self.open = false;
+
+        Some(changes)
+    }
+
+    /// Return the changes to be applied for redoing the most recently undone step
+    pub fn redo(&mut self) -> Option<Vec<Change>> {
+        let step = self.redo_steps.pop()?;
+        let changes = step.clone();
+        self.undo_steps.push(step);
+        self.open = false;
+
+        Some(changes)
+    }
+
+    /// Return true if there is a step that can be undone
+    pub fn can_undo(&self) -> bool {
+        !self.undo_steps.is_empty()
+    }
+
+    /// Return true if there is a step that can be redone
+    pub fn can_redo(&self) -> bool {
+        !self.redo_steps.is_empty()
+    }
+}
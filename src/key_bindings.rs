@@ -0,0 +1,277 @@
+//! Keyboard shortcuts of the editor
+//!
+//! Shortcuts are written as key names joined with `+`, for example `Ctrl+Shift+Z`.
+//! `Ctrl` stands for the command key on macOS. Several shortcuts of an action are
+//! separated by commas. Bindings in the config file replace the defaults of their
+//! actions, all other actions keep their defaults.
+
+use std::collections::HashMap;
+
+use iced_native::keyboard::{KeyCode, Modifiers};
+use serde::{Deserialize, Serialize};
+
+/// Action triggered by a shortcut
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum KeyAction {
+    /// Focus the next parameter of the sound page
+    FocusNext,
+
+    /// Focus the previous parameter of the sound page
+    FocusPrevious,
+
+    /// Remove the focus
+    FocusClear,
+
+    /// Increase the focused parameter by a normal step
+    Increment,
+
+    /// Decrease the focused parameter by a normal step
+    Decrement,
+
+    /// Increase the focused parameter by one
+    IncrementFine,
+
+    /// Decrease the focused parameter by one
+    DecrementFine,
+
+    /// Increase the focused parameter by a large step
+    IncrementCoarse,
+
+    /// Decrease the focused parameter by a large step
+    DecrementCoarse,
+
+    /// Set the focused parameter to its default value
+    ResetValue,
+
+    Part1,
+    Part2,
+    Part3,
+    Part4,
+
+    /// Request all parameters from the device
+    UpdateFromDevice,
+
+    /// Load a syx file
+    LoadSysexFile,
+
+    /// Save the current preset to a syx file
+    SavePresetSysexFile,
+
+    Undo,
+    Redo,
+
+    /// Open the command palette
+    CommandPalette,
+}
+
+impl KeyAction {
+    pub const ALL: [KeyAction; 20] = [
+        KeyAction::FocusNext,
+        KeyAction::FocusPrevious,
+        KeyAction::FocusClear,
+        KeyAction::Increment,
+        KeyAction::Decrement,
+        KeyAction::IncrementFine,
+        KeyAction::DecrementFine,
+        KeyAction::IncrementCoarse,
+        KeyAction::DecrementCoarse,
+        KeyAction::ResetValue,
+        KeyAction::Part1,
+        KeyAction::Part2,
+        KeyAction::Part3,
+        KeyAction::Part4,
+        KeyAction::UpdateFromDevice,
+        KeyAction::LoadSysexFile,
+        KeyAction::SavePresetSysexFile,
+        KeyAction::Undo,
+        KeyAction::Redo,
+        KeyAction::CommandPalette,
+    ];
+
+    /// Return the default shortcuts
+    pub fn default_shortcuts(&self) -> &'static str {
+        match self {
+            KeyAction::FocusNext => "Tab",
+            KeyAction::FocusPrevious => "Shift+Tab",
+            KeyAction::FocusClear => "Escape",
+            KeyAction::Increment => "Up, Right",
+            KeyAction::Decrement => "Down, Left",
+            KeyAction::IncrementFine => "Shift+Up, Shift+Right",
+            KeyAction::DecrementFine => "Shift+Down, Shift+Left",
+            KeyAction::IncrementCoarse => "PageUp",
+            KeyAction::DecrementCoarse => "PageDown",
+            KeyAction::ResetValue => "Delete",
+            KeyAction::Part1 => "Ctrl+1",
+            KeyAction::Part2 => "Ctrl+2",
+            KeyAction::Part3 => "Ctrl+3",
+            KeyAction::Part4 => "Ctrl+4",
+            KeyAction::UpdateFromDevice => "Ctrl+U",
+            KeyAction::LoadSysexFile => "Ctrl+O",
+            KeyAction::SavePresetSysexFile => "Ctrl+S",
+            KeyAction::Undo => "Ctrl+Z",
+            KeyAction::Redo => "Ctrl+Shift+Z, Ctrl+Y",
+            KeyAction::CommandPalette => "Ctrl+K",
+        }
+    }
+
+    /// Return the name shown in the command palette
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            KeyAction::FocusNext => "Focus next control",
+            KeyAction::FocusPrevious => "Focus previous control",
+            KeyAction::FocusClear => "Clear focus",
+            KeyAction::Increment => "Increase value",
+            KeyAction::Decrement => "Decrease value",
+            KeyAction::IncrementFine => "Increase value by one",
+            KeyAction::DecrementFine => "Decrease value by one",
+            KeyAction::IncrementCoarse => "Increase value by a large step",
+            KeyAction::DecrementCoarse => "Decrease value by a large step",
+            KeyAction::ResetValue => "Reset value",
+            KeyAction::Part1 => "Select part 1",
+            KeyAction::Part2 => "Select part 2",
+            KeyAction::Part3 => "Select part 3",
+            KeyAction::Part4 => "Select part 4",
+            KeyAction::UpdateFromDevice => "Update from device",
+            KeyAction::LoadSysexFile => "Load sysex file",
+            KeyAction::SavePresetSysexFile => "Save preset to sysex file",
+            KeyAction::Undo => "Undo",
+            KeyAction::Redo => "Redo",
+            KeyAction::CommandPalette => "Command palette",
+        }
+    }
+
+    /// Return the shortcuts in effect
+    ///
+    /// - `bindings`    Shortcuts replacing the defaults of their actions
+    pub fn get_shortcuts<'a>(&self, bindings: &'a HashMap<KeyAction, String>) -> &'a str {
+        bindings
+            .get(self)
+            .map_or(self.default_shortcuts(), String::as_str)
+    }
+}
+
+/// Names of the keys usable in shortcuts
+const KEY_NAMES: [(&str, KeyCode); 57] = [
+    ("0", KeyCode::Key0),
+    ("1", KeyCode::Key1),
+    ("2", KeyCode::Key2),
+    ("3", KeyCode::Key3),
+    ("4", KeyCode::Key4),
+    ("5", KeyCode::Key5),
+    ("6", KeyCode::Key6),
+    ("7", KeyCode::Key7),
+    ("8", KeyCode::Key8),
+    ("9", KeyCode::Key9),
+    ("A", KeyCode::A),
+    ("B", KeyCode::B),
+    ("C", KeyCode::C),
+    ("D", KeyCode::D),
+    ("E", KeyCode::E),
+    ("F", KeyCode::F),
+    ("G", KeyCode::G),
+    ("H", KeyCode::H),
+    ("I", KeyCode::I),
+    ("J", KeyCode::J),
+    ("K", KeyCode::K),
+    ("L", KeyCode::L),
+    ("M", KeyCode::M),
+    ("N", KeyCode::N),
+    ("O", KeyCode::O),
+    ("P", KeyCode::P),
+    ("Q", KeyCode::Q),
+    ("R", KeyCode::R),
+    ("S", KeyCode::S),
+    ("T", KeyCode::T),
+    ("U", KeyCode::U),
+    ("V", KeyCode::V),
+    ("W", KeyCode::W),
+    ("X", KeyCode::X),
+    ("Y", KeyCode::Y),
+    ("Z", KeyCode::Z),
+    ("F1", KeyCode::F1),
+    ("F2", KeyCode::F2),
+    ("F3", KeyCode::F3),
+    ("F4", KeyCode::F4),
+    ("F5", KeyCode::F5),
+    ("F6", KeyCode::F6),
+    ("F7", KeyCode::F7),
+    ("F8", KeyCode::F8),
+    ("Tab", KeyCode::Tab),
+    ("Escape", KeyCode::Escape),
+    ("Enter", KeyCode::Enter),
+    ("Space", KeyCode::Space),
+    ("Delete", KeyCode::Delete),
+    ("Home", KeyCode::Home),
+    ("End", KeyCode::End),
+    ("PageUp", KeyCode::PageUp),
+    ("PageDown", KeyCode::PageDown),
+    ("Up", KeyCode::Up),
+    ("Down", KeyCode::Down),
+    ("Left", KeyCode::Left),
+    ("Right", KeyCode::Right),
+];
+
+/// A key with the required modifiers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Shortcut {
+    key: KeyCode,
+    ctrl: bool,
+    shift: bool,
+    alt: bool,
+}
+
+impl Shortcut {
+    /// Parse a shortcut like `Ctrl+Shift+Z`, `None` for unknown key names, which are ignored
+    fn parse(s: &str) -> Option<Self> {
+        let (mut ctrl, mut shift, mut alt) = (false, false, false);
+        let mut key = None;
+
+        for part in s.split('+').map(str::trim) {
+            match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "cmd" => ctrl = true,
+                "shift" => shift = true,
+                "alt" => alt = true,
+                _ => {
+                    key = KEY_NAMES
+                        .iter()
+                        .find(|(name, _)| name.eq_ignore_ascii_case(part))
+                        .map(|(_, code)| *code);
+                }
+            }
+        }
+
+        Some(Shortcut {
+            key: key?,
+            ctrl,
+            shift,
+            alt,
+        })
+    }
+
+    /// Return true if the shortcut matches a key press
+    fn matches(&self, key: KeyCode, modifiers: Modifiers) -> bool {
+        self.key == key
+            && self.ctrl == modifiers.command()
+            && self.shift == modifiers.shift()
+            && self.alt == modifiers.alt()
+    }
+}
+
+/// Return the action of a key press
+///
+/// - `key`         Pressed key
+/// - `modifiers`   Modifier keys held down
+/// - `bindings`    Shortcuts replacing the defaults of their actions
+pub fn find_action(
+    key: KeyCode,
+    modifiers: Modifiers,
+    bindings: &HashMap<KeyAction, String>,
+) -> Option<KeyAction> {
+    KeyAction::ALL.iter().copied().find(|action| {
+        action
+            .get_shortcuts(bindings)
+            .split(',')
+            .filter_map(Shortcut::parse)
+            .any(|shortcut| shortcut.matches(key, modifiers))
+    })
+}
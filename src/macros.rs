@@ -0,0 +1,196 @@
+//! User-defined macro controls driving several sound parameters at once
+//!
+//! The macros are stored in a sidecar file next to a preset syx file, so that
+//! they are restored together with the sound.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::params::SoundParameter;
+
+/// Maximum macro value, matching the range of a MIDI CC
+pub const MAX_VALUE: i32 = 127;
+
+/// Highest CC number available for macros, the channel mode messages are excluded
+const MAX_CC: u8 = 119;
+
+/// Mapping of the macro value to the target range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MacroCurve {
+    #[default]
+    Linear,
+
+    /// Slow start, fast end
+    Exponential,
+
+    /// Fast start, slow end
+    Logarithmic,
+}
+
+impl MacroCurve {
+    pub const ALL: [MacroCurve; 3] = [
+        MacroCurve::Linear,
+        MacroCurve::Exponential,
+        MacroCurve::Logarithmic,
+    ];
+
+    /// Map a position 0.0-1.0 to the curve
+    fn apply(&self, position: f32) -> f32 {
+        match self {
+            MacroCurve::Linear => position,
+            MacroCurve::Exponential => position * position,
+            MacroCurve::Logarithmic => position.sqrt(),
+        }
+    }
+}
+
+impl std::fmt::Display for MacroCurve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                MacroCurve::Linear => "Linear",
+                MacroCurve::Exponential => "Exponential",
+                MacroCurve::Logarithmic => "Logarithmic",
+            }
+        )
+    }
+}
+
+/// CC number assigned to a macro, `None` if the macro is not controlled via MIDI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct MacroCc(pub Option<u8>);
+
+impl MacroCc {
+    /// Return all possible assignments
+    pub fn all() -> Vec<MacroCc> {
+        std::iter::once(MacroCc(None))
+            .chain((0..=MAX_CC).map(|cc| MacroCc(Some(cc))))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for MacroCc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(cc) => write!(f, "CC {}", cc),
+            None => write!(f, "No CC"),
+        }
+    }
+}
+
+/// A sound parameter driven by a macro
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroTarget {
+    /// Driven parameter
+    pub param: SoundParameter,
+
+    /// Parameter value at macro value 0
+    pub min: i32,
+
+    /// Parameter value at the maximum macro value, may be lower than `min`
+    pub max: i32,
+
+    /// Mapping of the macro value to the range
+    pub curve: MacroCurve,
+}
+
+impl MacroTarget {
+    /// Constructs a new target covering the full range of the parameter
+    pub fn new(param: SoundParameter) -> Self {
+        let range = param.get_range();
+
+        Self {
+            param,
+            min: *range.start(),
+            max: *range.end(),
+            curve: MacroCurve::default(),
+        }
+    }
+
+    /// Change the driven parameter and reset the range
+    pub fn set_param(&mut self, param: SoundParameter) {
+        *self = Self {
+            curve: self.curve,
+            ..Self::new(param)
+        };
+    }
+
+    /// Return the parameter value for a macro value
+    pub fn value(&self, macro_value: i32) -> i32 {
+        let position = self
+            .curve
+            .apply(macro_value.clamp(0, MAX_VALUE) as f32 / MAX_VALUE as f32);
+
+        self.min + ((self.max - self.min) as f32 * position).round() as i32
+    }
+}
+
+/// A macro control
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Macro {
+    /// Name shown in the macro strip
+    pub name: String,
+
+    /// Current value 0-`MAX_VALUE`
+    pub value: i32,
+
+    /// CC number controlling the macro via the merge input
+    pub cc: MacroCc,
+
+    /// Driven parameters
+    pub targets: Vec<MacroTarget>,
+}
+
+impl Macro {
+    /// Constructs a new macro without targets
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            value: 0,
+            cc: MacroCc::default(),
+            targets: Vec::new(),
+        }
+    }
+
+    /// Return the values of all targets for the current macro value
+    pub fn target_values(&self) -> Vec<(SoundParameter, i32)> {
+        self.targets
+            .iter()
+            .map(|target| (target.param, target.value(self.value)))
+            .collect()
+    }
+}
+
+/// All macros of a sound
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MacroSet {
+    pub macros: Vec<Macro>,
+}
+
+impl MacroSet {
+    /// Return the sidecar file belonging to a syx file
+    pub fn sidecar_path(syx_file: impl AsRef<Path>) -> PathBuf {
+        syx_file.as_ref().with_extension("macros.ron")
+    }
+
+    /// Load the macros from the sidecar file of a syx file
+    pub fn load(syx_file: impl AsRef<Path>) -> Result<Self, Box<dyn std::error::Error>> {
+        let s = std::fs::read_to_string(Self::sidecar_path(syx_file))?;
+        Ok(ron::from_str(s.as_str())?)
+    }
+
+    /// Save the macros to the sidecar file of a syx file
+    pub fn save(&self, syx_file: impl AsRef<Path>) -> Result<(), Box<dyn std::error::Error>> {
+        let s = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        std::fs::write(Self::sidecar_path(syx_file), s)?;
+        Ok(())
+    }
+
+    /// Return the index of the macro assigned to a CC number
+    pub fn find_cc(&self, cc: u8) -> Option<usize> {
+        self.macros.iter().position(|m| m.cc == MacroCc(Some(cc)))
+    }
+}
@@ -3,11 +3,25 @@
 #![windows_subsystem = "windows"]
 #![warn(missing_docs)]
 
+mod breeding;
+mod clipboard;
+mod command_palette;
+mod compare;
+mod history;
+mod key_bindings;
+mod macros;
 mod messages;
 mod midi;
+mod modulation;
+mod morph;
+mod param_docs;
 mod params;
+mod randomizer;
 mod ui;
+mod units;
+mod xy_pad;
 
+use std::collections::{HashMap, HashSet};
 use std::sync::mpsc;
 use std::time::{Duration, Instant};
 
@@ -15,17 +29,53 @@ use iced::widget::{Column, Container, PickList, Row, Text};
 use iced::{
     executor, time, Alignment, Application, Command, Element, Length, Settings, Subscription,
 };
+use iced_native::keyboard;
 use serde::{Deserialize, Serialize};
 use simple_logger::SimpleLogger;
-use tinyfiledialogs::{open_file_dialog, save_file_dialog_with_filter};
-
+use tinyfiledialogs::{open_file_dialog, open_file_dialog_multi, save_file_dialog_with_filter};
+
+use breeding::{Breeder, Individual};
+use command_palette::{CommandPalette, PaletteItem, PaletteMatch};
+use compare::CompareSlots;
+use history::{Change, History};
+use key_bindings::KeyAction;
+use macros::{Macro, MacroSet, MacroTarget};
 use messages::Message;
-use midi::MidiConnector;
-use params::{GetValue, MultiParameterValues, SoundParameterValues};
+use midi::monitor::MonitorFilter;
+use midi::sync::{DeviceSync, Request};
+use midi::trace::{Trace, TracePlayer};
+use midi::virtual_device::VirtualDevice;
+use midi::{Emulation, MidiConnector};
+use morph::{Morph, MorphPoint, MorphSource};
+use params::{
+    GetValue, MultiParameter, SoundParameter, SoundParameterValues, SoundSection, PART_COUNT,
+    PRESET_COUNT,
+};
+use randomizer::Randomizer;
+use ui::breeding::BreedingPanel;
+use ui::command_palette::{self as palette_ui, CommandPalettePanel};
+use ui::compare::ComparePanel;
+use ui::elements::control_style_list::ControlStyle;
+use ui::elements::link_mode_list::{LinkMode, PartLink};
+use ui::elements::page_list::Page;
+use ui::elements::value_entry::{self, ValueEntry, ValueTarget};
+use ui::layout::LayoutPanel;
+use ui::locks::LocksPanel;
+use ui::macros::MacrosPanel;
 use ui::manager::ManagerPanel;
+use ui::mod_matrix::ModMatrixPanel;
+use ui::monitor::MonitorPanel;
+use ui::morph::MorphPanel;
 use ui::multi::MultiPanel;
+use ui::overview::OverviewPanel;
+use ui::randomizer::RandomizerPanel;
+use ui::sections::SectionsPanel;
 use ui::sound::SoundPanel;
 use ui::style;
+use ui::theme::{self, EditorTheme};
+use ui::toolbar::ToolbarPanel;
+use ui::xy_pad::XYPadPanel;
+use xy_pad::{XYPad, XYTarget};
 
 /// Application name used for file path of persistent storage.
 const APP_NAME: &str = env!("CARGO_PKG_NAME");
@@ -37,6 +87,12 @@ fn main() -> iced::Result {
         .init()
         .unwrap();
 
+    let options = Options::from_args(std::env::args().skip(1));
+
+    if let Some(file) = options.check_trace_file {
+        std::process::exit(check_trace(&file));
+    }
+
     let settings = Settings {
         window: iced::window::Settings {
             size: (style::WINDOW_WIDTH, style::WINDOW_HEIGHT),
@@ -46,17 +102,94 @@ fn main() -> iced::Result {
             ..iced::window::Settings::default()
         },
         exit_on_close_request: false,
+        flags: options,
         ..Settings::default()
     };
 
     EditorApp::run(settings)
 }
 
+/// Options passed on the command line.
+#[derive(Debug, Default)]
+struct Options {
+    /// Trace file to be replayed in place of the device, `--replay FILE`.
+    replay_file: Option<String>,
+
+    /// Use the in-memory device in place of the hardware, `--virtual-device`.
+    virtual_device: bool,
+
+    /// Trace file to be checked against the in-memory device, `--check-trace FILE`.
+    check_trace_file: Option<String>,
+}
+
+impl Options {
+    /// Parses the command line arguments.
+    fn from_args(mut args: impl Iterator<Item = String>) -> Self {
+        let mut options = Self::default();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--replay" => options.replay_file = args.next(),
+                "--virtual-device" => options.virtual_device = true,
+                "--check-trace" => options.check_trace_file = args.next(),
+                _ => log::error!("Unknown argument {}", arg),
+            }
+        }
+
+        options
+    }
+}
+
+/// Replays a trace against the in-memory device and returns the process exit code.
+fn check_trace(file: &str) -> i32 {
+    let trace = match Trace::load(file) {
+        Ok(trace) => trace,
+        Err(error) => {
+            log::error!("Loading trace {} failed: {}", file, error);
+            return 2;
+        }
+    };
+
+    let mismatches = midi::trace::check_against_device(&trace, &mut VirtualDevice::new());
+
+    for mismatch in mismatches.iter() {
+        log::error!("{}", mismatch);
+    }
+
+    if mismatches.is_empty() {
+        log::info!("Trace {} replayed without mismatches", file);
+        0
+    } else {
+        log::error!(
+            "Trace {} replayed with {} mismatches",
+            file,
+            mismatches.len()
+        );
+        1
+    }
+}
+
 /// Persistent state saved between launches.
 #[derive(Debug, Serialize, Deserialize, Default)]
 struct AppState {
     /// Name of the merge input port.
     merge_input_name: String,
+
+    /// Parameters keeping their value when a preset is loaded.
+    #[serde(default)]
+    preset_locks: HashSet<SoundParameter>,
+
+    /// Control style of the sound sections, sliders if missing.
+    #[serde(default)]
+    control_styles: HashMap<SoundSection, ControlStyle>,
+
+    /// Keyboard shortcuts replacing the defaults of their actions.
+    #[serde(default)]
+    key_bindings: HashMap<KeyAction, String>,
+
+    /// Name of the selected theme, the default theme if empty or unknown.
+    #[serde(default)]
+    theme: String,
 }
 
 /// Holds the application data and state
@@ -73,6 +206,66 @@ struct EditorApp {
     /// UI section containing global controls
     manager_panel: ManagerPanel,
 
+    /// UI section containing the page selection and editing tools
+    toolbar_panel: ToolbarPanel,
+
+    /// UI section showing the key parameters of all parts
+    overview_panel: OverviewPanel,
+
+    /// UI section listing the differences between the A/B compare slots
+    compare_panel: ComparePanel,
+
+    /// UI section showing the modulation routings
+    mod_matrix_panel: ModMatrixPanel,
+
+    /// UI section containing the copy and swap operations
+    sections_panel: SectionsPanel,
+
+    /// UI section containing the parameter locks
+    locks_panel: LocksPanel,
+
+    /// UI section selecting the control style of the sound sections
+    layout_panel: LayoutPanel,
+
+    /// UI section containing the macro strip and editor
+    macros_panel: MacrosPanel,
+
+    /// UI section containing the randomizer settings
+    randomizer_panel: RandomizerPanel,
+
+    /// UI section for morphing between two sounds
+    morph_panel: MorphPanel,
+
+    /// UI section containing the XY pad
+    xy_pad_panel: XYPadPanel,
+
+    /// UI section for breeding sounds
+    breeding_panel: BreedingPanel,
+
+    /// UI section showing the MIDI traffic
+    monitor_panel: MonitorPanel,
+
+    /// UI section containing the command palette
+    command_palette_panel: CommandPalettePanel,
+
+    /// Currently displayed page
+    page: Page,
+
+    /// Value being entered as text
+    value_entry: Option<ValueEntry>,
+
+    /// Sound parameter with keyboard focus
+    focus: Option<SoundParameter>,
+
+    /// Open command palette, replacing the page
+    palette: Option<CommandPalette>,
+
+    /// Built-in and user themes
+    themes: Vec<EditorTheme>,
+
+    /// Filter settings of the MIDI monitor
+    monitor_filter: MonitorFilter,
+
     /// Status bar info if connection is enabled or disabled
     status_connection: String,
 
@@ -85,26 +278,44 @@ struct EditorApp {
     /// Current part id 0-3 for part 1-4
     part_id: u8,
 
-    /// Current sound (preset) parameter values
-    sound_params: SoundParameterValues,
+    /// Settings for the linked editing of parts
+    part_link: PartLink,
+
+    /// Undo/redo history of all edits
+    history: History,
 
-    /// Current multi parameter values
-    multi_params: MultiParameterValues,
+    /// A/B compare slots of all parts
+    compare_slots: [CompareSlots; PART_COUNT],
+
+    /// Macros of the current sound
+    macros: MacroSet,
+
+    /// Randomizer settings
+    randomizer: Randomizer,
+
+    /// Morph between two sounds
+    morph: Morph,
+
+    /// Sound of the current part before the morph position was changed
+    morph_origin: Option<SoundParameterValues>,
+
+    /// XY pad controlling two parameters
+    xy_pad: XYPad,
+
+    /// Breeding of sounds
+    breeder: Breeder,
 
     /// MIDI connection handler for all ports
     midi: MidiConnector,
 
+    /// Copy of the device state and pending dump requests
+    sync: DeviceSync,
+
     /// Device connection state
     device_connected: bool,
 
-    /// Flag for requested sound (preset) parameter update from device
-    request_sound_update: bool,
-
-    /// Flag for requested multi parameter update from device
-    request_multi_update: bool,
-
-    /// Time of last dump request
-    request_time: Option<Instant>,
+    /// Start time of the preview animations
+    animation_start: Instant,
 
     /// File to capture next received preset dump
     preset_capture_file: Option<String>,
@@ -116,17 +327,56 @@ struct EditorApp {
 impl Application for EditorApp {
     type Executor = executor::Default;
     type Message = Message;
-    type Flags = ();
+    type Flags = Options;
     type Theme = iced::Theme;
 
     /// Constructs a new application
-    fn new(_flags: ()) -> (Self, Command<Message>) {
+    fn new(flags: Options) -> (Self, Command<Message>) {
+        let midi = if let Some(file) = flags.replay_file {
+            match Trace::load(&file) {
+                Ok(trace) => {
+                    log::info!("Replaying trace {}", file);
+                    MidiConnector::with_emulation(Emulation::Replay(TracePlayer::new(trace)))
+                }
+                Err(error) => {
+                    log::error!("Loading trace {} failed: {}", file, error);
+                    MidiConnector::new()
+                }
+            }
+        } else if flags.virtual_device {
+            log::info!("Using virtual device");
+            MidiConnector::with_emulation(Emulation::Device(VirtualDevice::new()))
+        } else {
+            MidiConnector::new()
+        };
+
         let mut app = Self {
             app_state: AppState::default(),
 
             sound_panel: SoundPanel::new(),
             multi_panel: MultiPanel::new(),
             manager_panel: ManagerPanel::new(),
+            toolbar_panel: ToolbarPanel::new(),
+            overview_panel: OverviewPanel::new(),
+            compare_panel: ComparePanel::new(),
+            mod_matrix_panel: ModMatrixPanel::new(),
+            sections_panel: SectionsPanel::new(),
+            locks_panel: LocksPanel::new(),
+            layout_panel: LayoutPanel::new(),
+            macros_panel: MacrosPanel::new(),
+            randomizer_panel: RandomizerPanel::new(),
+            morph_panel: MorphPanel::new(),
+            xy_pad_panel: XYPadPanel::new(),
+            breeding_panel: BreedingPanel::new(),
+            monitor_panel: MonitorPanel::new(),
+            command_palette_panel: CommandPalettePanel::new(),
+
+            page: Page::Sound,
+            value_entry: None,
+            focus: None,
+            palette: None,
+            themes: EditorTheme::builtin(),
+            monitor_filter: MonitorFilter::default(),
 
             status_connection: String::from("Device disconnected"),
             status_communication: String::from("Initializing..."),
@@ -135,15 +385,27 @@ impl Application for EditorApp {
 
             part_id: 0,
 
-            sound_params: SoundParameterValues::with_capacity(128),
-            multi_params: MultiParameterValues::with_capacity(32),
+            part_link: PartLink::default(),
 
-            midi: MidiConnector::new(),
+            history: History::new(),
+
+            compare_slots: std::array::from_fn(|_| CompareSlots::new()),
+
+            macros: MacroSet::default(),
+
+            randomizer: Randomizer::new(),
+
+            morph: Morph::new(),
+            morph_origin: None,
+            xy_pad: XYPad::new(),
+
+            breeder: Breeder::new(),
+
+            midi,
+            sync: DeviceSync::new(),
             device_connected: false,
 
-            request_sound_update: false,
-            request_multi_update: false,
-            request_time: None,
+            animation_start: Instant::now(),
 
             preset_capture_file: None,
 
@@ -151,6 +413,8 @@ impl Application for EditorApp {
         };
 
         app.load_app_state();
+        app.load_user_themes();
+        app.apply_theme();
 
         // If the merge input is not present at startup, clear the stored setting.
         app.midi.scan_ports();
@@ -178,38 +442,538 @@ impl Application for EditorApp {
                     self.save_app_state();
                     return iced::window::close();
                 }
+
+                if let iced_native::Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                }) = event
+                {
+                    if key_code == keyboard::KeyCode::Escape && self.value_entry.is_some() {
+                        return self.update(Message::ValueEntryCancel);
+                    }
+
+                    if let Some(palette) = &mut self.palette {
+                        match key_code {
+                            keyboard::KeyCode::Up => palette.move_selection(-1),
+                            keyboard::KeyCode::Down => palette.move_selection(1),
+                            keyboard::KeyCode::Escape => self.palette = None,
+                            _ => {}
+                        }
+                        return Command::none();
+                    }
+
+                    if self.value_entry.is_none() {
+                        if let Some(action) = key_bindings::find_action(
+                            key_code,
+                            modifiers,
+                            &self.app_state.key_bindings,
+                        ) {
+                            return self.key_action(action);
+                        }
+                    }
+                }
             }
 
             Message::SoundParameterChange(param, value) => {
-                let last_value = self.sound_params.get_value(param);
+                self.edit_sound_param(self.part_id, param, value);
+                if param.is_continuous() {
+                    self.focus = Some(param);
+                } else {
+                    // Lists and switches are not dragged, each choice is a step of its own
+                    self.history.close_step();
+                }
+            }
 
-                if value != last_value {
-                    self.sound_params.insert(param, value);
-                    if self.device_connected {
-                        let message =
-                            midi::sysex::preset_param_dump(0x70 + self.part_id, &param, value);
-                        // log::debug!("Sending preset parameter dump {:?}", message);
-                        self.midi.send(&message);
-                    }
+            Message::PartParameterChange(part_id, param, value) => {
+                self.edit_sound_param(part_id, param, value);
+                if !param.is_continuous() {
+                    self.history.close_step();
                 }
             }
 
             Message::MultiParameterChange(param, value) => {
-                let last_value = self.multi_params.get_value(param);
+                if let Some(change) = self.set_multi_param(param, value) {
+                    self.history.record(vec![change]);
+                }
+                if !param.is_continuous() {
+                    self.history.close_step();
+                }
+            }
 
-                if value != last_value {
-                    self.multi_params.insert(param, value);
-                    if self.device_connected {
-                        let message = midi::sysex::multi_param_dump(&param, value);
-                        // log::debug!("Sending multi parameter dump {:?}", message);
-                        self.midi.send(&message);
+            Message::ParameterEditFinished => {
+                if let Some(origin) = self.morph_origin.take() {
+                    // A morph gesture is recorded as a single step
+                    let part_id = self.part_id as usize;
+                    self.history.record(Change::sound_diff(
+                        self.part_id,
+                        &origin,
+                        &self.sync.part_params[part_id],
+                    ));
+                }
+                self.history.close_step();
+            }
+
+            Message::Sequence(messages) => {
+                let commands: Vec<Command<Message>> = messages
+                    .into_iter()
+                    .map(|message| self.update(message))
+                    .collect();
+                return Command::batch(commands);
+            }
+
+            Message::Undo => {
+                if let Some(changes) = self.history.undo() {
+                    self.apply_changes(changes);
+                }
+            }
+
+            Message::Redo => {
+                if let Some(changes) = self.history.redo() {
+                    self.apply_changes(changes);
+                }
+            }
+
+            Message::CompareSelect(slot) => {
+                let part_id = self.part_id as usize;
+                let current = &self.sync.part_params[part_id];
+                if let Some(values) = self.compare_slots[part_id].switch(slot, current) {
+                    log::debug!("Compare slot {} of part {} activated", slot, part_id + 1);
+                    self.load_sound(self.part_id, &values);
+                }
+            }
+
+            Message::CompareCopyAToB => {
+                let part_id = self.part_id as usize;
+                let current = &self.sync.part_params[part_id];
+                if let Some(values) = self.compare_slots[part_id].copy_a_to_b(current) {
+                    self.load_sound(self.part_id, &values);
+                }
+            }
+
+            Message::RandomizerScopeChange(scope) => {
+                self.randomizer.scope = scope;
+            }
+
+            Message::RandomizerAmountChange(amount) => {
+                self.randomizer.amount = amount;
+            }
+
+            Message::RandomizerLockToggle(param, locked) => {
+                if locked {
+                    self.randomizer.locks.insert(param);
+                } else {
+                    self.randomizer.locks.remove(&param);
+                }
+            }
+
+            Message::PresetLockToggle(param, locked) => {
+                if locked {
+                    self.app_state.preset_locks.insert(param);
+                } else {
+                    self.app_state.preset_locks.remove(&param);
+                }
+            }
+
+            Message::ControlStyleChange(section, control_style) => {
+                self.app_state.control_styles.insert(section, control_style);
+            }
+
+            Message::ControlStyleChangeAll(control_style) => {
+                for section in SoundSection::ALL {
+                    self.app_state.control_styles.insert(section, control_style);
+                }
+            }
+
+            Message::ThemeChange(name) => {
+                self.app_state.theme = name;
+                self.apply_theme();
+            }
+
+            Message::ThemeReload => {
+                self.load_user_themes();
+                self.apply_theme();
+            }
+
+            Message::MacroAdd => {
+                let name = format!("Macro {}", self.macros.macros.len() + 1);
+                self.macros.macros.push(Macro::new(name));
+            }
+
+            Message::MacroRemove(index) if index < self.macros.macros.len() => {
+                self.macros.macros.remove(index);
+            }
+
+            Message::MacroNameChange(index, name) => {
+                if let Some(m) = self.macros.macros.get_mut(index) {
+                    m.name = name;
+                }
+            }
+
+            Message::MacroCcChange(index, cc) => {
+                if let Some(m) = self.macros.macros.get_mut(index) {
+                    m.cc = cc;
+                }
+            }
+
+            Message::MacroValueChange(index, value) => {
+                self.set_macro_value(index, value);
+            }
+
+            Message::MacroTargetAdd(index) => {
+                if let Some(m) = self.macros.macros.get_mut(index) {
+                    m.targets
+                        .push(MacroTarget::new(SoundParameter::FilterCutoff));
+                }
+            }
+
+            Message::MacroTargetRemove(index, target_index) => {
+                if let Some(m) = self.macros.macros.get_mut(index) {
+                    if target_index < m.targets.len() {
+                        m.targets.remove(target_index);
+                    }
+                }
+            }
+
+            Message::MacroTargetParamChange(index, target_index, param) => {
+                if let Some(target) = self.macro_target_mut(index, target_index) {
+                    target.set_param(param);
+                }
+            }
+
+            Message::MacroTargetMinChange(index, target_index, value) => {
+                if let Some(target) = self.macro_target_mut(index, target_index) {
+                    target.min = value;
+                }
+            }
+
+            Message::MacroTargetMaxChange(index, target_index, value) => {
+                if let Some(target) = self.macro_target_mut(index, target_index) {
+                    target.max = value;
+                }
+            }
+
+            Message::MacroTargetCurveChange(index, target_index, curve) => {
+                if let Some(target) = self.macro_target_mut(index, target_index) {
+                    target.curve = curve;
+                }
+            }
+
+            Message::Randomize => {
+                let current = &self.sync.part_params[self.part_id as usize];
+                let values = self.randomizer.generate(current, &mut rand::thread_rng());
+                log::debug!("Randomized sound of part {}", self.part_id + 1);
+                self.load_sound(self.part_id, &values);
+            }
+
+            Message::MorphSetPoint(end, source) => {
+                let part_id = self.part_id as usize;
+                let current = &self.sync.part_params[part_id];
+                let point = match source {
+                    MorphSource::SlotA | MorphSource::SlotB => {
+                        let slot = if source == MorphSource::SlotA {
+                            compare::Slot::A
+                        } else {
+                            compare::Slot::B
+                        };
+                        Some(MorphPoint {
+                            label: format!("Part {} slot {}", part_id + 1, slot),
+                            values: self.compare_slots[part_id].values(slot, current),
+                        })
+                    }
+                    MorphSource::Current => Some(MorphPoint {
+                        label: format!("Part {} {}", part_id + 1, self.sync.part_names[part_id]),
+                        values: current.clone(),
+                    }),
+                    MorphSource::File => {
+                        open_file_dialog("Open syx file", "", Some((&["*.syx"], "Sysex files")))
+                            .and_then(|file| {
+                                log::debug!("Loading morph sound from file {}", file);
+                                let data = std::fs::read(&file).ok()?;
+                                let (name, values) = midi::sysex::decode_preset_dump(&data)?;
+                                Some(MorphPoint {
+                                    label: format!("{} ({})", name, file),
+                                    values,
+                                })
+                            })
+                    }
+                    MorphSource::Stored(preset_id) => {
+                        // The point is set when the dump has been received
+                        self.sync.request_preset(preset_id);
+                        self.morph.requested = Some((end, preset_id));
+                        None
+                    }
+                };
+
+                if let Some(point) = point {
+                    self.morph.set_point(end, point);
+                }
+            }
+
+            Message::MorphPositionChange(position) => {
+                self.morph.position = position;
+                if let Some(values) = self.morph.values() {
+                    let part_id = self.part_id as usize;
+                    if self.morph_origin.is_none() {
+                        self.morph_origin = Some(self.sync.part_params[part_id].clone());
+                    }
+                    for param in SoundParameter::ALL {
+                        self.set_sound_param(self.part_id, param, values.get_value(param));
                     }
                 }
             }
 
+            Message::XYPadMove(x, y) => {
+                let now = Instant::now();
+                self.xy_pad.set_playing(now, false);
+                self.xy_pad.returning = false;
+                self.xy_pad.move_to(now, x, y);
+                self.apply_xy_pad(true);
+            }
+
+            Message::XYPadRelease => {
+                if self.xy_pad.spring {
+                    // The step is closed when the pad has reached the centre
+                    self.xy_pad.returning = true;
+                } else {
+                    self.history.close_step();
+                }
+            }
+
+            Message::XYPadTargetChange(axis, target) => {
+                self.xy_pad.set_target(axis, target);
+            }
+
+            Message::XYPadSpringToggle(spring) => {
+                self.xy_pad.spring = spring;
+            }
+
+            Message::XYPadRecordToggle => {
+                if self.xy_pad.is_recording() {
+                    self.xy_pad.stop_recording();
+                } else {
+                    self.xy_pad.start_recording(Instant::now());
+                }
+            }
+
+            Message::XYPadPlayToggle(playing) => {
+                self.xy_pad.set_playing(Instant::now(), playing);
+            }
+
+            Message::BreedingAddCurrent => {
+                let part_id = self.part_id as usize;
+                match self.sync.part_dump(self.part_id) {
+                    Some(dump) => self.breeder.parents.push(Individual {
+                        name: format!("Part {} {}", part_id + 1, self.sync.part_names[part_id]),
+                        values: self.sync.part_params[part_id].clone(),
+                        dump,
+                    }),
+                    None => log::error!("Sound of part {} not received yet", part_id + 1),
+                }
+            }
+
+            Message::BreedingAddFiles => {
+                if let Some(files) =
+                    open_file_dialog_multi("Open syx files", "", Some((&["*.syx"], "Sysex files")))
+                {
+                    for file in files {
+                        log::debug!("Loading breeding parent from file {}", file);
+                        let data = std::fs::read(&file).unwrap_or_default();
+                        match midi::sysex::decode_preset_dump(&data) {
+                            Some((name, values)) => self.breeder.parents.push(Individual {
+                                name,
+                                values,
+                                dump: data,
+                            }),
+                            None => log::error!("Invalid preset file {}", file),
+                        }
+                    }
+                }
+            }
+
+            Message::BreedingRemoveParent(index) if index < self.breeder.parents.len() => {
+                self.breeder.parents.remove(index);
+            }
+
+            Message::BreedingClearParents => {
+                self.breeder.parents.clear();
+            }
+
+            Message::BreedingMutationChange(mutation) => {
+                self.breeder.mutation = mutation;
+            }
+
+            Message::Breed => {
+                self.breeder.breed(&mut rand::thread_rng());
+            }
+
+            Message::BreedingNextGeneration => {
+                self.breeder.next_generation(&mut rand::thread_rng());
+            }
+
+            Message::BreedingAudition(index) => {
+                if let Some(child) = self.breeder.children.get(index) {
+                    let values = child.individual.values.clone();
+                    self.breeder.auditioned = Some(index);
+                    self.load_sound(self.part_id, &values);
+                }
+            }
+
+            Message::BreedingKeepToggle(index, keep) => {
+                if let Some(child) = self.breeder.children.get_mut(index) {
+                    child.keep = keep;
+                }
+            }
+
+            Message::BreedingSaveChild(index) => {
+                if let Some(child) = self.breeder.children.get(index) {
+                    if let Some(file) =
+                        save_file_dialog_with_filter("Save syx file", "", &["*.syx"], "Sysex files")
+                    {
+                        let mut file = std::path::PathBuf::from(file);
+                        file.set_extension("syx");
+                        log::debug!("Saving child to {:?}", file);
+                        let individual = &child.individual;
+                        let message = midi::sysex::patch_preset_dump(
+                            &individual.dump,
+                            0x70,
+                            &individual.values,
+                            &individual.name,
+                        );
+                        if let Some(message) = message {
+                            if let Err(error) = std::fs::write(file, message) {
+                                log::error!("Saving failed: {}", error);
+                            }
+                        }
+                    }
+                }
+            }
+
+            Message::SectionCopyToTwin(section) => {
+                let values =
+                    clipboard::copy_to_twin(&self.sync.part_params[self.part_id as usize], section);
+                self.load_sound(self.part_id, &values);
+            }
+
+            Message::SectionSwapWithTwin(section) => {
+                let values = clipboard::swap_with_twin(
+                    &self.sync.part_params[self.part_id as usize],
+                    section,
+                );
+                self.load_sound(self.part_id, &values);
+            }
+
+            Message::SectionCopyToPart(section, target_part_id) => {
+                let values = clipboard::copy_section(
+                    &self.sync.part_params[self.part_id as usize],
+                    &self.sync.part_params[target_part_id as usize],
+                    section,
+                );
+                self.load_sound(target_part_id, &values);
+            }
+
+            Message::SoundCopyToPart(target_part_id) => {
+                let values = self.sync.part_params[self.part_id as usize].clone();
+                self.load_sound(target_part_id, &values);
+            }
+
+            Message::AnimationTick(now) => {
+                if let Some((x, y)) = self.xy_pad.playback_position(now) {
+                    self.xy_pad.move_to(now, x, y);
+                    self.apply_xy_pad(false);
+                } else if self.xy_pad.returning {
+                    let (x, y) = self.xy_pad.spring_step();
+                    self.xy_pad.move_to(now, x, y);
+                    self.apply_xy_pad(true);
+                    if !self.xy_pad.returning {
+                        self.history.close_step();
+                    }
+                }
+            }
+
+            Message::ValueEntryStart(target, value) => {
+                self.value_entry = Some(ValueEntry::new(target, value));
+                return Command::batch(vec![
+                    iced::widget::text_input::focus(value_entry::input_id()),
+                    iced::widget::text_input::select_all(value_entry::input_id()),
+                ]);
+            }
+
+            Message::ValueEntryChange(text) => {
+                if let Some(entry) = &mut self.value_entry {
+                    entry.text = text;
+                    entry.invalid = false;
+                }
+            }
+
+            Message::ValueEntrySubmit => {
+                if let Some(mut entry) = self.value_entry.take() {
+                    if let Some(message) = entry.target.parse(&entry.text) {
+                        let command = self.update(message);
+                        self.history.close_step();
+                        return command;
+                    }
+                    entry.invalid = true;
+                    self.value_entry = Some(entry);
+                }
+            }
+
+            Message::ValueEntryCancel => {
+                self.value_entry = None;
+            }
+
+            Message::PaletteOpen => {
+                self.value_entry = None;
+                self.palette = Some(CommandPalette::default());
+                return iced::widget::text_input::focus(palette_ui::input_id());
+            }
+
+            Message::PaletteChange(query) => {
+                if let Some(palette) = &mut self.palette {
+                    palette.set_query(query);
+                }
+            }
+
+            Message::PaletteSubmit => {
+                if let Some(found) = self.palette.as_ref().and_then(|p| p.selected_match()) {
+                    return self.apply_palette_match(found);
+                }
+            }
+
+            Message::PalettePick(index) => {
+                if let Some(found) = self.palette.as_ref().and_then(|p| p.matches.get(index)) {
+                    return self.apply_palette_match(*found);
+                }
+            }
+
+            Message::PaletteClose => {
+                self.palette = None;
+            }
+
             Message::PartChange(part_id) => {
+                // The cached values are shown instantly, the refetch only validates them
                 self.part_id = part_id;
-                self.request_sound_update = true;
+                self.sync.request_sound_update[part_id as usize] = true;
+            }
+
+            Message::PageChange(page) => {
+                if page == Page::Compare {
+                    let part_id = self.part_id as usize;
+                    self.compare_slots[part_id].start(&self.sync.part_params[part_id]);
+                }
+                self.page = page;
+                self.focus = None;
+            }
+
+            Message::LinkEnable(enabled) => {
+                self.part_link.enabled = enabled;
+            }
+
+            Message::LinkPartToggle(part_id, linked) => {
+                self.part_link.parts[part_id as usize] = linked;
+            }
+
+            Message::LinkModeChange(mode) => {
+                self.part_link.mode = mode;
             }
 
             Message::MergeInputChange(input_name) => {
@@ -221,8 +985,8 @@ impl Application for EditorApp {
             }
 
             Message::UpdateFromDevice if self.device_connected => {
-                self.request_sound_update = true;
-                self.request_multi_update = true;
+                self.sync.request_sound_update = [true; PART_COUNT];
+                self.sync.request_multi_update = true;
             }
 
             Message::LoadSysexFile if self.device_connected => {
@@ -230,7 +994,7 @@ impl Application for EditorApp {
                     open_file_dialog("Open syx file", "", Some((&["*.syx"], "Sysex files")))
                 {
                     log::debug!("Loading file {}", file);
-                    let data = std::fs::read(file);
+                    let data = std::fs::read(&file);
 
                     if let Ok(mut message) = data {
                         match message[1] {
@@ -241,7 +1005,52 @@ impl Application for EditorApp {
                                 log::debug!("Sending preset dump with id {:#X}", preset_id);
                                 message[2] = preset_id;
                                 self.midi.send(&message);
-                                self.request_sound_update = true;
+                                self.sync.request_sound_update[self.part_id as usize] = true;
+
+                                let part_id = self.part_id as usize;
+                                let param_values =
+                                    midi::sysex::unpack_data(&message[3..message.len()]);
+                                let before_dump = self.sync.part_dump(self.part_id);
+                                let before = self.sync.part_params[part_id].clone();
+                                let locked = self
+                                    .sync
+                                    .locked_values(self.part_id, &self.app_state.preset_locks);
+                                midi::sysex::update_sound_params(
+                                    &mut self.sync.part_params[part_id],
+                                    &param_values,
+                                );
+                                self.restore_locked_values(self.part_id, locked);
+                                self.sync.part_names[part_id] =
+                                    midi::sysex::preset_name(&param_values);
+                                self.sync.part_dumps[part_id] = Some(message.clone());
+
+                                // Without a dump from the device, only the parameters
+                                // known to the editor can be restored
+                                let changes = match (before_dump, self.sync.part_dump(self.part_id))
+                                {
+                                    (Some(before), Some(after)) => vec![Change::Preset {
+                                        part_id: self.part_id,
+                                        before,
+                                        after,
+                                    }],
+                                    _ => Change::sound_diff(
+                                        self.part_id,
+                                        &before,
+                                        &self.sync.part_params[part_id],
+                                    ),
+                                };
+                                self.history.record(changes);
+                                self.history.close_step();
+
+                                // Macros of the previous sound don't fit the loaded one
+                                self.macros = if MacroSet::sidecar_path(&file).exists() {
+                                    MacroSet::load(&file).unwrap_or_else(|error| {
+                                        log::error!("Loading macros failed: {}", error);
+                                        MacroSet::default()
+                                    })
+                                } else {
+                                    MacroSet::default()
+                                };
                             }
 
                             midi::sysex::SERVICE_MULTI_DUMP
@@ -251,7 +1060,22 @@ impl Application for EditorApp {
                                 log::debug!("Sending multi dump with id {:#X}", multi_id);
                                 message[2] = multi_id;
                                 self.midi.send(&message);
-                                self.request_multi_update = true;
+                                self.sync.request_multi_update = true;
+
+                                let param_values =
+                                    midi::sysex::unpack_data(&message[3..message.len()]);
+                                let before = self.sync.multi_params.clone();
+                                midi::sysex::update_multi_params(
+                                    &mut self.sync.multi_params,
+                                    &param_values,
+                                );
+                                self.sync
+                                    .request_changed_parts(&before, &self.app_state.preset_locks);
+                                self.history.record(vec![Change::MultiDump {
+                                    before,
+                                    after: self.sync.multi_params.clone(),
+                                }]);
+                                self.history.close_step();
                             }
 
                             _ => {
@@ -269,13 +1093,100 @@ impl Application for EditorApp {
                     let mut file = std::path::PathBuf::from(file);
                     file.set_extension("syx");
                     log::debug!("Capturing next preset dump in file {:?}", file);
+                    if !self.macros.macros.is_empty() {
+                        if let Err(error) = self.macros.save(&file) {
+                            log::error!("Saving macros failed: {}", error);
+                        }
+                    } else {
+                        // Macros of a sound previously saved under this name don't fit
+                        let sidecar = MacroSet::sidecar_path(&file);
+                        if sidecar.exists() {
+                            if let Err(error) = std::fs::remove_file(sidecar) {
+                                log::error!("Removing macros failed: {}", error);
+                            }
+                        }
+                    }
                     self.preset_capture_file = Some(file.into_os_string().into_string().unwrap());
-                    self.request_sound_update = true;
+                    self.sync.request_sound_update[self.part_id as usize] = true;
+                }
+            }
+
+            Message::MonitorFilterChange(filter) => {
+                self.monitor_filter = filter;
+            }
+
+            Message::MonitorTogglePause => {
+                let monitor = self.midi.monitor_mut();
+                monitor.set_paused(!monitor.is_paused());
+            }
+
+            Message::MonitorClear => {
+                self.midi.monitor_mut().clear();
+            }
+
+            Message::MonitorExportSysex => {
+                if let Some(file) =
+                    save_file_dialog_with_filter("Export syx file", "", &["*.syx"], "Sysex files")
+                {
+                    let mut file = std::path::PathBuf::from(file);
+                    file.set_extension("syx");
+                    log::debug!("Exporting monitor capture to {:?}", file);
+                    let data = self.midi.monitor().export_sysex(&self.monitor_filter);
+                    if let Err(error) = std::fs::write(file, data) {
+                        log::error!("Export failed: {}", error);
+                    }
+                }
+            }
+
+            Message::MonitorExportText => {
+                if let Some(file) =
+                    save_file_dialog_with_filter("Export text file", "", &["*.txt"], "Text files")
+                {
+                    let mut file = std::path::PathBuf::from(file);
+                    file.set_extension("txt");
+                    log::debug!("Exporting monitor capture to {:?}", file);
+                    let text = self.midi.monitor().export_text(&self.monitor_filter);
+                    if let Err(error) = std::fs::write(file, text) {
+                        log::error!("Export failed: {}", error);
+                    }
+                }
+            }
+
+            Message::MonitorToggleTrace => {
+                if let Some(trace) = self.midi.stop_trace() {
+                    if let Some(file) = save_file_dialog_with_filter(
+                        "Save trace file",
+                        "",
+                        &["*.ron"],
+                        "Trace files",
+                    ) {
+                        let mut file = std::path::PathBuf::from(file);
+                        file.set_extension("ron");
+                        log::debug!("Saving trace to {:?}", file);
+                        if let Err(error) = trace.save(file) {
+                            log::error!("Saving trace failed: {}", error);
+                        }
+                    }
+                } else {
+                    log::debug!("Trace recording started");
+                    self.midi.start_trace();
                 }
             }
 
             Message::Tick => {
                 self.midi.scan_ports();
+
+                if let Some(mismatches) = self.midi.take_replay_result() {
+                    for mismatch in mismatches.iter() {
+                        log::error!("Replay mismatch: {}", mismatch);
+                    }
+                    self.status_communication = if mismatches.is_empty() {
+                        String::from("Trace replay complete")
+                    } else {
+                        format!("Trace replay: {} mismatches", mismatches.len())
+                    };
+                }
+
                 let connection_state = self.midi.is_connected();
 
                 if connection_state != self.device_connected {
@@ -304,32 +1215,20 @@ impl Application for EditorApp {
                 }
 
                 if self.device_connected {
-                    if self.request_sound_update && self.request_time.is_none() {
-                        let preset_id = 0x70 + self.part_id;
-                        log::debug!("Requesting preset with id {:#X}", preset_id);
-                        self.status_communication = String::from("Requesting preset dump...");
-                        let message = midi::sysex::preset_request(preset_id);
-                        self.midi.send(&message);
-                        self.request_time = Some(Instant::now());
-                        self.request_sound_update = false;
+                    if let Some(request) = self.sync.next_request(self.part_id) {
+                        log::debug!("Requesting {:?}", request);
+                        self.status_communication = String::from(match request {
+                            Request::Sound(_) => "Requesting preset dump...",
+                            Request::Multi => "Requesting multi dump...",
+                            Request::Preset(_) => "Requesting stored preset...",
+                            Request::PresetName(_) => "Reading preset names...",
+                        });
+                        self.midi.send(&request.message());
                     }
 
-                    if self.request_multi_update && self.request_time.is_none() {
-                        let multi_id = 0x7F;
-                        log::debug!("Requesting multi with id {:#X}", multi_id);
-                        self.status_communication = String::from("Requesting multi dump...");
-                        let message = midi::sysex::multi_request(multi_id);
-                        self.midi.send(&message);
-                        self.request_time = Some(Instant::now());
-                        self.request_multi_update = false;
-                    }
-
-                    if let Some(request_time) = self.request_time {
-                        if request_time.elapsed() >= Duration::new(1, 0) {
-                            log::error!("Response timeout");
-                            self.status_communication = String::from("Error: response timeout");
-                            self.request_time = None;
-                        }
+                    if self.sync.check_timeout() {
+                        log::error!("Response timeout");
+                        self.status_communication = String::from("Error: response timeout");
                     }
                 }
             }
@@ -349,7 +1248,17 @@ impl Application for EditorApp {
             }
 
             Message::MidiMergeInputMessage(message) => {
-                self.midi.send(&message);
+                // CCs assigned to a macro are consumed by the editor
+                let macro_index = match message[..] {
+                    [status, cc, _] if status & 0xF0 == 0xB0 => self.macros.find_cc(cc),
+                    _ => None,
+                };
+
+                if let Some(index) = macro_index {
+                    self.set_macro_value(index, message[2] as i32);
+                } else {
+                    self.midi.forward(&message);
+                }
             }
 
             _ => {}
@@ -366,31 +1275,88 @@ impl Application for EditorApp {
         let fast_tick_subscription =
             time::every(Duration::from_millis(100)).map(|_| Message::FastTick);
 
-        let subscriptions = vec![
+        let mut subscriptions = vec![
             tick_subscription,
             fast_tick_subscription,
             midi_merge_input_subscription(),
             event_subscription,
         ];
 
+        // The XY pad is animated while springing back or playing, the LFO
+        // previews redraw themselves without any messages
+        if self.xy_pad.is_animated() {
+            subscriptions.push(time::every(Duration::from_millis(40)).map(Message::AnimationTick));
+        }
+
         Subscription::batch(subscriptions)
     }
 
     /// Returns the widgets to display
     fn view(&self) -> Element<Self::Message, iced::Renderer<iced::Theme>> {
+        let main_panel = if let Some(palette) = &self.palette {
+            self.command_palette_panel
+                .view(palette, &self.app_state.key_bindings)
+        } else {
+            match self.page {
+                Page::Sound => self.sound_panel.view(
+                    &self.sync.part_params[self.part_id as usize],
+                    self.animation_start,
+                    &self.app_state.control_styles,
+                    self.focus,
+                ),
+                Page::Overview => self.overview_panel.view(
+                    &self.sync.part_params,
+                    &self.sync.part_names,
+                    &self.sync.multi_params,
+                    &self.sync.preset_names,
+                    &self.part_link,
+                ),
+                Page::Compare => self.compare_panel.view(
+                    self.part_id,
+                    &self.compare_slots[self.part_id as usize],
+                    &self.sync.part_params[self.part_id as usize],
+                ),
+                Page::ModMatrix => self
+                    .mod_matrix_panel
+                    .view(&self.sync.part_params[self.part_id as usize]),
+                Page::Sections => self.sections_panel.view(self.part_id),
+                Page::Locks => self.locks_panel.view(&self.app_state.preset_locks),
+                Page::Layout => self.layout_panel.view(
+                    &self.app_state.control_styles,
+                    &self.themes,
+                    &theme::current().name,
+                ),
+                Page::Macros => self.macros_panel.view(&self.macros),
+                Page::Randomizer => self.randomizer_panel.view(&self.randomizer),
+                Page::Morph => self.morph_panel.view(&self.morph, &self.sync.preset_names),
+                Page::XYPad => self.xy_pad_panel.view(&self.xy_pad),
+                Page::Breeding => self.breeding_panel.view(&self.breeder),
+                Page::Monitor => self.monitor_panel.view(
+                    self.midi.monitor(),
+                    &self.monitor_filter,
+                    self.midi.is_tracing(),
+                ),
+            }
+        };
+
         Container::new(
             Column::new()
+                .push(self.toolbar_panel.view(
+                    self.page,
+                    self.part_id,
+                    &self.sync.multi_params,
+                    &self.sync.preset_names,
+                    &self.history,
+                    &self.compare_slots[self.part_id as usize],
+                    self.value_entry.as_ref(),
+                ))
                 .push(
                     Row::new()
-                        .push(
-                            Column::new()
-                                .push(self.sound_panel.view(&self.sound_params))
-                                .width(Length::FillPortion(4)),
-                        )
+                        .push(Column::new().push(main_panel).width(Length::FillPortion(4)))
                         .push(
                             Column::new()
                                 .push(self.manager_panel.view(self.part_id, self.device_connected))
-                                .push(self.multi_panel.view(&self.multi_params))
+                                .push(self.multi_panel.view(&self.sync.multi_params))
                                 .width(Length::FillPortion(1)),
                         )
                         .height(625),
@@ -402,14 +1368,14 @@ impl Application for EditorApp {
                             Column::new()
                                 .push(
                                     Text::new(&self.status_connection)
-                                        .size(style::STATUS_TEXT_SIZE),
+                                        .size(style::status_text_size()),
                                 )
                                 .width(Length::FillPortion(1)),
                         )
                         .push(
                             Column::new().push(
                                 Row::new()
-                                    .push(Text::new("Merge Input:").size(style::STATUS_TEXT_SIZE))
+                                    .push(Text::new("Merge Input:").size(style::status_text_size()))
                                     .push(
                                         PickList::new(
                                             {
@@ -423,7 +1389,7 @@ impl Application for EditorApp {
                                         )
                                         .width(250)
                                         .style(style::PickList)
-                                        .text_size(style::LIST_ITEM_TEXT_SIZE),
+                                        .text_size(style::list_item_text_size()),
                                     )
                                     .spacing(10),
                             ),
@@ -432,7 +1398,7 @@ impl Application for EditorApp {
                             Column::new()
                                 .push(
                                     Text::new(&self.status_communication)
-                                        .size(style::STATUS_TEXT_SIZE),
+                                        .size(style::status_text_size()),
                                 )
                                 .width(Length::FillPortion(3))
                                 .align_items(Alignment::Center),
@@ -445,10 +1411,10 @@ impl Application for EditorApp {
                                         "v{} (debug build)",
                                         env!("CARGO_PKG_VERSION")
                                     ))
-                                    .size(style::STATUS_TEXT_SIZE),
+                                    .size(style::status_text_size()),
                                     #[cfg(not(debug_assertions))]
                                     Text::new(format!("v{}", env!("CARGO_PKG_VERSION")))
-                                        .size(style::STATUS_TEXT_SIZE),
+                                        .size(style::status_text_size()),
                                 )
                                 .width(200)
                                 .align_items(Alignment::End),
@@ -463,7 +1429,7 @@ impl Application for EditorApp {
     }
 
     fn theme(&self) -> iced::Theme {
-        iced::Theme::Dark
+        iced::Theme::custom((&theme::current().palette).into())
     }
 }
 
@@ -485,6 +1451,58 @@ impl EditorApp {
         }
     }
 
+    /// Load the user themes from file, replacing built-in themes with the same name.
+    fn load_user_themes(&mut self) {
+        self.themes = EditorTheme::builtin();
+
+        if let Some(proj_dirs) = directories_next::ProjectDirs::from("", "", APP_NAME) {
+            let themes_file_path = proj_dirs.config_dir().join("themes.ron");
+            if let Ok(s) = std::fs::read_to_string(&themes_file_path) {
+                match ron::from_str::<Vec<EditorTheme>>(s.as_str()) {
+                    Ok(mut user_themes) => {
+                        user_themes.retain(|theme| {
+                            if theme.name.is_empty() {
+                                log::warn!(
+                                    "Ignoring theme without name in {}",
+                                    themes_file_path.display()
+                                );
+                            }
+                            !theme.name.is_empty()
+                        });
+                        log::info!(
+                            "Loaded {} user themes from {}",
+                            user_themes.len(),
+                            themes_file_path.display()
+                        );
+                        self.themes
+                            .retain(|theme| !user_themes.iter().any(|t| t.name == theme.name));
+                        self.themes.extend(user_themes);
+                    }
+                    Err(err) => {
+                        log::warn!("Invalid theme file {}: {}", themes_file_path.display(), err)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Use the selected theme, falling back to the default theme if it is unknown.
+    fn apply_theme(&self) {
+        let selected = self
+            .themes
+            .iter()
+            .find(|theme| theme.name == self.app_state.theme)
+            .or_else(|| {
+                self.themes
+                    .iter()
+                    .find(|theme| theme.name == theme::DEFAULT_THEME)
+            })
+            .cloned()
+            .unwrap_or_default();
+
+        theme::set_current(selected);
+    }
+
     /// Save persistent state data to file.
     fn save_app_state(&self) {
         if let Some(proj_dirs) = directories_next::ProjectDirs::from("", "", APP_NAME) {
@@ -499,101 +1517,420 @@ impl EditorApp {
         }
     }
 
-    /// Called when device is connected
-    fn on_device_connected(&mut self) {
-        log::debug!("Device connected");
-        self.status_connection = String::from("Device connected");
-        self.request_sound_update = true;
-        self.request_multi_update = true;
+    /// Set a sound parameter of a part and send it to the device
+    ///
+    /// - `part_id`     Part id 0-3 for part 1-4
+    /// - `param`       Parameter to be changed
+    /// - `value`       New value
+    ///
+    /// Returns the change if the value was modified.
+    fn set_sound_param(
+        &mut self,
+        part_id: u8,
+        param: SoundParameter,
+        value: i32,
+    ) -> Option<Change> {
+        let part_params = &mut self.sync.part_params[part_id as usize];
+        let last_value = part_params.get_value(param);
+
+        if value == last_value {
+            return None;
+        }
+
+        part_params.insert(param, value);
+        if self.device_connected {
+            let message = midi::sysex::preset_param_dump(0x70 + part_id, &param, value);
+            // log::debug!("Sending preset parameter dump {:?}", message);
+            self.midi.send(&message);
+        }
+
+        Some(Change::Sound {
+            part_id,
+            param,
+            before: last_value,
+            after: value,
+        })
     }
 
-    /// Called when device is disconnected
-    fn on_device_disconnected(&mut self) {
-        log::debug!("Device disconnected");
-        self.status_connection = String::from("Device disconnected");
-        self.request_sound_update = false;
-        self.request_multi_update = false;
-        self.request_time = None;
+    /// Load complete sound parameters into a part as a single undo step
+    ///
+    /// Only the parameters differing from the edit buffer are sent to the device.
+    ///
+    /// - `part_id`     Part id 0-3 for part 1-4
+    /// - `values`      Sound parameters to be loaded
+    fn load_sound(&mut self, part_id: u8, values: &SoundParameterValues) {
+        let changes: Vec<Change> = SoundParameter::ALL
+            .iter()
+            .filter_map(|param| self.set_sound_param(part_id, *param, values.get_value(*param)))
+            .collect();
+
+        self.history.record(changes);
+        self.history.close_step();
     }
 
-    /// Process an incoming MIDI message from the device
-    fn process_midi(&mut self, message: &[u8]) {
-        match message[0] {
-            0xB0..=0xCF => {
-                // Whenever the device sends a CC or program change message,
-                // a full parameter update will be requested to keep editor in sync
-                self.request_sound_update = true;
-                self.request_multi_update = true;
-            }
-
-            0xF0 => {
-                // Sysex
-                match message[1] {
-                    midi::sysex::SERVICE_PRESET_DUMP
-                        if message.len() == midi::sysex::PRESET_DUMP_LENGTH =>
-                    {
-                        self.process_preset_dump(message);
+    /// Set a multi parameter and send it to the device
+    ///
+    /// Returns the change if the value was modified.
+    fn set_multi_param(&mut self, param: MultiParameter, value: i32) -> Option<Change> {
+        let last_value = self.sync.multi_params.get_value(param);
+
+        if value == last_value {
+            return None;
+        }
+
+        self.sync.multi_params.insert(param, value);
+        if self.device_connected {
+            let message = midi::sysex::multi_param_dump(&param, value);
+            // log::debug!("Sending multi parameter dump {:?}", message);
+            self.midi.send(&message);
+            if let Some(part_id) = param.get_preset_part() {
+                // The device loads the assigned preset into the edit buffer
+                self.sync.request_sound_update[part_id as usize] = true;
+                self.sync
+                    .request_lock_restore(part_id, &self.app_state.preset_locks);
+            }
+        }
+
+        Some(Change::Multi {
+            param,
+            before: last_value,
+            after: value,
+        })
+    }
+
+    /// Apply changes restored from the history and send them to the device
+    fn apply_changes(&mut self, changes: Vec<Change>) {
+        for change in changes {
+            match change {
+                Change::Sound {
+                    part_id,
+                    param,
+                    after,
+                    ..
+                } => {
+                    self.set_sound_param(part_id, param, after);
+                }
+
+                Change::Multi { param, after, .. } => {
+                    self.set_multi_param(param, after);
+                }
+
+                Change::Preset { part_id, after, .. } => {
+                    if self.device_connected {
+                        self.midi.send(&after);
                     }
-                    midi::sysex::SERVICE_MULTI_DUMP
-                        if message.len() == midi::sysex::MULTI_DUMP_LENGTH =>
-                    {
-                        self.process_multi_dump(message);
+                    if let Some((name, params)) = midi::sysex::decode_preset_dump(&after) {
+                        self.sync.part_params[part_id as usize] = params;
+                        self.sync.part_names[part_id as usize] = name;
                     }
-                    _ => {}
+                    self.sync.part_dumps[part_id as usize] = Some(after);
                 }
-            }
 
-            _ => {}
+                Change::MultiDump { after, .. } => {
+                    if self.device_connected {
+                        let message = midi::sysex::multi_dump(0x7F, &after);
+                        self.midi.send(&message);
+                    }
+                    let last_params = std::mem::replace(&mut self.sync.multi_params, after);
+                    self.sync
+                        .request_changed_parts(&last_params, &self.app_state.preset_locks);
+                }
+            }
         }
     }
 
-    /// Process an incoming preset dump from the device
-    fn process_preset_dump(&mut self, message: &[u8]) {
-        let preset_id = message[2];
-
-        log::debug!("Preset dump received with id {:#X}", preset_id);
-        self.status_communication = String::from("");
-
-        // Wait a little bit because the dump is possibly echoed by the DAW
-        std::thread::sleep(Duration::from_millis(100));
-
-        match preset_id {
-            0..=99 => {}
-            0x70..=0x73 => {
-                if self.part_id == preset_id - 0x70 {
-                    let param_values = midi::sysex::unpack_data(&message[3..message.len()]);
-                    midi::sysex::update_sound_params(&mut self.sound_params, &param_values);
-                    if let Some(file) = &self.preset_capture_file {
-                        log::debug!("Preset dump captured in file {}", file);
-                        let mut message: Vec<u8> = message.to_vec();
-                        message[2] = 0x70;
-                        std::fs::write(file, message).ok();
-                        self.preset_capture_file = None;
+    /// Set a macro value and apply the resulting values to the current part
+    fn set_macro_value(&mut self, index: usize, value: i32) {
+        let target_values = match self.macros.macros.get_mut(index) {
+            Some(m) => {
+                m.value = value;
+                m.target_values()
+            }
+            None => return,
+        };
+
+        let changes: Vec<Change> = target_values
+            .into_iter()
+            .filter_map(|(param, value)| self.set_sound_param(self.part_id, param, value))
+            .collect();
+
+        self.history.record(changes);
+    }
+
+    /// Return a target of a macro for modification
+    ///
+    /// - `index`           Index of the macro
+    /// - `target_index`    Index of the target within the macro
+    fn macro_target_mut(&mut self, index: usize, target_index: usize) -> Option<&mut MacroTarget> {
+        self.macros
+            .macros
+            .get_mut(index)
+            .and_then(|m| m.targets.get_mut(target_index))
+    }
+
+    /// Send the parameter values at the XY pad position
+    ///
+    /// - `record`      Record the changes in the undo history
+    fn apply_xy_pad(&mut self, record: bool) {
+        for (target, value) in self.xy_pad.values() {
+            match target {
+                XYTarget::Sound(param) if record => {
+                    self.edit_sound_param(self.part_id, param, value);
+                }
+                XYTarget::Sound(param) => {
+                    self.set_linked_sound_param(self.part_id, param, value);
+                }
+                XYTarget::Multi(param) => {
+                    let change = self.set_multi_param(param, value);
+                    if record {
+                        self.history.record(change.into_iter().collect());
                     }
                 }
             }
+        }
+    }
+
+    /// Send the values of locked parameters to the device after a preset was loaded
+    ///
+    /// - `part_id`     Part id 0-3 for part 1-4
+    /// - `locked`      Parameter values taken before loading the preset
+    fn restore_locked_values(&mut self, part_id: u8, locked: Vec<(SoundParameter, i32)>) {
+        for (param, value) in locked {
+            if self.set_sound_param(part_id, param, value).is_some() {
+                log::debug!(
+                    "Restored locked parameter {:?} of part {}",
+                    param,
+                    part_id + 1
+                );
+            }
+        }
+    }
+
+    /// Perform the action of a keyboard shortcut
+    fn key_action(&mut self, action: KeyAction) -> Command<Message> {
+        match action {
+            KeyAction::Part1 => return self.update(Message::PartChange(0)),
+            KeyAction::Part2 => return self.update(Message::PartChange(1)),
+            KeyAction::Part3 => return self.update(Message::PartChange(2)),
+            KeyAction::Part4 => return self.update(Message::PartChange(3)),
+            KeyAction::UpdateFromDevice => return self.update(Message::UpdateFromDevice),
+            KeyAction::LoadSysexFile => return self.update(Message::LoadSysexFile),
+            KeyAction::SavePresetSysexFile => {
+                return self.update(Message::SavePresetSysexFile);
+            }
+            KeyAction::Undo => return self.update(Message::Undo),
+            KeyAction::Redo => return self.update(Message::Redo),
+            KeyAction::CommandPalette => return self.update(Message::PaletteOpen),
             _ => {}
         }
 
-        self.request_time = None;
+        // Focus and value changes only apply to the controls of the sound page
+        if self.page != Page::Sound {
+            return Command::none();
+        }
+
+        let order = &ui::sound::FOCUS_ORDER;
+        let index = self
+            .focus
+            .and_then(|focus| order.iter().position(|param| *param == focus));
+
+        match action {
+            KeyAction::FocusNext => {
+                let next = index.map_or(0, |i| (i + 1) % order.len());
+                self.focus = Some(order[next]);
+            }
+            KeyAction::FocusPrevious => {
+                let previous =
+                    index.map_or(order.len() - 1, |i| (i + order.len() - 1) % order.len());
+                self.focus = Some(order[previous]);
+            }
+            KeyAction::FocusClear => self.focus = None,
+            _ => {
+                if let Some(param) = self.focus {
+                    let range = param.get_range();
+                    let span = range.end() - range.start();
+                    let value = self.sync.part_params[self.part_id as usize].get_value(param);
+
+                    let new_value = match action {
+                        KeyAction::Increment => value + (span / 100).max(1),
+                        KeyAction::Decrement => value - (span / 100).max(1),
+                        KeyAction::IncrementFine => value + 1,
+                        KeyAction::DecrementFine => value - 1,
+                        KeyAction::IncrementCoarse => value + (span / 10).max(1),
+                        KeyAction::DecrementCoarse => value - (span / 10).max(1),
+                        _ => param.get_default(),
+                    }
+                    .clamp(*range.start(), *range.end());
+
+                    if new_value != value {
+                        self.edit_sound_param(self.part_id, param, new_value);
+                        self.history.close_step();
+                    }
+                }
+            }
+        }
+
+        Command::none()
+    }
+
+    /// Close the command palette and jump to a result, setting its value if present
+    fn apply_palette_match(&mut self, found: PaletteMatch) -> Command<Message> {
+        self.palette = None;
+
+        match (found.item, found.value) {
+            (PaletteItem::Sound(param), value) => {
+                self.page = Page::Sound;
+                self.focus = Some(param).filter(SoundParameter::is_continuous);
+                if let Some(value) = value {
+                    self.edit_sound_param(self.part_id, param, value);
+                    self.history.close_step();
+                } else if self.focus.is_none() {
+                    // Lists and switches have no focus highlight, their value is entered as text
+                    let value = self.sync.part_params[self.part_id as usize].get_value(param);
+                    return self.update(Message::ValueEntryStart(ValueTarget::Sound(param), value));
+                }
+            }
+            (PaletteItem::Multi(param), Some(value)) => {
+                if let Some(change) = self.set_multi_param(param, value) {
+                    self.history.record(vec![change]);
+                    self.history.close_step();
+                }
+            }
+            (PaletteItem::Multi(param), None) => {
+                let value = self.sync.multi_params.get_value(param);
+                return self.update(Message::ValueEntryStart(ValueTarget::Multi(param), value));
+            }
+            (PaletteItem::Section(section), _) => {
+                self.page = Page::Sound;
+                self.focus = ui::sound::FOCUS_ORDER
+                    .iter()
+                    .copied()
+                    .find(|param| param.get_section() == section);
+            }
+            (PaletteItem::Page(page), _) => return self.update(Message::PageChange(page)),
+            (PaletteItem::Action(action), _) => return self.key_action(action),
+        }
+
+        Command::none()
     }
 
-    /// Process an incoming multi dump from the device
-    fn process_multi_dump(&mut self, message: &[u8]) {
-        let multi_id = message[2];
+    /// Apply an edit of a sound parameter to a part and all parts linked to it
+    ///
+    /// - `part_id`     Part id 0-3 of the edited part
+    /// - `param`       Parameter to be changed
+    /// - `value`       New value
+    fn edit_sound_param(&mut self, part_id: u8, param: SoundParameter, value: i32) {
+        let changes = self.set_linked_sound_param(part_id, param, value);
+        self.history.record(changes);
+    }
 
-        log::debug!("Multi dump received with id {:#X}", multi_id);
-        self.status_communication = String::from("");
+    /// Set a sound parameter of a part and all parts linked to it without recording the changes
+    ///
+    /// - `part_id`     Part id 0-3 of the changed part
+    /// - `param`       Parameter to be changed
+    /// - `value`       New value
+    fn set_linked_sound_param(
+        &mut self,
+        part_id: u8,
+        param: SoundParameter,
+        value: i32,
+    ) -> Vec<Change> {
+        let offset = value - self.sync.part_params[part_id as usize].get_value(param);
+
+        let mut changes: Vec<Change> = self
+            .set_sound_param(part_id, param, value)
+            .into_iter()
+            .collect();
+
+        if !self.part_link.enabled || !self.part_link.parts[part_id as usize] {
+            return changes;
+        }
 
-        // Wait a little bit because the dump is possibly echoed by the DAW
-        std::thread::sleep(Duration::from_millis(100));
+        let range = param.get_range();
 
-        if multi_id == 0x7F {
-            let param_values = midi::sysex::unpack_data(&message[3..message.len()]);
-            midi::sysex::update_multi_params(&mut self.multi_params, &param_values);
+        for linked_part_id in 0..PART_COUNT as u8 {
+            if linked_part_id == part_id || !self.part_link.parts[linked_part_id as usize] {
+                continue;
+            }
+            let linked_value = match self.part_link.mode {
+                LinkMode::Absolute => value,
+                LinkMode::Relative => {
+                    (self.sync.part_params[linked_part_id as usize].get_value(param) + offset)
+                        .clamp(*range.start(), *range.end())
+                }
+            };
+            changes.extend(self.set_sound_param(linked_part_id, param, linked_value));
         }
 
-        self.request_time = None;
+        changes
+    }
+
+    /// Called when device is connected
+    fn on_device_connected(&mut self) {
+        log::debug!("Device connected");
+        self.status_connection = String::from("Device connected");
+        self.sync.request_all();
+        self.sync.request_preset_names(0..PRESET_COUNT as u8);
+    }
+
+    /// Called when device is disconnected
+    fn on_device_disconnected(&mut self) {
+        log::debug!("Device disconnected");
+        self.status_connection = String::from("Device disconnected");
+        self.sync.cancel_requests();
+    }
+
+    /// Process an incoming MIDI message from the device
+    fn process_midi(&mut self, message: &[u8]) {
+        let is_preset_dump = message[0] == 0xF0
+            && message[1] == midi::sysex::SERVICE_PRESET_DUMP
+            && message.len() == midi::sysex::PRESET_DUMP_LENGTH;
+        let is_multi_dump = message[0] == 0xF0
+            && message[1] == midi::sysex::SERVICE_MULTI_DUMP
+            && message.len() == midi::sysex::MULTI_DUMP_LENGTH;
+
+        let is_part_dump = is_preset_dump && (0x70..=0x73).contains(&message[2]);
+
+        if is_preset_dump || is_multi_dump {
+            self.status_communication = String::from("");
+        }
+
+        // Wait a little bit because the dump is possibly echoed by the DAW.
+        // Dumps of stored presets only carry their names and are not waited for,
+        // their requests are paced by the fast tick instead.
+        if is_part_dump || is_multi_dump {
+            std::thread::sleep(Duration::from_millis(100));
+        }
+
+        for response in self
+            .sync
+            .process_message(message, &self.app_state.preset_locks)
+        {
+            self.midi.send(&response);
+        }
+
+        if let Some((end, preset_id)) = self.morph.requested {
+            if is_preset_dump && message[2] == preset_id {
+                if let Some((name, values)) = midi::sysex::decode_preset_dump(message) {
+                    self.morph.set_point(
+                        end,
+                        MorphPoint {
+                            label: format!("Preset {:02} {}", preset_id, name.trim_end()),
+                            values,
+                        },
+                    );
+                }
+            }
+        }
+
+        if is_part_dump && message[2] == 0x70 + self.part_id {
+            if let Some(file) = self.preset_capture_file.take() {
+                log::debug!("Preset dump captured in file {}", file);
+                let mut message: Vec<u8> = message.to_vec();
+                message[2] = 0x70;
+                std::fs::write(file, message).ok();
+            }
+        }
     }
 }
 
@@ -1,6 +1,18 @@
 //! Application messages definitions
 
-use crate::params::{MultiParameter, SoundParameter};
+use std::time::Instant;
+
+use crate::compare::Slot;
+use crate::macros::{MacroCc, MacroCurve};
+use crate::midi::monitor::MonitorFilter;
+use crate::morph::{MorphEnd, MorphSource};
+use crate::params::{MultiParameter, SoundParameter, SoundSection};
+use crate::randomizer::RandomizeScope;
+use crate::ui::elements::control_style_list::ControlStyle;
+use crate::ui::elements::link_mode_list::LinkMode;
+use crate::ui::elements::page_list::Page;
+use crate::ui::elements::value_entry::ValueTarget;
+use crate::xy_pad::{XYAxis, XYTarget};
 
 #[derive(Debug, Clone)]
 pub enum Message {
@@ -10,12 +22,201 @@ pub enum Message {
     /// Modification of a sound (preset) parameter
     SoundParameterChange(SoundParameter, i32),
 
+    /// Modification of a sound (preset) parameter of a specific part
+    PartParameterChange(u8, SoundParameter, i32),
+
     /// Modification of a a multi parameter
     MultiParameterChange(MultiParameter, i32),
 
+    /// End of a parameter edit, e.g. release of a slider
+    ParameterEditFinished,
+
+    /// Several messages processed in order, e.g. a change followed by the end of the edit
+    Sequence(Vec<Message>),
+
+    /// Undo the most recent edit
+    Undo,
+
+    /// Redo the most recently undone edit
+    Redo,
+
+    /// Activate an A/B compare slot of the current part
+    CompareSelect(Slot),
+
+    /// Copy compare slot A to slot B of the current part
+    CompareCopyAToB,
+
+    /// Change of the randomizer scope via the dropdown menu
+    RandomizerScopeChange(RandomizeScope),
+
+    /// Change of the randomizer amount in percent
+    RandomizerAmountChange(i32),
+
+    /// Lock or unlock a parameter for the randomizer
+    RandomizerLockToggle(SoundParameter, bool),
+
+    /// Toggle of a parameter kept when loading a preset
+    PresetLockToggle(SoundParameter, bool),
+
+    /// Change of the control style of a sound section
+    ControlStyleChange(SoundSection, ControlStyle),
+
+    /// Change of the control style of all sound sections
+    ControlStyleChangeAll(ControlStyle),
+
+    /// Selection of a theme by name
+    ThemeChange(String),
+
+    /// Reload the user themes from file
+    ThemeReload,
+
+    /// XY pad dragged to a position, 0.0-1.0 on both axes
+    XYPadMove(f32, f32),
+
+    /// XY pad released after dragging
+    XYPadRelease,
+
+    /// Change of the parameter controlled by an XY pad axis
+    XYPadTargetChange(XYAxis, XYTarget),
+
+    /// Toggle of the XY pad spring-back
+    XYPadSpringToggle(bool),
+
+    /// Start or stop recording an XY pad gesture
+    XYPadRecordToggle,
+
+    /// Start or stop the looping playback of the XY pad gesture
+    XYPadPlayToggle(bool),
+
+    /// Add a macro after the button was pressed
+    MacroAdd,
+
+    /// Remove a macro after the button was pressed
+    MacroRemove(usize),
+
+    /// Change of a macro name
+    MacroNameChange(usize, String),
+
+    /// Change of the CC controlling a macro
+    MacroCcChange(usize, MacroCc),
+
+    /// Change of a macro value via the macro strip
+    MacroValueChange(usize, i32),
+
+    /// Add a target to a macro after the button was pressed
+    MacroTargetAdd(usize),
+
+    /// Remove a target from a macro after the button was pressed
+    MacroTargetRemove(usize, usize),
+
+    /// Change of the parameter driven by a macro target
+    MacroTargetParamChange(usize, usize, SoundParameter),
+
+    /// Change of the value of a macro target at macro value 0
+    MacroTargetMinChange(usize, usize, i32),
+
+    /// Change of the value of a macro target at the maximum macro value
+    MacroTargetMaxChange(usize, usize, i32),
+
+    /// Change of the curve of a macro target
+    MacroTargetCurveChange(usize, usize, MacroCurve),
+
+    /// Randomize the sound of the current part after the button was pressed
+    Randomize,
+
+    /// Select the start or end sound of the morph
+    MorphSetPoint(MorphEnd, MorphSource),
+
+    /// Change of the morph position
+    MorphPositionChange(i32),
+
+    /// Add the sound of the current part to the breeding parents
+    BreedingAddCurrent,
+
+    /// Add sounds from files to the breeding parents after the button was pressed
+    BreedingAddFiles,
+
+    /// Remove a breeding parent
+    BreedingRemoveParent(usize),
+
+    /// Remove all breeding parents
+    BreedingClearParents,
+
+    /// Change of the breeding mutation amount in percent
+    BreedingMutationChange(i32),
+
+    /// Generate a new population from the breeding parents
+    Breed,
+
+    /// Make the kept children the parents and generate the next population
+    BreedingNextGeneration,
+
+    /// Load a child into the current part
+    BreedingAudition(usize),
+
+    /// Keep or discard a child as parent of the next generation
+    BreedingKeepToggle(usize, bool),
+
+    /// Save a child as sysex file after the button was pressed
+    BreedingSaveChild(usize),
+
+    /// Copy a section of the current part to its twin section
+    SectionCopyToTwin(SoundSection),
+
+    /// Exchange a section of the current part with its twin section
+    SectionSwapWithTwin(SoundSection),
+
+    /// Copy a section of the current part to another part
+    SectionCopyToPart(SoundSection, u8),
+
+    /// Copy the sound of the current part to another part
+    SoundCopyToPart(u8),
+
+    /// Animation timer event of the XY pad
+    AnimationTick(Instant),
+
+    /// Start entering a value as text after the value display was clicked
+    ValueEntryStart(ValueTarget, i32),
+
+    /// Change of the entered value text
+    ValueEntryChange(String),
+
+    /// Apply the entered value after enter was pressed
+    ValueEntrySubmit,
+
+    /// Cancel entering a value
+    ValueEntryCancel,
+
+    /// Open the command palette
+    PaletteOpen,
+
+    /// Change of the command palette search text
+    PaletteChange(String),
+
+    /// Apply the highlighted command palette result after enter was pressed
+    PaletteSubmit,
+
+    /// Apply a command palette result after it was clicked
+    PalettePick(usize),
+
+    /// Close the command palette
+    PaletteClose,
+
     /// Change of the selected part via the dropdown menu
     PartChange(u8),
 
+    /// Change of the displayed page via the dropdown menu
+    PageChange(Page),
+
+    /// Enable or disable linked editing of parts
+    LinkEnable(bool),
+
+    /// Include or exclude a part from linked editing
+    LinkPartToggle(u8, bool),
+
+    /// Change of the linked editing mode via the dropdown menu
+    LinkModeChange(LinkMode),
+
     /// A new MIDI merge input was selected from the dropdown menu
     MergeInputChange(String),
 
@@ -28,6 +229,24 @@ pub enum Message {
     /// Save sysex after the button was pressed
     SavePresetSysexFile,
 
+    /// Change of the MIDI monitor filter settings
+    MonitorFilterChange(MonitorFilter),
+
+    /// Pause or resume the MIDI monitor capture
+    MonitorTogglePause,
+
+    /// Clear the MIDI monitor capture
+    MonitorClear,
+
+    /// Export the MIDI monitor capture as sysex file after the button was pressed
+    MonitorExportSysex,
+
+    /// Export the MIDI monitor capture as text file after the button was pressed
+    MonitorExportText,
+
+    /// Start or stop the trace recording after the button was pressed
+    MonitorToggleTrace,
+
     /// Regular tick in 1s intervals
     Tick,
 
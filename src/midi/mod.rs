@@ -1,13 +1,30 @@
 //! Module containing all MIDI-related code
 
+pub mod monitor;
+pub mod sync;
 pub mod sysex;
+pub mod trace;
+pub mod virtual_device;
 
 use std::sync::mpsc;
 
 use midir::{MidiInput, MidiInputConnection, MidiOutput, MidiOutputConnection};
 
+use monitor::{Direction, MidiMonitor};
+use trace::{Trace, TracePlayer, TraceRecorder};
+use virtual_device::VirtualDevice;
+
 type MpscChannel = (mpsc::Sender<Vec<u8>>, mpsc::Receiver<Vec<u8>>);
 
+/// Replacement for the hardware device
+pub enum Emulation {
+    /// Replay of a recorded trace
+    Replay(TracePlayer),
+
+    /// In-memory device
+    Device(VirtualDevice),
+}
+
 /// Container for connections and state
 pub struct MidiConnector {
     /// Output connection to the device
@@ -28,6 +45,19 @@ pub struct MidiConnector {
 
     /// Merge input connection
     merge_input: Option<MidiInputConnection<OnReceiveArgs>>,
+
+    /// Port names of the device and merge input connections
+    device_port_name: String,
+    merge_port_name: String,
+
+    /// Capture of all exchanged messages
+    monitor: MidiMonitor,
+
+    /// Recording of a trace, if active
+    trace_recorder: Option<TraceRecorder>,
+
+    /// Emulation used instead of the hardware device, if any
+    emulation: Option<Emulation>,
 }
 
 impl MidiConnector {
@@ -41,9 +71,25 @@ impl MidiConnector {
             scan_output: None,
             merge_inputs_list: Vec::new(),
             merge_input: None,
+            device_port_name: String::new(),
+            merge_port_name: String::new(),
+            monitor: MidiMonitor::new(),
+            trace_recorder: None,
+            emulation: None,
         }
     }
 
+    /// Constructs a new instance using an emulation instead of the hardware device
+    pub fn with_emulation(emulation: Emulation) -> Self {
+        let mut connector = Self::new();
+        connector.device_port_name = String::from(match emulation {
+            Emulation::Replay(_) => "Trace replay",
+            Emulation::Device(_) => "Virtual device",
+        });
+        connector.emulation = Some(emulation);
+        connector
+    }
+
     /// Scans the ports and establishes a connection to the device if found
     pub fn scan_ports(&mut self) {
         if self.scan_input.is_none() {
@@ -75,9 +121,10 @@ impl MidiConnector {
 
             for port in input.ports().iter() {
                 let port_name = cleanup_port_name(input.port_name(port).unwrap());
-                if port_name.contains("Tooro") {
+                if port_name.contains("Tooro") && self.emulation.is_none() {
                     if self.device_input.is_none() {
                         log::info!("MIDI input connected to port {}", port_name);
+                        self.device_port_name = port_name;
                         let on_receive_args = OnReceiveArgs {
                             sender: Some(self.device_input_mpsc_channel.0.clone()),
                         };
@@ -117,7 +164,7 @@ impl MidiConnector {
 
             for port in output.ports().iter() {
                 let port_name = cleanup_port_name(output.port_name(port).unwrap());
-                if port_name.contains("Tooro") {
+                if port_name.contains("Tooro") && self.emulation.is_none() {
                     if self.device_output.is_none() {
                         log::info!("MIDI output connected to port {}", port_name);
                         self.device_output = Some(
@@ -142,26 +189,63 @@ impl MidiConnector {
 
     /// Sends a message
     pub fn send(&mut self, message: &[u8]) {
-        if let Some(conn) = self.device_output.as_mut() {
-            conn.send(message).ok();
-        }
+        self.transmit(Direction::ToDevice, message);
+    }
+
+    /// Forwards a message from the merge input to the device
+    pub fn forward(&mut self, message: &[u8]) {
+        self.transmit(Direction::FromMerge, message);
     }
 
     /// Receives a message
     pub fn receive(&mut self) -> Option<Vec<u8>> {
-        let receiver = &self.device_input_mpsc_channel.1;
-        let result = receiver.try_recv();
+        let message = match self.emulation.as_mut() {
+            Some(Emulation::Replay(player)) => player.receive()?,
+            Some(Emulation::Device(device)) => device.receive()?,
+            None => self.device_input_mpsc_channel.1.try_recv().ok()?,
+        };
 
-        if result.is_err() {
-            return None;
-        }
+        self.record(Direction::FromDevice, &message);
+
+        Some(message)
+    }
+
+    /// Returns the capture of exchanged messages
+    pub fn monitor(&self) -> &MidiMonitor {
+        &self.monitor
+    }
+
+    /// Returns the capture of exchanged messages for modification
+    pub fn monitor_mut(&mut self) -> &mut MidiMonitor {
+        &mut self.monitor
+    }
 
-        Some(result.unwrap())
+    /// Starts recording a trace
+    pub fn start_trace(&mut self) {
+        self.trace_recorder = Some(TraceRecorder::new());
+    }
+
+    /// Stops recording a trace and returns it
+    pub fn stop_trace(&mut self) -> Option<Trace> {
+        self.trace_recorder.take().map(TraceRecorder::finish)
+    }
+
+    /// Returns true if a trace is recorded
+    pub fn is_tracing(&self) -> bool {
+        self.trace_recorder.is_some()
+    }
+
+    /// Returns the mismatches once after a trace replay is complete
+    pub fn take_replay_result(&mut self) -> Option<Vec<String>> {
+        match self.emulation.as_mut() {
+            Some(Emulation::Replay(player)) => player.take_result(),
+            _ => None,
+        }
     }
 
     /// Returns the device connection state
     pub fn is_connected(&self) -> bool {
-        self.device_input.is_some() && self.device_output.is_some()
+        self.emulation.is_some() || (self.device_input.is_some() && self.device_output.is_some())
     }
 
     /// Return a vector of inputs that are suitable for merging
@@ -193,6 +277,7 @@ impl MidiConnector {
             let port_name = cleanup_port_name(input.port_name(port).unwrap());
             if port_name == input_name {
                 log::info!("Merge MIDI input connected to port {}", port_name);
+                self.merge_port_name = port_name;
                 let on_receive_args = OnReceiveArgs {
                     sender: Some(sender),
                 };
@@ -207,6 +292,38 @@ impl MidiConnector {
             }
         }
     }
+
+    /// Sends a message to the device or its emulation
+    fn transmit(&mut self, direction: Direction, message: &[u8]) {
+        if self.device_output.is_none() && self.emulation.is_none() {
+            return;
+        }
+
+        self.record(direction, message);
+
+        match self.emulation.as_mut() {
+            Some(Emulation::Replay(player)) => player.send(message),
+            Some(Emulation::Device(device)) => device.send(message),
+            None => {
+                if let Some(conn) = self.device_output.as_mut() {
+                    conn.send(message).ok();
+                }
+            }
+        }
+    }
+
+    /// Adds a message to the monitor capture and the trace
+    fn record(&mut self, direction: Direction, message: &[u8]) {
+        self.monitor.record(direction, message);
+
+        if let Some(recorder) = self.trace_recorder.as_mut() {
+            let port = match direction {
+                Direction::FromMerge => &self.merge_port_name,
+                _ => &self.device_port_name,
+            };
+            recorder.record(direction, port, message);
+        }
+    }
 }
 
 /// Arguments for on_receive() callback function
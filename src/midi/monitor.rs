@@ -0,0 +1,217 @@
+//! Capture of the MIDI traffic for the monitor view
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use super::sysex;
+
+/// Maximum number of entries kept in the capture
+const MAX_ENTRIES: usize = 5000;
+
+/// Direction of a captured message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Direction {
+    /// Message sent to the device
+    ToDevice,
+
+    /// Message received from the device
+    FromDevice,
+
+    /// Message received on the merge input and forwarded to the device
+    FromMerge,
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Direction::ToDevice => "Out",
+                Direction::FromDevice => "In",
+                Direction::FromMerge => "Merge",
+            }
+        )
+    }
+}
+
+/// A single captured message
+#[derive(Debug, Clone)]
+pub struct MonitorEntry {
+    /// Time since the start of the capture
+    pub time: Duration,
+
+    /// Direction of the message
+    pub direction: Direction,
+
+    /// Raw message data
+    pub data: Vec<u8>,
+}
+
+impl MonitorEntry {
+    /// Return true if the message is a sysex message
+    pub fn is_sysex(&self) -> bool {
+        self.data.first() == Some(&0xF0)
+    }
+
+    /// Return the message bytes in hex notation, shortened to `max_bytes`
+    pub fn hex(&self, max_bytes: usize) -> String {
+        let mut result = self
+            .data
+            .iter()
+            .take(max_bytes)
+            .map(|b| format!("{:02X}", b))
+            .collect::<Vec<String>>()
+            .join(" ");
+        if self.data.len() > max_bytes {
+            result.push_str(" ...");
+        }
+        result
+    }
+
+    /// Return the decoded message in human terms
+    pub fn description(&self) -> String {
+        sysex::describe(&self.data)
+    }
+
+    /// Return a line of text describing the entry
+    pub fn to_text(&self) -> String {
+        format!(
+            "{:10.3}  {:<5}  {}  [{}]",
+            self.time.as_secs_f64(),
+            self.direction,
+            self.description(),
+            self.hex(usize::MAX)
+        )
+    }
+}
+
+/// Filter settings for the monitor view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonitorFilter {
+    /// Show messages sent to the device
+    pub to_device: bool,
+
+    /// Show messages received from the device
+    pub from_device: bool,
+
+    /// Show messages received on the merge input
+    pub from_merge: bool,
+
+    /// Show sysex messages
+    pub sysex: bool,
+
+    /// Show all other messages
+    pub other: bool,
+}
+
+impl Default for MonitorFilter {
+    fn default() -> Self {
+        Self {
+            to_device: true,
+            from_device: true,
+            from_merge: true,
+            sysex: true,
+            other: true,
+        }
+    }
+}
+
+impl MonitorFilter {
+    /// Return true if the entry passes the filter
+    pub fn matches(&self, entry: &MonitorEntry) -> bool {
+        let direction = match entry.direction {
+            Direction::ToDevice => self.to_device,
+            Direction::FromDevice => self.from_device,
+            Direction::FromMerge => self.from_merge,
+        };
+        let kind = if entry.is_sysex() {
+            self.sysex
+        } else {
+            self.other
+        };
+
+        direction && kind
+    }
+}
+
+/// Capture of all MIDI messages exchanged with the device
+pub struct MidiMonitor {
+    /// Start time of the capture
+    start: Instant,
+
+    /// Captured entries, oldest first
+    entries: VecDeque<MonitorEntry>,
+
+    /// Flag for paused capture
+    paused: bool,
+}
+
+impl MidiMonitor {
+    /// Constructs a new instance
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            entries: VecDeque::new(),
+            paused: false,
+        }
+    }
+
+    /// Add a message to the capture unless it is paused
+    pub fn record(&mut self, direction: Direction, data: &[u8]) {
+        if self.paused {
+            return;
+        }
+
+        if self.entries.len() >= MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+
+        self.entries.push_back(MonitorEntry {
+            time: self.start.elapsed(),
+            direction,
+            data: data.to_vec(),
+        });
+    }
+
+    /// Return an iterator over the captured entries
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &MonitorEntry> {
+        self.entries.iter()
+    }
+
+    /// Remove all entries and restart the time base
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.start = Instant::now();
+    }
+
+    /// Return the pause state
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Pause or resume the capture
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    /// Return all sysex messages passing the filter concatenated as .syx data
+    pub fn export_sysex(&self, filter: &MonitorFilter) -> Vec<u8> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.is_sysex() && filter.matches(entry))
+            .flat_map(|entry| entry.data.iter().copied())
+            .collect()
+    }
+
+    /// Return all entries passing the filter as text, one line per entry
+    pub fn export_text(&self, filter: &MonitorFilter) -> String {
+        self.entries
+            .iter()
+            .filter(|entry| filter.matches(entry))
+            .map(|entry| entry.to_text() + "\n")
+            .collect()
+    }
+}
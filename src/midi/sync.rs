@@ -0,0 +1,546 @@
+//! Synchronisation of the editor with the device
+//!
+//! Holds the editor's copy of the device state, i.e. the sound parameters
+//! of all parts, the multi parameters and the names of the stored presets,
+//! and schedules the dump requests keeping it up to date. The state does not
+//! depend on the user interface, so the synchronisation can be checked
+//! against recorded traces and the in-memory device.
+
+use std::collections::{HashSet, VecDeque};
+use std::time::{Duration, Instant};
+
+use super::sysex;
+use crate::params::{
+    GetValue, MultiParameter, MultiParameterValues, SoundParameter, SoundParameterValues,
+    PART_COUNT, PRESET_COUNT,
+};
+
+/// Time after which a request without response is given up
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Dump requested from the device
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Request {
+    /// Sound parameters of a part, part id 0-3
+    Sound(u8),
+
+    /// Multi parameters
+    Multi,
+
+    /// Sound of a stored preset, preset id 0-99
+    Preset(u8),
+
+    /// Name of a stored preset, preset id 0-99
+    PresetName(u8),
+}
+
+impl Request {
+    /// Return the request message
+    pub fn message(&self) -> Vec<u8> {
+        match *self {
+            Request::Sound(part_id) => sysex::preset_request(0x70 + part_id),
+            Request::Multi => sysex::multi_request(0x7F),
+            Request::Preset(preset_id) | Request::PresetName(preset_id) => {
+                sysex::preset_request(preset_id)
+            }
+        }
+    }
+}
+
+/// Editor's copy of the device state
+pub struct DeviceSync {
+    /// Sound (preset) parameter values of all parts
+    pub part_params: [SoundParameterValues; PART_COUNT],
+
+    /// Flags for parts with sound parameters received from the device at least once
+    pub part_params_valid: [bool; PART_COUNT],
+
+    /// Preset names of all parts
+    pub part_names: [String; PART_COUNT],
+
+    /// Last preset dumps received for all parts, holding the device settings
+    /// not modelled by the editor
+    pub part_dumps: [Option<Vec<u8>>; PART_COUNT],
+
+    /// Names of the stored presets, empty if not received yet
+    pub preset_names: Vec<String>,
+
+    /// Current multi parameter values
+    pub multi_params: MultiParameterValues,
+
+    /// Flags for requested sound (preset) parameter updates from device, one per part
+    pub request_sound_update: [bool; PART_COUNT],
+
+    /// Values of the locked parameters of parts loading a new preset, taken
+    /// before the preset changed and restored when its sound is received
+    pub lock_restore: [Option<Vec<(SoundParameter, i32)>>; PART_COUNT],
+
+    /// Flag for requested multi parameter update from device
+    pub request_multi_update: bool,
+
+    /// Ids of the stored presets whose sounds are still to be requested
+    request_presets: VecDeque<u8>,
+
+    /// Ids of the stored presets whose names are still to be requested
+    request_preset_names: VecDeque<u8>,
+
+    /// Time of last dump request
+    request_time: Option<Instant>,
+}
+
+impl DeviceSync {
+    /// Constructs a new instance without any values received
+    pub fn new() -> Self {
+        Self {
+            part_params: std::array::from_fn(|_| SoundParameterValues::with_capacity(128)),
+            part_params_valid: [false; PART_COUNT],
+            part_names: Default::default(),
+            part_dumps: Default::default(),
+            preset_names: vec![String::new(); PRESET_COUNT],
+            multi_params: MultiParameterValues::with_capacity(32),
+            request_sound_update: [false; PART_COUNT],
+            lock_restore: Default::default(),
+            request_multi_update: false,
+            request_presets: VecDeque::new(),
+            request_preset_names: VecDeque::new(),
+            request_time: None,
+        }
+    }
+
+    /// Return a preset dump of the current sound of a part
+    ///
+    /// The dump is based on the last one received from the device, so the
+    /// settings not modelled by the editor are kept. Returns `None` if no
+    /// dump of the part was received yet.
+    ///
+    /// - `part_id`     Part id 0-3
+    pub fn part_dump(&self, part_id: u8) -> Option<Vec<u8>> {
+        let part = part_id as usize;
+        self.part_dumps[part].as_ref().and_then(|dump| {
+            sysex::patch_preset_dump(
+                dump,
+                0x70 + part_id,
+                &self.part_params[part],
+                &self.part_names[part],
+            )
+        })
+    }
+
+    /// Request the sounds of all parts and the multi parameters
+    pub fn request_all(&mut self) {
+        self.request_sound_update = [true; PART_COUNT];
+        self.request_multi_update = true;
+    }
+
+    /// Request the names of stored presets
+    pub fn request_preset_names(&mut self, preset_ids: impl IntoIterator<Item = u8>) {
+        self.request_preset_names.extend(preset_ids);
+    }
+
+    /// Request the sound of a stored preset, e.g. as a morph point
+    pub fn request_preset(&mut self, preset_id: u8) {
+        if !self.request_presets.contains(&preset_id) {
+            self.request_presets.push_back(preset_id);
+        }
+    }
+
+    /// Drop all pending requests, e.g. when the device is disconnected
+    pub fn cancel_requests(&mut self) {
+        self.request_sound_update = [false; PART_COUNT];
+        self.lock_restore = Default::default();
+        self.request_multi_update = false;
+        self.request_presets.clear();
+        self.request_preset_names.clear();
+        self.request_time = None;
+    }
+
+    /// Request the sounds of all parts whose preset assignment differs from the previous one
+    ///
+    /// - `last_params` Multi parameters before the change
+    /// - `locks`       Parameters keeping their value when a preset is loaded
+    pub fn request_changed_parts(
+        &mut self,
+        last_params: &MultiParameterValues,
+        locks: &HashSet<SoundParameter>,
+    ) {
+        let changed: Vec<u8> = self
+            .multi_params
+            .iter()
+            .filter_map(|(param, value)| {
+                let part_id = param.get_preset_part()?;
+                let last = last_params.get(param)?;
+                (last != value).then(|| {
+                    log::debug!("Preset of part {} changed to {}", part_id + 1, value);
+                    part_id
+                })
+            })
+            .collect();
+
+        for part_id in changed {
+            self.request_sound_update[part_id as usize] = true;
+            self.request_lock_restore(part_id, locks);
+        }
+    }
+
+    /// Keep the current values of the locked parameters of a part loading a new preset
+    ///
+    /// The values are restored when the next sound of the part is received.
+    /// Values kept before are not replaced, as the part may already hold
+    /// parameters of the new preset.
+    ///
+    /// - `part_id`     Part id 0-3
+    /// - `locks`       Parameters keeping their value when a preset is loaded
+    pub fn request_lock_restore(&mut self, part_id: u8, locks: &HashSet<SoundParameter>) {
+        let part = part_id as usize;
+        if self.part_params_valid[part] && self.lock_restore[part].is_none() {
+            self.lock_restore[part] = Some(self.locked_values(part_id, locks));
+        }
+    }
+
+    /// Return the current values of the locked parameters of a part
+    pub fn locked_values(
+        &self,
+        part_id: u8,
+        locks: &HashSet<SoundParameter>,
+    ) -> Vec<(SoundParameter, i32)> {
+        let part_params = &self.part_params[part_id as usize];
+
+        locks
+            .iter()
+            .map(|param| (*param, part_params.get_value(*param)))
+            .collect()
+    }
+
+    /// Return the request to be sent next, `None` while waiting for a response
+    ///
+    /// Part sounds are requested first, the current part preferred, followed
+    /// by the multi parameters, the stored presets and the preset names.
+    ///
+    /// - `part_id`     Current part id 0-3
+    pub fn next_request(&mut self, part_id: u8) -> Option<Request> {
+        if self.request_time.is_some() {
+            return None;
+        }
+
+        let request = if let Some(part_id) = self.next_sound_update(part_id) {
+            self.request_sound_update[part_id as usize] = false;
+            Request::Sound(part_id)
+        } else if self.request_multi_update {
+            self.request_multi_update = false;
+            Request::Multi
+        } else if let Some(preset_id) = self.request_presets.pop_front() {
+            Request::Preset(preset_id)
+        } else {
+            Request::PresetName(self.request_preset_names.pop_front()?)
+        };
+
+        self.request_time = Some(Instant::now());
+
+        Some(request)
+    }
+
+    /// Give up a pending request after the timeout, returns `true` if it timed out
+    pub fn check_timeout(&mut self) -> bool {
+        match self.request_time {
+            Some(request_time) if request_time.elapsed() >= REQUEST_TIMEOUT => {
+                self.request_time = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Process a message received from the device
+    ///
+    /// - `message`     Received message
+    /// - `locks`       Parameters keeping their value when a preset is loaded
+    ///
+    /// Returns the messages to be sent to the device in response.
+    pub fn process_message(
+        &mut self,
+        message: &[u8],
+        locks: &HashSet<SoundParameter>,
+    ) -> Vec<Vec<u8>> {
+        match message[0] {
+            0xB0..=0xCF => {
+                // A CC or program change sent by the device changes the parts
+                // receiving on its channel, only those are requested again
+                self.request_parts_on_channel(message[0] & 0x0F, locks);
+                self.request_multi_update = true;
+                Vec::new()
+            }
+
+            0xF0 => {
+                // Sysex
+                match message[1] {
+                    sysex::SERVICE_PRESET_DUMP if message.len() == sysex::PRESET_DUMP_LENGTH => {
+                        self.process_preset_dump(message)
+                    }
+                    sysex::SERVICE_MULTI_DUMP if message.len() == sysex::MULTI_DUMP_LENGTH => {
+                        self.process_multi_dump(message, locks);
+                        Vec::new()
+                    }
+                    sysex::SERVICE_PRESET_PARAMETER_DUMP => {
+                        self.process_preset_param_dump(message);
+                        Vec::new()
+                    }
+                    sysex::SERVICE_MULTI_PARAMETER_DUMP => {
+                        self.process_multi_param_dump(message, locks);
+                        Vec::new()
+                    }
+                    _ => Vec::new(),
+                }
+            }
+
+            _ => Vec::new(),
+        }
+    }
+
+    /// Return the part to be requested next from the device
+    ///
+    /// The current part is preferred, followed by parts never received before.
+    fn next_sound_update(&self, current: u8) -> Option<u8> {
+        if self.request_sound_update[current as usize] {
+            return Some(current);
+        }
+
+        let pending = |part_id: &usize| self.request_sound_update[*part_id];
+
+        (0..PART_COUNT)
+            .filter(pending)
+            .find(|part_id| !self.part_params_valid[*part_id])
+            .or_else(|| (0..PART_COUNT).find(pending))
+            .map(|part_id| part_id as u8)
+    }
+
+    /// Process an incoming preset dump, returns the messages restoring locked parameters
+    fn process_preset_dump(&mut self, message: &[u8]) -> Vec<Vec<u8>> {
+        let preset_id = message[2];
+        let mut responses = Vec::new();
+
+        log::debug!("Preset dump received with id {:#X}", preset_id);
+
+        match preset_id {
+            0..=99 => {
+                let param_values = sysex::unpack_data(&message[3..message.len()]);
+                self.preset_names[preset_id as usize] = sysex::preset_name(&param_values);
+            }
+            0x70..=0x73 => {
+                let part = (preset_id - 0x70) as usize;
+                let param_values = sysex::unpack_data(&message[3..message.len()]);
+                sysex::update_sound_params(&mut self.part_params[part], &param_values);
+                for (param, value) in self.lock_restore[part].take().unwrap_or_default() {
+                    if self.part_params[part].get_value(param) != value {
+                        log::debug!("Restored locked parameter {:?} of part {}", param, part + 1);
+                        self.part_params[part].insert(param, value);
+                        responses.push(sysex::preset_param_dump(preset_id, &param, value));
+                    }
+                }
+                self.part_params_valid[part] = true;
+                self.part_names[part] = sysex::preset_name(&param_values);
+                self.part_dumps[part] = Some(message.to_vec());
+            }
+            _ => {}
+        }
+
+        self.request_time = None;
+
+        responses
+    }
+
+    /// Request the sounds of the parts receiving on a MIDI channel and restore their locks
+    ///
+    /// - `channel`     Channel 0-15 as encoded in the status byte
+    /// - `locks`       Parameters keeping their value when a preset is loaded
+    fn request_parts_on_channel(&mut self, channel: u8, locks: &HashSet<SoundParameter>) {
+        for part_id in 0..PART_COUNT as u8 {
+            // Channel parameter 0 is omni, 1-16 for channel 1-16
+            let part_channel = self
+                .multi_params
+                .get_value(MultiParameter::from_channel_part(part_id));
+            if part_channel == 0 || part_channel == channel as i32 + 1 {
+                self.request_sound_update[part_id as usize] = true;
+                self.request_lock_restore(part_id, locks);
+            }
+        }
+    }
+
+    /// Apply an incoming sound parameter change of a part
+    fn process_preset_param_dump(&mut self, message: &[u8]) {
+        if let Some((preset_id @ 0x70..=0x73, param, value)) =
+            sysex::decode_preset_param_dump(message)
+        {
+            let part = (preset_id - 0x70) as usize;
+            log::debug!(
+                "Parameter {:?} of part {} changed to {}",
+                param,
+                part + 1,
+                value
+            );
+            self.part_params[part].insert(param, value);
+        }
+    }
+
+    /// Apply an incoming multi parameter change
+    fn process_multi_param_dump(&mut self, message: &[u8], locks: &HashSet<SoundParameter>) {
+        if let Some((param, value)) = sysex::decode_multi_param_dump(message) {
+            log::debug!("Multi parameter {:?} changed to {}", param, value);
+            let last_params = self.multi_params.clone();
+            self.multi_params.insert(param, value);
+            self.request_changed_parts(&last_params, locks);
+        }
+    }
+
+    /// Process an incoming multi dump
+    fn process_multi_dump(&mut self, message: &[u8], locks: &HashSet<SoundParameter>) {
+        let multi_id = message[2];
+
+        log::debug!("Multi dump received with id {:#X}", multi_id);
+
+        if multi_id == 0x7F {
+            let last_params = self.multi_params.clone();
+            let param_values = sysex::unpack_data(&message[3..message.len()]);
+            sysex::update_multi_params(&mut self.multi_params, &param_values);
+
+            // Reload the parts whose preset assignment was changed on the device
+            self.request_changed_parts(&last_params, locks);
+        }
+
+        self.request_time = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midi::virtual_device::VirtualDevice;
+
+    /// Return a dump of part 1 as sent by the in-memory device
+    fn device_part_dump() -> Vec<u8> {
+        let mut device = VirtualDevice::new();
+        device.send(&sysex::preset_request(0x70));
+        device.receive().unwrap()
+    }
+
+    /// Return an instance with all parts and the multi received
+    fn synced(channels: [i32; PART_COUNT]) -> DeviceSync {
+        let mut sync = DeviceSync::new();
+        sync.part_params_valid = [true; PART_COUNT];
+        for (part_id, channel) in channels.iter().enumerate() {
+            sync.multi_params
+                .insert(MultiParameter::from_channel_part(part_id as u8), *channel);
+        }
+        sync
+    }
+
+    #[test]
+    fn cc_requests_parts_on_its_channel() {
+        let mut sync = synced([1, 2, 2, 3]);
+
+        sync.process_message(&[0xB1, 74, 100], &HashSet::new());
+
+        assert_eq!(sync.request_sound_update, [false, true, true, false]);
+        assert!(sync.request_multi_update);
+    }
+
+    #[test]
+    fn program_change_requests_omni_parts() {
+        let mut sync = synced([0, 2, 3, 4]);
+
+        sync.process_message(&[0xC3, 5], &HashSet::new());
+
+        assert_eq!(sync.request_sound_update, [true, false, false, true]);
+    }
+
+    #[test]
+    fn param_dump_updates_cache_without_request() {
+        let mut sync = synced([1, 2, 3, 4]);
+        let message = sysex::preset_param_dump(0x72, &SoundParameter::FilterCutoff, 123);
+
+        sync.process_message(&message, &HashSet::new());
+
+        assert_eq!(
+            sync.part_params[2].get_value(SoundParameter::FilterCutoff),
+            123
+        );
+        assert_eq!(sync.request_sound_update, [false; PART_COUNT]);
+        assert!(!sync.request_multi_update);
+    }
+
+    #[test]
+    fn stored_preset_is_requested_before_names() {
+        let mut sync = synced([1, 2, 3, 4]);
+        sync.request_preset_names(0..3);
+        sync.request_preset(42);
+
+        assert_eq!(sync.next_request(0), Some(Request::Preset(42)));
+        sync.request_time = None;
+        assert_eq!(sync.next_request(0), Some(Request::PresetName(0)));
+    }
+
+    #[test]
+    fn program_change_restores_locks_after_part_dump() {
+        let mut sync = synced([1, 2, 3, 4]);
+        sync.part_params[0].insert(SoundParameter::FilterCutoff, 10);
+        sync.multi_params.insert(MultiParameter::PresetPart1, 0);
+        let locks = HashSet::from([SoundParameter::FilterCutoff]);
+
+        sync.process_message(&[0xC0, 5], &locks);
+
+        let mut new_preset = SoundParameterValues::new();
+        new_preset.insert(SoundParameter::FilterCutoff, 99);
+        let dump = sysex::patch_preset_dump(&device_part_dump(), 0x70, &new_preset, "New").unwrap();
+        let responses = sync.process_message(&dump, &locks);
+
+        assert_eq!(
+            responses,
+            vec![sysex::preset_param_dump(
+                0x70,
+                &SoundParameter::FilterCutoff,
+                10
+            )]
+        );
+        assert_eq!(
+            sync.part_params[0].get_value(SoundParameter::FilterCutoff),
+            10
+        );
+
+        // The multi dump following the part dump keeps the restored value
+        let mut multi = sync.multi_params.clone();
+        multi.insert(MultiParameter::PresetPart1, 5);
+        sync.process_message(&sysex::multi_dump(0x7F, &multi), &locks);
+
+        assert_eq!(
+            sync.lock_restore[0],
+            Some(vec![(SoundParameter::FilterCutoff, 10)])
+        );
+    }
+
+    #[test]
+    fn part_dump_keeps_unmodelled_settings() {
+        // Word 80 is a device setting without an editor parameter
+        let dump = device_part_dump();
+        let mut data = sysex::unpack_data(&dump[3..dump.len()]);
+        data[160] = 42;
+        let mut dump = vec![0xF0, sysex::SERVICE_PRESET_DUMP, 0x70];
+        dump.append(&mut sysex::pack_data(&data));
+        dump.push(0xF7);
+
+        let mut sync = synced([1, 2, 3, 4]);
+        sync.process_message(&dump, &HashSet::new());
+        sync.part_params[0].insert(SoundParameter::FilterCutoff, 77);
+
+        let patched = sysex::patch_preset_dump(
+            sync.part_dumps[0].as_ref().unwrap(),
+            0x70,
+            &sync.part_params[0],
+            &sync.part_names[0],
+        )
+        .unwrap();
+        let data = sysex::unpack_data(&patched[3..patched.len()]);
+        assert_eq!(data[160], 42);
+        let (_, params) = sysex::decode_preset_dump(&patched).unwrap();
+        assert_eq!(params.get_value(SoundParameter::FilterCutoff), 77);
+        assert_eq!(sync.part_dumps[1], None);
+    }
+}
@@ -22,6 +22,10 @@ pub const PRESET_DUMP_LENGTH: usize = 264;
 pub const PRESET_PARAM_DUMP_LENGTH: usize = 8;
 pub const MULTI_PARAM_DUMP_LENGTH: usize = 8;
 
+// Location of the name in unpacked preset dump data
+const PRESET_NAME_INDEX: usize = 176;
+const PRESET_NAME_LENGTH: usize = 32;
+
 /// Return message for preset request
 ///
 /// - `preset_id`   Preset id, either 0..99 or 0x70..0x73
@@ -42,7 +46,34 @@ pub fn multi_request(multi_id: u8) -> Vec<u8> {
 /// - `param`       Sound parameter enum value
 /// - `value`       Sound parameter value
 pub fn preset_param_dump(preset_id: u8, param: &SoundParameter, value: i32) -> Vec<u8> {
-    let (id, value) = match param {
+    let (id, value) = preset_param_raw(param, value);
+
+    let id_low = id & 0x7F;
+    let id_high = (id >> 7) & 0x7F;
+    let value_low = (value & 0x7F) as u8;
+    let value_high = ((value >> 7) & 0x7F) as u8;
+
+    vec![
+        0xF0,
+        SERVICE_PRESET_PARAMETER_DUMP,
+        preset_id,
+        id_low,
+        id_high,
+        value_low,
+        value_high,
+        0xF7,
+    ]
+}
+
+/// Return the parameter id and the raw device value of a sound parameter
+///
+/// The parameter id also is the index of the value in the unpacked
+/// preset dump data, counted in 16-bit words.
+///
+/// - `param`       Sound parameter enum value
+/// - `value`       Sound parameter value
+fn preset_param_raw(param: &SoundParameter, value: i32) -> (u8, i32) {
+    match param {
         // Osc 1
         SoundParameter::Osc1Wave => (0, value * 4),
         SoundParameter::Osc1Coarse => (1, value),
@@ -147,7 +178,15 @@ pub fn preset_param_dump(preset_id: u8, param: &SoundParameter, value: i32) -> V
         SoundParameter::Tune => (84, value),
         SoundParameter::BendRange => (86, value),
         SoundParameter::PolyMode => (87, value),
-    };
+    }
+}
+
+/// Return message for multi parameter dump
+///
+/// - `param`       Multi parameter enum value
+/// - `value`       Multi parameter value
+pub fn multi_param_dump(param: &MultiParameter, value: i32) -> Vec<u8> {
+    let (id, value) = multi_param_raw(param, value);
 
     let id_low = id & 0x7F;
     let id_high = (id >> 7) & 0x7F;
@@ -156,8 +195,8 @@ pub fn preset_param_dump(preset_id: u8, param: &SoundParameter, value: i32) -> V
 
     vec![
         0xF0,
-        SERVICE_PRESET_PARAMETER_DUMP,
-        preset_id,
+        SERVICE_MULTI_PARAMETER_DUMP,
+        0,
         id_low,
         id_high,
         value_low,
@@ -166,12 +205,12 @@ pub fn preset_param_dump(preset_id: u8, param: &SoundParameter, value: i32) -> V
     ]
 }
 
-/// Return message for multi parameter dump
+/// Return the parameter id and the raw device value of a multi parameter
 ///
 /// - `param`       Multi parameter enum value
 /// - `value`       Multi parameter value
-pub fn multi_param_dump(param: &MultiParameter, value: i32) -> Vec<u8> {
-    let (id, value) = match param {
+fn multi_param_raw(param: &MultiParameter, value: i32) -> (u8, i32) {
+    match param {
         // Preset IDs
         MultiParameter::PresetPart1 => (0, value),
         MultiParameter::PresetPart2 => (1, value),
@@ -203,23 +242,280 @@ pub fn multi_param_dump(param: &MultiParameter, value: i32) -> Vec<u8> {
         MultiParameter::FXMode => (19, value),
         MultiParameter::FXSpeed => (20, value * 4),
         MultiParameter::FXDepth => (21, value * 4),
-    };
+    }
+}
 
-    let id_low = id & 0x7F;
-    let id_high = (id >> 7) & 0x7F;
-    let value_low = (value & 0x7F) as u8;
-    let value_high = ((value >> 7) & 0x7F) as u8;
+/// Decode a preset parameter dump message
+///
+/// Returns the preset id, the parameter and its value or `None` if the
+/// message is not a valid preset parameter dump.
+///
+/// - `message`     Complete sysex message
+pub fn decode_preset_param_dump(message: &[u8]) -> Option<(u8, SoundParameter, i32)> {
+    if message.len() != PRESET_PARAM_DUMP_LENGTH || message[1] != SERVICE_PRESET_PARAMETER_DUMP {
+        return None;
+    }
 
-    vec![
-        0xF0,
-        SERVICE_MULTI_PARAMETER_DUMP,
-        0,
-        id_low,
-        id_high,
-        value_low,
-        value_high,
-        0xF7,
-    ]
+    let id = message[3] as i32 | (message[4] as i32) << 7;
+    let value = value_from_param_dump(message);
+
+    let (param, value) = param_from_raw(&SoundParameter::ALL, preset_param_raw, id, value)?;
+
+    Some((message[2], param, value))
+}
+
+/// Decode a multi parameter dump message
+///
+/// Returns the parameter and its value or `None` if the message
+/// is not a valid multi parameter dump.
+///
+/// - `message`     Complete sysex message
+pub fn decode_multi_param_dump(message: &[u8]) -> Option<(MultiParameter, i32)> {
+    if message.len() != MULTI_PARAM_DUMP_LENGTH || message[1] != SERVICE_MULTI_PARAMETER_DUMP {
+        return None;
+    }
+
+    let id = message[3] as i32 | (message[4] as i32) << 7;
+    let value = value_from_param_dump(message);
+
+    param_from_raw(&MultiParameter::ALL, multi_param_raw, id, value)
+}
+
+/// Return the parameter with the given id and its value for a raw device value
+///
+/// The parameter and the scaling are looked up in the same mapping used for
+/// sending, so both directions stay consistent.
+///
+/// - `params`      All parameters
+/// - `raw`         Mapping of a parameter value to parameter id and raw device value
+/// - `id`          Parameter id
+/// - `value`       Raw device value
+fn param_from_raw<P: Copy>(
+    params: &[P],
+    raw: fn(&P, i32) -> (u8, i32),
+    id: i32,
+    value: i32,
+) -> Option<(P, i32)> {
+    let param = params.iter().find(|param| raw(param, 0).0 as i32 == id)?;
+    let (_, offset) = raw(param, 0);
+    let (_, step) = raw(param, 1);
+
+    Some((*param, (value - offset) / (step - offset)))
+}
+
+/// Return a human readable description of a MIDI message
+///
+/// - `message`     Complete MIDI message
+pub fn describe(message: &[u8]) -> String {
+    if message.is_empty() {
+        return String::from("Empty message");
+    }
+
+    let channel = (message[0] & 0x0F) + 1;
+
+    match message[0] {
+        0x80..=0x8F if message.len() == 3 => {
+            format!("Note off ch {}: {}", channel, note_name(message[1]))
+        }
+        0x90..=0x9F if message.len() == 3 => format!(
+            "Note on ch {}: {} velocity {}",
+            channel,
+            note_name(message[1]),
+            message[2]
+        ),
+        0xA0..=0xAF if message.len() == 3 => format!(
+            "Poly aftertouch ch {}: {} = {}",
+            channel,
+            note_name(message[1]),
+            message[2]
+        ),
+        0xB0..=0xBF if message.len() == 3 => {
+            format!(
+                "Control change ch {}: CC {} = {}",
+                channel, message[1], message[2]
+            )
+        }
+        0xC0..=0xCF if message.len() == 2 => {
+            format!("Program change ch {}: {}", channel, message[1])
+        }
+        0xD0..=0xDF if message.len() == 2 => {
+            format!("Channel aftertouch ch {}: {}", channel, message[1])
+        }
+        0xE0..=0xEF if message.len() == 3 => format!(
+            "Pitch bend ch {}: {}",
+            channel,
+            (message[1] as i32 | (message[2] as i32) << 7) - 8192
+        ),
+        0xF0 if message.len() >= 4 => describe_sysex(message),
+        0xF8 => String::from("Clock"),
+        0xFA => String::from("Start"),
+        0xFB => String::from("Continue"),
+        0xFC => String::from("Stop"),
+        0xFE => String::from("Active sensing"),
+        _ => String::from("Unknown message"),
+    }
+}
+
+/// Return a human readable description of a sysex message
+///
+/// - `message`     Complete sysex message
+fn describe_sysex(message: &[u8]) -> String {
+    match message[1] {
+        SERVICE_PRESET_REQUEST => format!("Preset request {}", preset_id_name(message[2])),
+        SERVICE_MULTI_REQUEST => format!("Multi request {}", multi_id_name(message[2])),
+        SERVICE_PRESET_DUMP if message.len() == PRESET_DUMP_LENGTH => {
+            format!("Preset dump {}", preset_id_name(message[2]))
+        }
+        SERVICE_MULTI_DUMP if message.len() == MULTI_DUMP_LENGTH => {
+            format!("Multi dump {}", multi_id_name(message[2]))
+        }
+        SERVICE_PRESET_PARAMETER_DUMP => match decode_preset_param_dump(message) {
+            Some((preset_id, param, value)) => format!(
+                "Preset param dump {}: {} = {}",
+                preset_id_name(preset_id),
+                param.get_name(),
+                value
+            ),
+            None => String::from("Invalid preset param dump"),
+        },
+        SERVICE_MULTI_PARAMETER_DUMP => match decode_multi_param_dump(message) {
+            Some((param, value)) => {
+                format!("Multi param dump: {} = {}", param.get_name(), value)
+            }
+            None => String::from("Invalid multi param dump"),
+        },
+        SERVICE_PRESET_PARAM_REQUEST => {
+            format!("Preset param request {}", preset_id_name(message[2]))
+        }
+        SERVICE_MULTI_PARAM_REQUEST => String::from("Multi param request"),
+        _ => format!("Sysex with unknown service id {:#04X}", message[1]),
+    }
+}
+
+/// Return a readable name for a preset id
+///
+/// - `preset_id`   Preset id, either 0..99 or 0x70..0x73
+fn preset_id_name(preset_id: u8) -> String {
+    match preset_id {
+        0x70..=0x73 => format!("part {}", preset_id - 0x70 + 1),
+        _ => format!("preset {}", preset_id),
+    }
+}
+
+/// Return a readable name for a multi id
+///
+/// - `multi_id`    Multi id, either 0..9 or 0x7F
+fn multi_id_name(multi_id: u8) -> String {
+    match multi_id {
+        0x7F => String::from("edit buffer"),
+        _ => format!("multi {}", multi_id),
+    }
+}
+
+/// Return the name of a MIDI note number, e.g. "C4" for 60
+///
+/// - `note`    MIDI note number
+fn note_name(note: u8) -> String {
+    const NAMES: [&str; 12] = [
+        "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+    ];
+    format!("{}{}", NAMES[(note % 12) as usize], note as i32 / 12 - 1)
+}
+
+/// Return the signed 14-bit value contained in a parameter dump
+///
+/// - `message`     Complete parameter dump message
+fn value_from_param_dump(message: &[u8]) -> i32 {
+    let value = message[5] as i32 | (message[6] as i32) << 7;
+    if value & 0x2000 != 0 {
+        value - 0x4000
+    } else {
+        value
+    }
+}
+
+/// Return a preset dump message carrying a sound
+///
+/// Only the parameters and the name known to the editor are replaced, all
+/// other device settings are kept from the given dump. Returns `None` if
+/// `dump` is not a valid preset dump.
+///
+/// - `dump`        Preset dump received from the device or read from a file
+/// - `preset_id`   Preset id, either 0..99 or 0x70..0x73
+/// - `params`      Sound parameter values
+/// - `name`        Preset name, truncated to 32 characters
+pub fn patch_preset_dump(
+    dump: &[u8],
+    preset_id: u8,
+    params: &SoundParameterValues,
+    name: &str,
+) -> Option<Vec<u8>> {
+    if dump.len() != PRESET_DUMP_LENGTH || dump[0] != 0xF0 || dump[1] != SERVICE_PRESET_DUMP {
+        return None;
+    }
+
+    let mut data = unpack_data(&dump[3..dump.len()]);
+
+    for param in SoundParameter::ALL {
+        let (id, value) = preset_param_raw(&param, params.get_value(param));
+        let index = id as usize * 2;
+        data[index..index + 2].copy_from_slice(&(value as i16).to_le_bytes());
+    }
+
+    // Name, padded with spaces
+    let name_data = &mut data[PRESET_NAME_INDEX..PRESET_NAME_INDEX + PRESET_NAME_LENGTH];
+    name_data.fill(32);
+    for (dest, c) in name_data.iter_mut().zip(name.bytes()) {
+        *dest = c;
+    }
+
+    // Build message
+    let mut message = Vec::with_capacity(PRESET_DUMP_LENGTH);
+    message.append(&mut vec![0xF0, SERVICE_PRESET_DUMP, preset_id]);
+    message.append(&mut pack_data(&data));
+    message.push(0xF7);
+
+    Some(message)
+}
+
+/// Return the preset name contained in unpacked preset dump data
+///
+/// - `values`  Raw values from unpacked sysex data
+pub fn preset_name(values: &[u8]) -> String {
+    values
+        .iter()
+        .skip(PRESET_NAME_INDEX)
+        .take(PRESET_NAME_LENGTH)
+        .map(|c| {
+            if c.is_ascii_graphic() {
+                *c as char
+            } else {
+                ' '
+            }
+        })
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/// Return the name and sound parameters contained in a preset dump message
+///
+/// Returns `None` if the message is not a valid preset dump.
+///
+/// - `message`     Complete preset dump message, e.g. read from a .syx file
+pub fn decode_preset_dump(message: &[u8]) -> Option<(String, SoundParameterValues)> {
+    if message.len() != PRESET_DUMP_LENGTH
+        || message[0] != 0xF0
+        || message[1] != SERVICE_PRESET_DUMP
+    {
+        return None;
+    }
+
+    let values = unpack_data(&message[3..message.len()]);
+    let mut params = SoundParameterValues::with_capacity(128);
+    update_sound_params(&mut params, &values);
+
+    Some((preset_name(&values), params))
 }
 
 /// Return message for multi dump
@@ -626,3 +922,20 @@ fn push_param(vector: &mut Vec<u8>, value: i32) {
     vector.push((value & 0xFF) as u8);
     vector.push((value >> 8) as u8);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn param_dumps_decode_to_the_sent_values() {
+        for param in SoundParameter::ALL {
+            let message = preset_param_dump(0x70, &param, 5);
+            assert_eq!(decode_preset_param_dump(&message), Some((0x70, param, 5)));
+        }
+        for param in MultiParameter::ALL {
+            let message = multi_param_dump(&param, 5);
+            assert_eq!(decode_multi_param_dump(&message), Some((param, 5)));
+        }
+    }
+}
@@ -0,0 +1,416 @@
+//! Recording and replay of the complete device communication
+//!
+//! A trace contains every message exchanged with the device together with
+//! its timing, direction and port. Traces are stored as RON files and can be
+//! replayed either against the editor in place of the hardware or against
+//! the in-memory device, so that reported problems can be reproduced
+//! without a Töörö connected.
+
+use std::collections::VecDeque;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use super::monitor::Direction;
+use super::sysex;
+use super::virtual_device::VirtualDevice;
+
+/// A single recorded message
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceEvent {
+    /// Time since the start of the recording in milliseconds
+    pub time_ms: u64,
+
+    /// Direction of the message
+    pub direction: Direction,
+
+    /// Name of the port the message was sent or received on
+    pub port: String,
+
+    /// Raw message data
+    pub data: Vec<u8>,
+}
+
+/// A recorded session
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trace {
+    /// Recorded messages in chronological order
+    pub events: Vec<TraceEvent>,
+}
+
+impl Trace {
+    /// Load a trace from a RON file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Box<dyn std::error::Error>> {
+        let s = std::fs::read_to_string(path)?;
+        Ok(ron::from_str(s.as_str())?)
+    }
+
+    /// Save the trace to a RON file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Box<dyn std::error::Error>> {
+        let s = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        std::fs::write(path, s)?;
+        Ok(())
+    }
+}
+
+/// Records messages into a trace
+pub struct TraceRecorder {
+    /// Start time of the recording
+    start: Instant,
+
+    /// Trace recorded so far
+    trace: Trace,
+}
+
+impl TraceRecorder {
+    /// Constructs a new instance and starts the recording
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            trace: Trace::default(),
+        }
+    }
+
+    /// Add a message to the trace
+    pub fn record(&mut self, direction: Direction, port: &str, data: &[u8]) {
+        self.trace.events.push(TraceEvent {
+            time_ms: self.start.elapsed().as_millis() as u64,
+            direction,
+            port: String::from(port),
+            data: data.to_vec(),
+        });
+    }
+
+    /// Stop the recording and return the trace
+    pub fn finish(self) -> Trace {
+        self.trace
+    }
+}
+
+/// Replays a trace in place of the device
+///
+/// Messages received from the device are delivered in their recorded order
+/// and with their recorded delays. A message is only delivered after all
+/// messages recorded before it have been sent by the editor. Every message
+/// sent by the editor is compared against the recording and differences are
+/// collected as mismatches.
+pub struct TracePlayer {
+    /// Events not replayed yet
+    events: VecDeque<TraceEvent>,
+
+    /// Recording time of the last replayed event
+    last_event_time_ms: u64,
+
+    /// Time the last event was replayed
+    last_event_instant: Instant,
+
+    /// Differences between recording and replay
+    mismatches: Vec<String>,
+
+    /// Flag for the result already taken
+    reported: bool,
+}
+
+impl TracePlayer {
+    /// Constructs a new instance
+    pub fn new(trace: Trace) -> Self {
+        Self {
+            events: trace
+                .events
+                .into_iter()
+                .filter(|event| event.direction != Direction::FromMerge)
+                .collect(),
+            last_event_time_ms: 0,
+            last_event_instant: Instant::now(),
+            mismatches: Vec::new(),
+            reported: false,
+        }
+    }
+
+    /// Process a message sent by the editor
+    pub fn send(&mut self, message: &[u8]) {
+        match self.events.front() {
+            Some(event) if event.direction == Direction::ToDevice => {
+                if event.data != message {
+                    self.mismatches.push(format!(
+                        "At {} ms: expected \"{}\", got \"{}\"",
+                        event.time_ms,
+                        sysex::describe(&event.data),
+                        sysex::describe(message)
+                    ));
+                }
+                self.advance();
+            }
+            Some(event) => {
+                self.mismatches.push(format!(
+                    "At {} ms: unexpected \"{}\"",
+                    event.time_ms,
+                    sysex::describe(message)
+                ));
+            }
+            None => {
+                self.mismatches.push(format!(
+                    "After end of trace: unexpected \"{}\"",
+                    sysex::describe(message)
+                ));
+            }
+        }
+    }
+
+    /// Return the next message from the device if it is due
+    pub fn receive(&mut self) -> Option<Vec<u8>> {
+        let event = self.events.front()?;
+
+        if event.direction != Direction::FromDevice {
+            return None;
+        }
+
+        let delay = Duration::from_millis(event.time_ms.saturating_sub(self.last_event_time_ms));
+        if self.last_event_instant.elapsed() < delay {
+            return None;
+        }
+
+        let data = event.data.clone();
+        self.advance();
+
+        Some(data)
+    }
+
+    /// Return the mismatches once after the trace has been replayed completely
+    pub fn take_result(&mut self) -> Option<Vec<String>> {
+        if self.reported || !self.events.is_empty() {
+            return None;
+        }
+
+        self.reported = true;
+
+        Some(std::mem::take(&mut self.mismatches))
+    }
+
+    /// Remove the front event and update the timing reference
+    fn advance(&mut self) {
+        if let Some(event) = self.events.pop_front() {
+            self.last_event_time_ms = event.time_ms;
+            self.last_event_instant = Instant::now();
+        }
+    }
+}
+
+/// Replay the messages sent to the device in a trace against the in-memory
+/// device and return the differences to the recorded device responses
+///
+/// Unsolicited messages of the real device, e.g. caused by turning a knob,
+/// are reported as missing responses.
+///
+/// - `trace`   Trace to be replayed
+/// - `device`  In-memory device in its initial state
+pub fn check_against_device(trace: &Trace, device: &mut VirtualDevice) -> Vec<String> {
+    let mut mismatches = Vec::new();
+    let mut responses = VecDeque::new();
+
+    for event in trace.events.iter() {
+        match event.direction {
+            Direction::ToDevice | Direction::FromMerge => {
+                device.send(&event.data);
+                while let Some(response) = device.receive() {
+                    responses.push_back(response);
+                }
+            }
+            Direction::FromDevice => match responses.pop_front() {
+                Some(response) if response == event.data => {}
+                Some(response) => mismatches.push(format!(
+                    "At {} ms: expected \"{}\", device sent \"{}\"",
+                    event.time_ms,
+                    sysex::describe(&event.data),
+                    sysex::describe(&response)
+                )),
+                None => mismatches.push(format!(
+                    "At {} ms: expected \"{}\", device sent nothing",
+                    event.time_ms,
+                    sysex::describe(&event.data)
+                )),
+            },
+        }
+    }
+
+    for response in responses {
+        mismatches.push(format!(
+            "After end of trace: device sent \"{}\"",
+            sysex::describe(&response)
+        ));
+    }
+
+    mismatches
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::midi::sync::DeviceSync;
+    use crate::params::{GetValue, MultiParameter, SoundParameter, PART_COUNT};
+
+    /// Session with the in-memory device loading a sound into part 1, assigning
+    /// preset 7 to part 2 and reading all parts, the multi and the names of
+    /// presets 6 and 7. It is not a recording of the hardware, so its messages
+    /// are additionally checked against the protocol.
+    const STARTUP_TRACE: &str = include_str!("../../tests/traces/virtual_device_startup.ron");
+
+    /// Number of messages at the start of the startup trace sent by user edits
+    const STARTUP_EDITS: usize = 2;
+
+    fn startup_trace() -> Trace {
+        ron::from_str(STARTUP_TRACE).unwrap()
+    }
+
+    /// Replay a trace against the editor synchronisation and return the mismatches
+    ///
+    /// - `trace`   Trace to be replayed
+    /// - `sync`    Synchronisation with the requests to be made
+    /// - `edits`   Number of messages at the start of the trace sent by user edits
+    fn replay(trace: Trace, sync: &mut DeviceSync, edits: usize) -> Vec<String> {
+        let locks = HashSet::new();
+        let edit_messages: Vec<Vec<u8>> = trace
+            .events
+            .iter()
+            .take(edits)
+            .map(|event| event.data.clone())
+            .collect();
+
+        let mut player = TracePlayer::new(trace);
+        for message in edit_messages {
+            player.send(&message);
+        }
+
+        loop {
+            while let Some(message) = player.receive() {
+                for response in sync.process_message(&message, &locks) {
+                    player.send(&response);
+                }
+            }
+            match sync.next_request(0) {
+                Some(request) => player.send(&request.message()),
+                None => break,
+            }
+        }
+
+        player
+            .take_result()
+            .unwrap_or_else(|| vec![String::from("Trace not replayed completely")])
+    }
+
+    #[test]
+    fn virtual_device_reproduces_startup_trace() {
+        let mismatches = check_against_device(&startup_trace(), &mut VirtualDevice::new());
+
+        assert_eq!(mismatches, Vec::<String>::new());
+    }
+
+    #[test]
+    fn startup_trace_follows_protocol() {
+        // Dumps still to be sent by the device, as service and id
+        let mut requested = Vec::new();
+
+        for event in startup_trace().events {
+            let data = event.data.as_slice();
+            let id = data[2];
+            assert_eq!((data[0], data[data.len() - 1]), (0xF0, 0xF7));
+            assert!(data[1..data.len() - 1].iter().all(|byte| *byte < 0x80));
+
+            match (event.direction, data[1]) {
+                (Direction::ToDevice, sysex::SERVICE_PRESET_REQUEST) => {
+                    assert_eq!(data.len(), 4);
+                    assert!(id < 100 || (0x70..=0x73).contains(&id));
+                    requested.push((sysex::SERVICE_PRESET_DUMP, id));
+                }
+                (Direction::ToDevice, sysex::SERVICE_MULTI_REQUEST) => {
+                    assert_eq!(data, sysex::multi_request(0x7F).as_slice());
+                    requested.push((sysex::SERVICE_MULTI_DUMP, id));
+                }
+                (Direction::ToDevice, sysex::SERVICE_PRESET_PARAMETER_DUMP) => {
+                    assert_eq!(data.len(), sysex::PRESET_PARAM_DUMP_LENGTH);
+                }
+                (Direction::ToDevice, sysex::SERVICE_MULTI_PARAMETER_DUMP) => {
+                    assert_eq!(data.len(), sysex::MULTI_PARAM_DUMP_LENGTH);
+                }
+                (_, sysex::SERVICE_PRESET_DUMP) => {
+                    assert_eq!(data.len(), sysex::PRESET_DUMP_LENGTH);
+                }
+                (_, sysex::SERVICE_MULTI_DUMP) => {
+                    assert_eq!(data.len(), sysex::MULTI_DUMP_LENGTH);
+                    assert_eq!(id, 0x7F);
+                }
+                (direction, service) => panic!("Unexpected {:?} service {:#X}", direction, service),
+            }
+
+            // The device only sends dumps answering a request
+            if event.direction == Direction::FromDevice {
+                let position = requested
+                    .iter()
+                    .position(|request| *request == (data[1], id));
+                assert!(
+                    position.is_some(),
+                    "Unrequested dump {:#X} {:#X}",
+                    data[1],
+                    id
+                );
+                requested.remove(position.unwrap());
+            }
+        }
+
+        assert_eq!(requested, Vec::new());
+    }
+
+    #[test]
+    fn startup_trace_fills_cache() {
+        let mut sync = DeviceSync::new();
+        sync.request_all();
+        sync.request_preset_names([6, 7]);
+
+        let mismatches = replay(startup_trace(), &mut sync, STARTUP_EDITS);
+
+        assert_eq!(mismatches, Vec::<String>::new());
+        assert_eq!(sync.part_params_valid, [true; PART_COUNT]);
+        assert_eq!(
+            sync.part_names,
+            ["Trace Bass", "Init 07", "Init 02", "Init 03"]
+        );
+        assert_eq!(
+            sync.part_params[0].get_value(SoundParameter::FilterCutoff),
+            200
+        );
+        assert_eq!(
+            sync.part_params[0].get_value(SoundParameter::FilterResonance),
+            50
+        );
+        assert_eq!(sync.part_params[0].get_value(SoundParameter::Osc1Table), 3);
+        assert_eq!(
+            sync.part_params[0].get_value(SoundParameter::Osc1Coarse),
+            -12
+        );
+        assert_eq!(
+            sync.part_params[1].get_value(SoundParameter::FilterCutoff),
+            0
+        );
+        assert_eq!(sync.multi_params.get_value(MultiParameter::PresetPart1), 0);
+        assert_eq!(sync.multi_params.get_value(MultiParameter::PresetPart2), 7);
+        assert_eq!(sync.preset_names[6], "Init 06");
+        assert_eq!(sync.preset_names[7], "Init 07");
+        assert_eq!(sync.preset_names[8], "");
+    }
+
+    #[test]
+    fn missing_request_is_reported() {
+        // Without the multi request, the multi dump is never delivered
+        let mut sync = DeviceSync::new();
+        sync.request_all();
+        sync.request_multi_update = false;
+        sync.request_preset_names([6, 7]);
+
+        let mismatches = replay(startup_trace(), &mut sync, STARTUP_EDITS);
+
+        assert!(!mismatches.is_empty());
+    }
+}
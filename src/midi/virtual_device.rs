@@ -0,0 +1,177 @@
+//! In-memory emulation of the device
+//!
+//! The emulation answers dump requests and applies parameter and dump
+//! messages the same way as the hardware does, so the editor can be
+//! exercised without a Töörö connected.
+
+use std::collections::VecDeque;
+
+use super::sysex;
+use crate::params::{
+    GetValue, MultiParameter, MultiParameterValues, SoundParameterValues, PART_COUNT, PRESET_COUNT,
+};
+
+/// Length of the unpacked data of a preset dump in bytes
+const PRESET_DATA_LENGTH: usize = (sysex::PRESET_DUMP_LENGTH - 4) / 5 * 4;
+
+/// State of the emulated device
+pub struct VirtualDevice {
+    /// Dumps of the stored presets
+    presets: Vec<Vec<u8>>,
+
+    /// Dumps of the part edit buffers
+    parts: Vec<Vec<u8>>,
+
+    /// Multi edit buffer
+    multi: MultiParameterValues,
+
+    /// Messages waiting to be received by the editor
+    pending: VecDeque<Vec<u8>>,
+}
+
+impl VirtualDevice {
+    /// Constructs a new instance with initialized presets
+    pub fn new() -> Self {
+        let presets: Vec<Vec<u8>> = (0..PRESET_COUNT as u8)
+            .map(|id| init_preset_dump(id, &format!("Init {:02}", id)))
+            .collect();
+        let parts = presets[..PART_COUNT].to_vec();
+
+        let mut multi = MultiParameterValues::new();
+        multi.insert(MultiParameter::PresetPart1, 0);
+        multi.insert(MultiParameter::PresetPart2, 1);
+        multi.insert(MultiParameter::PresetPart3, 2);
+        multi.insert(MultiParameter::PresetPart4, 3);
+
+        Self {
+            presets,
+            parts,
+            multi,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Process a message sent to the device
+    pub fn send(&mut self, message: &[u8]) {
+        if message.len() < 4 || message[0] != 0xF0 {
+            return;
+        }
+
+        match message[1] {
+            sysex::SERVICE_PRESET_REQUEST => {
+                let preset_id = message[2];
+                if let Some(dump) = self.preset(preset_id) {
+                    let mut response = dump.to_vec();
+                    response[2] = preset_id;
+                    self.pending.push_back(response);
+                }
+            }
+
+            sysex::SERVICE_MULTI_REQUEST if message[2] == 0x7F => {
+                let response = sysex::multi_dump(0x7F, &self.multi);
+                self.pending.push_back(response);
+            }
+
+            sysex::SERVICE_PRESET_PARAMETER_DUMP => {
+                if let Some((preset_id, param, value)) = sysex::decode_preset_param_dump(message) {
+                    if let Some(dump) = self.preset_mut(preset_id) {
+                        if let Some((name, mut params)) = sysex::decode_preset_dump(dump) {
+                            params.insert(param, value);
+                            if let Some(patched) =
+                                sysex::patch_preset_dump(dump, preset_id, &params, &name)
+                            {
+                                *dump = patched;
+                            }
+                        }
+                    }
+                }
+            }
+
+            sysex::SERVICE_MULTI_PARAMETER_DUMP => {
+                if let Some((param, value)) = sysex::decode_multi_param_dump(message) {
+                    let last_value = self.multi.insert(param, value);
+                    if last_value != Some(value) {
+                        self.load_part_presets(Some(param));
+                    }
+                }
+            }
+
+            sysex::SERVICE_PRESET_DUMP if message.len() == sysex::PRESET_DUMP_LENGTH => {
+                if let Some(dump) = self.preset_mut(message[2]) {
+                    *dump = message.to_vec();
+                }
+            }
+
+            sysex::SERVICE_MULTI_DUMP if message.len() == sysex::MULTI_DUMP_LENGTH => {
+                let values = sysex::unpack_data(&message[3..message.len()]);
+                sysex::update_multi_params(&mut self.multi, &values);
+                self.load_part_presets(None);
+            }
+
+            _ => {}
+        }
+    }
+
+    /// Return the next message sent by the device
+    pub fn receive(&mut self) -> Option<Vec<u8>> {
+        self.pending.pop_front()
+    }
+
+    /// Return a stored preset or part edit buffer
+    ///
+    /// - `preset_id`   Preset id, either 0..99 or 0x70..0x73
+    fn preset(&self, preset_id: u8) -> Option<&[u8]> {
+        let entry = match preset_id {
+            0x70..=0x73 => self.parts.get((preset_id - 0x70) as usize),
+            _ => self.presets.get(preset_id as usize),
+        };
+
+        entry.map(|dump| dump.as_slice())
+    }
+
+    /// Return a stored preset or part edit buffer for modification
+    ///
+    /// - `preset_id`   Preset id, either 0..99 or 0x70..0x73
+    fn preset_mut(&mut self, preset_id: u8) -> Option<&mut Vec<u8>> {
+        match preset_id {
+            0x70..=0x73 => self.parts.get_mut((preset_id - 0x70) as usize),
+            _ => self.presets.get_mut(preset_id as usize),
+        }
+    }
+
+    /// Load the assigned presets into the part edit buffers
+    ///
+    /// - `changed`     Changed multi parameter or `None` to load all parts
+    fn load_part_presets(&mut self, changed: Option<MultiParameter>) {
+        let assignments = [
+            MultiParameter::PresetPart1,
+            MultiParameter::PresetPart2,
+            MultiParameter::PresetPart3,
+            MultiParameter::PresetPart4,
+        ];
+
+        for (part, param) in assignments.iter().enumerate() {
+            if changed.is_some() && changed != Some(*param) {
+                continue;
+            }
+            let preset_id = self.multi.get_value(*param) as usize;
+            if let Some(preset) = self.presets.get(preset_id) {
+                self.parts[part] = preset.clone();
+            }
+        }
+    }
+}
+
+/// Return the dump of an initialized preset
+///
+/// The device settings not modelled by the editor are zero.
+///
+/// - `preset_id`   Preset id, either 0..99 or 0x70..0x73
+/// - `name`        Preset name
+fn init_preset_dump(preset_id: u8, name: &str) -> Vec<u8> {
+    let mut blank = vec![0xF0, sysex::SERVICE_PRESET_DUMP, preset_id];
+    blank.append(&mut sysex::pack_data(&[0; PRESET_DATA_LENGTH]));
+    blank.push(0xF7);
+
+    sysex::patch_preset_dump(&blank, preset_id, &SoundParameterValues::new(), name).unwrap()
+}
@@ -0,0 +1,155 @@
+//! Modulation routing of a sound
+//!
+//! Each of the eight modulation sources has a target and a bipolar amount.
+
+use crate::params::{GetValue, SoundParameter, SoundParameterValues};
+use crate::ui::elements::mod_target_list::ModTarget;
+
+/// Source of a modulation routing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModSource {
+    /// Filter envelope
+    EnvF,
+
+    /// Amplifier envelope
+    EnvA,
+
+    /// LFO 1
+    LFO1,
+
+    /// LFO 2
+    LFO2,
+
+    /// Modulation wheel
+    Modwheel,
+
+    /// Pitch bend wheel
+    Pitch,
+
+    /// Note on velocity
+    Velocity,
+
+    /// Channel aftertouch
+    Aftertouch,
+}
+
+impl ModSource {
+    /// All sources in the order of their parameters
+    pub const ALL: [ModSource; 8] = [
+        ModSource::EnvF,
+        ModSource::EnvA,
+        ModSource::LFO1,
+        ModSource::LFO2,
+        ModSource::Modwheel,
+        ModSource::Pitch,
+        ModSource::Velocity,
+        ModSource::Aftertouch,
+    ];
+
+    /// Return the parameter selecting the target
+    pub fn target_param(&self) -> SoundParameter {
+        match self {
+            ModSource::EnvF => SoundParameter::ModEnvFTarget,
+            ModSource::EnvA => SoundParameter::ModEnvATarget,
+            ModSource::LFO1 => SoundParameter::ModLFO1Target,
+            ModSource::LFO2 => SoundParameter::ModLFO2Target,
+            ModSource::Modwheel => SoundParameter::ModModwheelTarget,
+            ModSource::Pitch => SoundParameter::ModPitchTarget,
+            ModSource::Velocity => SoundParameter::ModVelocityTarget,
+            ModSource::Aftertouch => SoundParameter::ModAftertouchTarget,
+        }
+    }
+
+    /// Return the parameter containing the amount
+    pub fn amount_param(&self) -> SoundParameter {
+        match self {
+            ModSource::EnvF => SoundParameter::ModEnvFAmount,
+            ModSource::EnvA => SoundParameter::ModEnvAAmount,
+            ModSource::LFO1 => SoundParameter::ModLFO1Amount,
+            ModSource::LFO2 => SoundParameter::ModLFO2Amount,
+            ModSource::Modwheel => SoundParameter::ModModwheelAmount,
+            ModSource::Pitch => SoundParameter::ModPitchAmount,
+            ModSource::Velocity => SoundParameter::ModVelocityAmount,
+            ModSource::Aftertouch => SoundParameter::ModAftertouchAmount,
+        }
+    }
+}
+
+impl std::fmt::Display for ModSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ModSource::EnvF => "Env F",
+                ModSource::EnvA => "Env A",
+                ModSource::LFO1 => "LFO 1",
+                ModSource::LFO2 => "LFO 2",
+                ModSource::Modwheel => "Modwheel",
+                ModSource::Pitch => "Pitch",
+                ModSource::Velocity => "Velocity",
+                ModSource::Aftertouch => "Aftertouch",
+            }
+        )
+    }
+}
+
+/// Routing of a modulation source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Routing {
+    /// Modulation source
+    pub source: ModSource,
+
+    /// Target, `None` for an invalid parameter value
+    pub target: Option<ModTarget>,
+
+    /// Bipolar amount
+    pub amount: i32,
+}
+
+impl Routing {
+    /// Return true if the routing has an effect
+    pub fn is_active(&self) -> bool {
+        self.target.is_some() && self.amount != 0
+    }
+}
+
+/// Return the routings of all modulation sources
+pub fn routings(params: &SoundParameterValues) -> Vec<Routing> {
+    ModSource::ALL
+        .iter()
+        .map(|source| Routing {
+            source: *source,
+            target: ModTarget::from_value(params.get_value(source.target_param())),
+            amount: params.get_value(source.amount_param()),
+        })
+        .collect()
+}
+
+/// Return the active routings modulating a sound parameter
+pub fn modulations_of(params: &SoundParameterValues, param: SoundParameter) -> Vec<Routing> {
+    routings(params)
+        .into_iter()
+        .filter(|routing| {
+            routing.is_active() && routing.target.map(|target| target.get_param()) == Some(param)
+        })
+        .collect()
+}
+
+/// Return the targets modulated by more than one active source
+pub fn conflicts(params: &SoundParameterValues) -> Vec<(ModTarget, Vec<ModSource>)> {
+    let routings = routings(params);
+
+    ModTarget::ALL
+        .iter()
+        .map(|target| {
+            let sources: Vec<ModSource> = routings
+                .iter()
+                .filter(|routing| routing.is_active() && routing.target == Some(*target))
+                .map(|routing| routing.source)
+                .collect();
+            (*target, sources)
+        })
+        .filter(|(_, sources)| sources.len() > 1)
+        .collect()
+}
@@ -0,0 +1,126 @@
+//! Morphing between two sounds
+//!
+//! Continuous parameters are interpolated linearly, lists and switches
+//! change from the start to the end value at the half way position.
+
+use crate::params::{GetValue, SoundParameter, SoundParameterValues};
+
+/// Morph position at which lists and switches change to the end value
+const SWITCH_POSITION: i32 = 50;
+
+/// Maximum morph position
+pub const MAX_POSITION: i32 = 100;
+
+/// Start or end point of a morph
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MorphEnd {
+    Start,
+    End,
+}
+
+/// Origin of the sound used as start or end point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MorphSource {
+    /// Compare slot A of the current part
+    SlotA,
+
+    /// Compare slot B of the current part
+    SlotB,
+
+    /// Edit buffer of the current part
+    Current,
+
+    /// Preset dump loaded from a file
+    File,
+
+    /// Preset stored in the device, preset id 0-99
+    Stored(u8),
+}
+
+/// A sound used as start or end point of a morph
+#[derive(Debug, Clone)]
+pub struct MorphPoint {
+    /// Name shown in the user interface
+    pub label: String,
+
+    /// Sound parameters
+    pub values: SoundParameterValues,
+}
+
+/// State of the morph
+pub struct Morph {
+    /// Start point, `None` if not selected yet
+    pub start: Option<MorphPoint>,
+
+    /// End point, `None` if not selected yet
+    pub end: Option<MorphPoint>,
+
+    /// Position between start (0) and end (`MAX_POSITION`)
+    pub position: i32,
+
+    /// Point waiting for the dump of a stored preset with its preset id
+    pub requested: Option<(MorphEnd, u8)>,
+}
+
+impl Morph {
+    /// Constructs a new instance
+    pub fn new() -> Self {
+        Self {
+            start: None,
+            end: None,
+            position: 0,
+            requested: None,
+        }
+    }
+
+    /// Set the start or end point, replacing a requested stored preset
+    pub fn set_point(&mut self, end: MorphEnd, point: MorphPoint) {
+        if matches!(self.requested, Some((requested, _)) if requested == end) {
+            self.requested = None;
+        }
+        match end {
+            MorphEnd::Start => self.start = Some(point),
+            MorphEnd::End => self.end = Some(point),
+        }
+    }
+
+    /// Return the sound at the current position, `None` if a point is missing
+    pub fn values(&self) -> Option<SoundParameterValues> {
+        let start = &self.start.as_ref()?.values;
+        let end = &self.end.as_ref()?.values;
+
+        Some(interpolate(start, end, self.position))
+    }
+}
+
+/// Return the sound between two sounds
+///
+/// - `start`       Sound at position 0
+/// - `end`         Sound at position `MAX_POSITION`
+/// - `position`    Position between both sounds
+pub fn interpolate(
+    start: &SoundParameterValues,
+    end: &SoundParameterValues,
+    position: i32,
+) -> SoundParameterValues {
+    let position = position.clamp(0, MAX_POSITION);
+
+    SoundParameter::ALL
+        .iter()
+        .map(|param| {
+            let start_value = start.get_value(*param);
+            let end_value = end.get_value(*param);
+
+            let value = if param.is_continuous() {
+                let delta = (end_value - start_value) as f32 * position as f32;
+                start_value + (delta / MAX_POSITION as f32).round() as i32
+            } else if position < SWITCH_POSITION {
+                start_value
+            } else {
+                end_value
+            };
+
+            (*param, value)
+        })
+        .collect()
+}
@@ -0,0 +1,255 @@
+//! Documentation of the sound and multi parameters shown in tooltips
+//!
+//! This is the single source for the descriptive texts. Ranges and defaults
+//! are taken from the parameter definitions and shown in the units of the
+//! value displays. MIDI CC numbers are not listed: the editor talks to the
+//! device via sysex only and no verified CC assignment is available here.
+
+use crate::params::{MultiParameter, SoundParameter};
+use crate::ui::elements::mod_target_list::ModTarget;
+use crate::units;
+
+/// Documentation of a parameter
+pub struct ParamDoc {
+    /// Full name
+    pub name: &'static str,
+
+    /// What the parameter does on the device
+    pub description: &'static str,
+
+    /// Minimum and maximum value in the unit of the parameter
+    pub range: (String, String),
+
+    /// Default value in the unit of the parameter
+    pub default: String,
+
+    /// Index of the modulation target changing the parameter
+    pub mod_target: Option<(usize, ModTarget)>,
+}
+
+impl ParamDoc {
+    /// Return the documentation of a sound parameter
+    pub fn of_sound(param: SoundParameter) -> Self {
+        let range = param.get_range();
+        let mod_target = ModTarget::ALL
+            .iter()
+            .enumerate()
+            .find(|(_, target)| target.get_param() == param)
+            .map(|(index, target)| (index, *target));
+
+        Self {
+            name: param.get_name(),
+            description: sound_description(param),
+            range: (
+                units::format_sound_value(param, *range.start()),
+                units::format_sound_value(param, *range.end()),
+            ),
+            default: units::format_sound_value(param, param.get_default()),
+            mod_target,
+        }
+    }
+
+    /// Return the documentation of a multi parameter
+    pub fn of_multi(param: MultiParameter) -> Self {
+        let range = param.get_range();
+
+        Self {
+            name: param.get_name(),
+            description: multi_description(param),
+            range: (
+                units::format_multi_value(param, *range.start()),
+                units::format_multi_value(param, *range.end()),
+            ),
+            default: units::format_multi_value(param, param.get_default()),
+            mod_target: None,
+        }
+    }
+
+    /// Return the tooltip text
+    pub fn tooltip(&self) -> String {
+        let mut lines = vec![
+            self.name.to_string(),
+            self.description.to_string(),
+            format!(
+                "Range {} to {}, default {}",
+                self.range.0, self.range.1, self.default
+            ),
+        ];
+
+        if let Some((index, target)) = self.mod_target {
+            lines.push(format!("Mod target {} ({})", index, target));
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Return the description of a sound parameter
+fn sound_description(param: SoundParameter) -> &'static str {
+    match param {
+        // Osc 1 and 2
+        SoundParameter::Osc1Wave | SoundParameter::Osc2Wave => {
+            "Position within the wavetable, morphing between its waveforms."
+        }
+        SoundParameter::Osc1Coarse | SoundParameter::Osc2Coarse => {
+            "Pitch offset in semitones."
+        }
+        SoundParameter::Osc1FMAmount | SoundParameter::Osc2FMAmount => {
+            "Depth of the frequency modulation of the oscillator."
+        }
+        SoundParameter::Osc1Level | SoundParameter::Osc2Level => {
+            "Level of the oscillator in the mix."
+        }
+        SoundParameter::Osc1Table | SoundParameter::Osc2Table => {
+            "Wavetable played by the oscillator."
+        }
+        SoundParameter::Osc1Fine | SoundParameter::Osc2Fine => "Pitch offset in cents.",
+        SoundParameter::Osc1FMRate | SoundParameter::Osc2FMRate => {
+            "Frequency ratio of the frequency modulation."
+        }
+        SoundParameter::Osc1Sync | SoundParameter::Osc2Sync => {
+            "Amount of the hard sync of the oscillator, sweeping its timbre."
+        }
+
+        // Extra
+        SoundParameter::ExtraNoise => "Level of the noise generator in the mix.",
+        SoundParameter::ExtraRingMod => {
+            "Level of the ring modulation of oscillator 1 by oscillator 2 (O1xO2) in the mix."
+        }
+
+        // Filter
+        SoundParameter::FilterCutoff => "Cutoff frequency of the low-pass filter.",
+        SoundParameter::FilterResonance => {
+            "Emphasis of the frequencies around the cutoff of the low-pass filter."
+        }
+        SoundParameter::FilterEnvFAmount => {
+            "Amount by which envelope F changes the filter cutoff."
+        }
+        SoundParameter::FilterTrack => {
+            "Keyboard tracking, how much the filter cutoff follows the played note."
+        }
+        SoundParameter::FilterAfter => {
+            "Amount by which aftertouch changes the filter cutoff."
+        }
+        SoundParameter::FilterLFO1Amount => "Amount by which LFO 1 changes the filter cutoff.",
+
+        // Shaper
+        SoundParameter::ShaperCutoff => "Cutoff frequency of the shaper filter.",
+        SoundParameter::ShaperResonance => {
+            "Emphasis of the frequencies around the cutoff of the shaper filter."
+        }
+        SoundParameter::ShaperEnvAAmount => {
+            "Amount by which envelope A changes the shaper cutoff."
+        }
+        SoundParameter::ShaperTrack => {
+            "Keyboard tracking, how much the shaper cutoff follows the played note."
+        }
+        SoundParameter::ShaperMode => "Response of the shaper filter: low-, band- or high-pass.",
+        SoundParameter::ShaperLFO2Amount => "Amount by which LFO 2 changes the shaper cutoff.",
+
+        // Env F and Env A
+        SoundParameter::EnvFAttack | SoundParameter::EnvAAttack => {
+            "Time to rise to the maximum after a note starts."
+        }
+        SoundParameter::EnvFDecay | SoundParameter::EnvADecay => {
+            "Time to fall from the maximum to the sustain level."
+        }
+        SoundParameter::EnvFSustain | SoundParameter::EnvASustain => {
+            "Level held while the note is held."
+        }
+        SoundParameter::EnvFRelease | SoundParameter::EnvARelease => {
+            "Time to fall to zero after the note is released."
+        }
+        SoundParameter::EnvFVelo | SoundParameter::EnvAVelo => {
+            "Amount by which the note velocity scales the envelope."
+        }
+        SoundParameter::EnvFHold | SoundParameter::EnvAHold => {
+            "Time the envelope stays at the maximum before the decay starts."
+        }
+        SoundParameter::EnvFAfter | SoundParameter::EnvAAfter => {
+            "Amount by which aftertouch raises the envelope."
+        }
+        SoundParameter::EnvFTrigger | SoundParameter::EnvATrigger => {
+            "Restart of the envelope on new notes: always, never or continuing from the current level."
+        }
+
+        // LFO 1 and 2
+        SoundParameter::LFO1Shape | SoundParameter::LFO2Shape => "Waveform of the LFO.",
+        SoundParameter::LFO1Speed | SoundParameter::LFO2Speed => "Rate of the LFO.",
+        SoundParameter::LFO1Rise | SoundParameter::LFO2Rise => {
+            "Fade-in time of the LFO after a note starts."
+        }
+        SoundParameter::LFO1Phase | SoundParameter::LFO2Phase => {
+            "Phase of the LFO on a new note: free running, random or a fixed start angle."
+        }
+
+        // Arpeggiator
+        SoundParameter::ArpMode => "Note order of the arpeggiator, or off.",
+        SoundParameter::ArpGrid => "Note length of the arpeggiator steps.",
+        SoundParameter::ArpTempo => "Tempo of the arpeggiator in BPM.",
+        SoundParameter::ArpHold => "Keep the arpeggio running after the keys are released.",
+
+        // Amplifier
+        SoundParameter::AmpLevel => "Output level of the part.",
+        SoundParameter::AmpPan => "Position of the part in the stereo field.",
+
+        // Modulations
+        SoundParameter::ModEnvFAmount
+        | SoundParameter::ModEnvAAmount
+        | SoundParameter::ModLFO1Amount
+        | SoundParameter::ModLFO2Amount
+        | SoundParameter::ModModwheelAmount
+        | SoundParameter::ModPitchAmount
+        | SoundParameter::ModVelocityAmount
+        | SoundParameter::ModAftertouchAmount => {
+            "Depth and polarity of the modulation of the selected target by this source."
+        }
+        SoundParameter::ModEnvFTarget
+        | SoundParameter::ModEnvATarget
+        | SoundParameter::ModLFO1Target
+        | SoundParameter::ModLFO2Target
+        | SoundParameter::ModModwheelTarget
+        | SoundParameter::ModPitchTarget
+        | SoundParameter::ModVelocityTarget
+        | SoundParameter::ModAftertouchTarget => "Parameter modulated by this source.",
+
+        // Misc
+        SoundParameter::BendRange => "Pitch range of the pitch bend wheel in semitones.",
+        SoundParameter::Tune => "Fine tuning of the whole part in cents.",
+        SoundParameter::PolyMode => "Play the part polyphonically instead of monophonically.",
+    }
+}
+
+/// Return the description of a multi parameter
+fn multi_description(param: MultiParameter) -> &'static str {
+    match param {
+        MultiParameter::PresetPart1
+        | MultiParameter::PresetPart2
+        | MultiParameter::PresetPart3
+        | MultiParameter::PresetPart4 => "Stored preset played by the part.",
+
+        MultiParameter::ChannelPart1
+        | MultiParameter::ChannelPart2
+        | MultiParameter::ChannelPart3
+        | MultiParameter::ChannelPart4 => "MIDI channel the part receives on.",
+
+        MultiParameter::VolumePart1
+        | MultiParameter::VolumePart2
+        | MultiParameter::VolumePart3
+        | MultiParameter::VolumePart4 => "Volume of the part in the multi mix.",
+
+        MultiParameter::BalancePart1
+        | MultiParameter::BalancePart2
+        | MultiParameter::BalancePart3
+        | MultiParameter::BalancePart4 => "Stereo balance of the part in the multi mix.",
+
+        MultiParameter::FXLength => "Delay time of the effect.",
+        MultiParameter::FXFeedback => "Amount of the effect output fed back into its input.",
+        MultiParameter::FXMix => "Balance between the dry signal and the effect.",
+        MultiParameter::FXMode => {
+            "Type of the effect: off, mono delay, chorus/flanger or stereo delay."
+        }
+        MultiParameter::FXSpeed => "Rate of the modulation within the effect.",
+        MultiParameter::FXDepth => "Depth of the modulation within the effect.",
+    }
+}
@@ -3,8 +3,16 @@
 use std::collections::HashMap;
 use std::ops::RangeInclusive;
 
+use serde::{Deserialize, Serialize};
+
+/// Number of parts of the device
+pub const PART_COUNT: usize = 4;
+
+/// Number of stored presets of the device
+pub const PRESET_COUNT: usize = 100;
+
 /// Enum containing all preset parameters
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum SoundParameter {
     // Osc 1
     Osc1Wave,
@@ -113,6 +121,102 @@ pub enum SoundParameter {
 }
 
 impl SoundParameter {
+    /// All preset parameters
+    pub const ALL: [SoundParameter; 79] = [
+        // Osc 1
+        SoundParameter::Osc1Wave,
+        SoundParameter::Osc1Coarse,
+        SoundParameter::Osc1FMAmount,
+        SoundParameter::Osc1Level,
+        SoundParameter::Osc1Table,
+        SoundParameter::Osc1Fine,
+        SoundParameter::Osc1FMRate,
+        SoundParameter::Osc1Sync,
+        // Osc 2
+        SoundParameter::Osc2Wave,
+        SoundParameter::Osc2FMAmount,
+        SoundParameter::Osc2Level,
+        SoundParameter::Osc2Coarse,
+        SoundParameter::Osc2Table,
+        SoundParameter::Osc2Fine,
+        SoundParameter::Osc2FMRate,
+        SoundParameter::Osc2Sync,
+        // Extra
+        SoundParameter::ExtraNoise,
+        SoundParameter::ExtraRingMod,
+        // Filter
+        SoundParameter::FilterCutoff,
+        SoundParameter::FilterResonance,
+        SoundParameter::FilterEnvFAmount,
+        SoundParameter::FilterTrack,
+        SoundParameter::FilterAfter,
+        SoundParameter::FilterLFO1Amount,
+        // Shaper
+        SoundParameter::ShaperCutoff,
+        SoundParameter::ShaperResonance,
+        SoundParameter::ShaperEnvAAmount,
+        SoundParameter::ShaperTrack,
+        SoundParameter::ShaperMode,
+        SoundParameter::ShaperLFO2Amount,
+        // Env F
+        SoundParameter::EnvFAttack,
+        SoundParameter::EnvFDecay,
+        SoundParameter::EnvFSustain,
+        SoundParameter::EnvFRelease,
+        SoundParameter::EnvFVelo,
+        SoundParameter::EnvFHold,
+        SoundParameter::EnvFAfter,
+        SoundParameter::EnvFTrigger,
+        // Env A
+        SoundParameter::EnvAAttack,
+        SoundParameter::EnvADecay,
+        SoundParameter::EnvASustain,
+        SoundParameter::EnvARelease,
+        SoundParameter::EnvAVelo,
+        SoundParameter::EnvAHold,
+        SoundParameter::EnvAAfter,
+        SoundParameter::EnvATrigger,
+        // LFO 1
+        SoundParameter::LFO1Shape,
+        SoundParameter::LFO1Speed,
+        SoundParameter::LFO1Rise,
+        SoundParameter::LFO1Phase,
+        // LFO 2
+        SoundParameter::LFO2Shape,
+        SoundParameter::LFO2Speed,
+        SoundParameter::LFO2Rise,
+        SoundParameter::LFO2Phase,
+        // Arpeggiator
+        SoundParameter::ArpMode,
+        SoundParameter::ArpGrid,
+        SoundParameter::ArpTempo,
+        SoundParameter::ArpHold,
+        // Amplifier
+        SoundParameter::AmpLevel,
+        SoundParameter::AmpPan,
+        // Modulations
+        SoundParameter::ModEnvFAmount,
+        SoundParameter::ModEnvFTarget,
+        SoundParameter::ModEnvAAmount,
+        SoundParameter::ModEnvATarget,
+        SoundParameter::ModLFO1Amount,
+        SoundParameter::ModLFO1Target,
+        SoundParameter::ModLFO2Amount,
+        SoundParameter::ModLFO2Target,
+        SoundParameter::ModModwheelAmount,
+        SoundParameter::ModModwheelTarget,
+        SoundParameter::ModPitchAmount,
+        SoundParameter::ModPitchTarget,
+        SoundParameter::ModVelocityAmount,
+        SoundParameter::ModVelocityTarget,
+        SoundParameter::ModAftertouchAmount,
+        SoundParameter::ModAftertouchTarget,
+        // Misc
+        SoundParameter::BendRange,
+        SoundParameter::Tune,
+        SoundParameter::PolyMode,
+    ];
+
     /// Return the value range of the parameter
     pub fn get_range(&self) -> RangeInclusive<i32> {
         match self {
@@ -173,6 +277,397 @@ impl SoundParameter {
     pub fn get_default(&self) -> i32 {
         0
     }
+
+    /// Return true if the parameter has a continuous range of values,
+    /// false for lists and switches
+    pub fn is_continuous(&self) -> bool {
+        !matches!(
+            self,
+            SoundParameter::Osc1Table
+                | SoundParameter::Osc2Table
+                | SoundParameter::ShaperMode
+                | SoundParameter::EnvATrigger
+                | SoundParameter::EnvFTrigger
+                | SoundParameter::LFO1Shape
+                | SoundParameter::LFO2Shape
+                | SoundParameter::LFO1Phase
+                | SoundParameter::LFO2Phase
+                | SoundParameter::ArpMode
+                | SoundParameter::ArpGrid
+                | SoundParameter::ModEnvFTarget
+                | SoundParameter::ModEnvATarget
+                | SoundParameter::ModLFO1Target
+                | SoundParameter::ModLFO2Target
+                | SoundParameter::ModModwheelTarget
+                | SoundParameter::ModPitchTarget
+                | SoundParameter::ModVelocityTarget
+                | SoundParameter::ModAftertouchTarget
+                | SoundParameter::ArpHold
+                | SoundParameter::PolyMode
+        )
+    }
+
+    /// Return the section of the parameter
+    pub fn get_section(&self) -> SoundSection {
+        match self {
+            SoundParameter::Osc1Wave
+            | SoundParameter::Osc1Coarse
+            | SoundParameter::Osc1FMAmount
+            | SoundParameter::Osc1Level
+            | SoundParameter::Osc1Table
+            | SoundParameter::Osc1Fine
+            | SoundParameter::Osc1FMRate
+            | SoundParameter::Osc1Sync => SoundSection::Osc1,
+
+            SoundParameter::Osc2Wave
+            | SoundParameter::Osc2FMAmount
+            | SoundParameter::Osc2Level
+            | SoundParameter::Osc2Coarse
+            | SoundParameter::Osc2Table
+            | SoundParameter::Osc2Fine
+            | SoundParameter::Osc2FMRate
+            | SoundParameter::Osc2Sync => SoundSection::Osc2,
+
+            SoundParameter::ExtraNoise | SoundParameter::ExtraRingMod => SoundSection::Extra,
+
+            SoundParameter::FilterCutoff
+            | SoundParameter::FilterResonance
+            | SoundParameter::FilterEnvFAmount
+            | SoundParameter::FilterTrack
+            | SoundParameter::FilterAfter
+            | SoundParameter::FilterLFO1Amount => SoundSection::Filter,
+
+            SoundParameter::ShaperCutoff
+            | SoundParameter::ShaperResonance
+            | SoundParameter::ShaperEnvAAmount
+            | SoundParameter::ShaperTrack
+            | SoundParameter::ShaperMode
+            | SoundParameter::ShaperLFO2Amount => SoundSection::Shaper,
+
+            SoundParameter::EnvFAttack
+            | SoundParameter::EnvFDecay
+            | SoundParameter::EnvFSustain
+            | SoundParameter::EnvFRelease
+            | SoundParameter::EnvFVelo
+            | SoundParameter::EnvFHold
+            | SoundParameter::EnvFAfter
+            | SoundParameter::EnvFTrigger => SoundSection::EnvF,
+
+            SoundParameter::EnvAAttack
+            | SoundParameter::EnvADecay
+            | SoundParameter::EnvASustain
+            | SoundParameter::EnvARelease
+            | SoundParameter::EnvAVelo
+            | SoundParameter::EnvAHold
+            | SoundParameter::EnvAAfter
+            | SoundParameter::EnvATrigger => SoundSection::EnvA,
+
+            SoundParameter::LFO1Shape
+            | SoundParameter::LFO1Speed
+            | SoundParameter::LFO1Rise
+            | SoundParameter::LFO1Phase => SoundSection::LFO1,
+
+            SoundParameter::LFO2Shape
+            | SoundParameter::LFO2Speed
+            | SoundParameter::LFO2Rise
+            | SoundParameter::LFO2Phase => SoundSection::LFO2,
+
+            SoundParameter::ArpMode
+            | SoundParameter::ArpGrid
+            | SoundParameter::ArpTempo
+            | SoundParameter::ArpHold => SoundSection::Arp,
+
+            SoundParameter::AmpLevel | SoundParameter::AmpPan => SoundSection::Amp,
+
+            SoundParameter::ModEnvFAmount
+            | SoundParameter::ModEnvFTarget
+            | SoundParameter::ModEnvAAmount
+            | SoundParameter::ModEnvATarget
+            | SoundParameter::ModLFO1Amount
+            | SoundParameter::ModLFO1Target
+            | SoundParameter::ModLFO2Amount
+            | SoundParameter::ModLFO2Target
+            | SoundParameter::ModModwheelAmount
+            | SoundParameter::ModModwheelTarget
+            | SoundParameter::ModPitchAmount
+            | SoundParameter::ModPitchTarget
+            | SoundParameter::ModVelocityAmount
+            | SoundParameter::ModVelocityTarget
+            | SoundParameter::ModAftertouchAmount
+            | SoundParameter::ModAftertouchTarget => SoundSection::Modulation,
+
+            SoundParameter::BendRange | SoundParameter::Tune | SoundParameter::PolyMode => {
+                SoundSection::Misc
+            }
+        }
+    }
+
+    /// Return the full name of the parameter
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            // Osc 1
+            SoundParameter::Osc1Wave => "Osc 1 Wave",
+            SoundParameter::Osc1Coarse => "Osc 1 Coarse",
+            SoundParameter::Osc1FMAmount => "Osc 1 FM Amount",
+            SoundParameter::Osc1Level => "Osc 1 Level",
+            SoundParameter::Osc1Table => "Osc 1 Table",
+            SoundParameter::Osc1Fine => "Osc 1 Fine",
+            SoundParameter::Osc1FMRate => "Osc 1 FM Rate",
+            SoundParameter::Osc1Sync => "Osc 1 Sync",
+
+            // Osc 2
+            SoundParameter::Osc2Wave => "Osc 2 Wave",
+            SoundParameter::Osc2FMAmount => "Osc 2 FM Amount",
+            SoundParameter::Osc2Level => "Osc 2 Level",
+            SoundParameter::Osc2Coarse => "Osc 2 Coarse",
+            SoundParameter::Osc2Table => "Osc 2 Table",
+            SoundParameter::Osc2Fine => "Osc 2 Fine",
+            SoundParameter::Osc2FMRate => "Osc 2 FM Rate",
+            SoundParameter::Osc2Sync => "Osc 2 Sync",
+
+            // Extra
+            SoundParameter::ExtraNoise => "Extra Noise",
+            SoundParameter::ExtraRingMod => "Extra O1xO2",
+
+            // Filter
+            SoundParameter::FilterCutoff => "Filter Cutoff",
+            SoundParameter::FilterResonance => "Filter Resonance",
+            SoundParameter::FilterEnvFAmount => "Filter Env F Amount",
+            SoundParameter::FilterTrack => "Filter Track",
+            SoundParameter::FilterAfter => "Filter Aftertouch",
+            SoundParameter::FilterLFO1Amount => "Filter LFO 1 Amount",
+
+            // Shaper
+            SoundParameter::ShaperCutoff => "Shaper Cutoff",
+            SoundParameter::ShaperResonance => "Shaper Resonance",
+            SoundParameter::ShaperEnvAAmount => "Shaper Env A Amount",
+            SoundParameter::ShaperTrack => "Shaper Track",
+            SoundParameter::ShaperMode => "Shaper Mode",
+            SoundParameter::ShaperLFO2Amount => "Shaper LFO 2 Amount",
+
+            // Env F
+            SoundParameter::EnvFAttack => "Env F Attack",
+            SoundParameter::EnvFDecay => "Env F Decay",
+            SoundParameter::EnvFSustain => "Env F Sustain",
+            SoundParameter::EnvFRelease => "Env F Release",
+            SoundParameter::EnvFVelo => "Env F Velocity",
+            SoundParameter::EnvFHold => "Env F Hold",
+            SoundParameter::EnvFAfter => "Env F Aftertouch",
+            SoundParameter::EnvFTrigger => "Env F Trigger",
+
+            // Env A
+            SoundParameter::EnvAAttack => "Env A Attack",
+            SoundParameter::EnvADecay => "Env A Decay",
+            SoundParameter::EnvASustain => "Env A Sustain",
+            SoundParameter::EnvARelease => "Env A Release",
+            SoundParameter::EnvAVelo => "Env A Velocity",
+            SoundParameter::EnvAHold => "Env A Hold",
+            SoundParameter::EnvAAfter => "Env A Aftertouch",
+            SoundParameter::EnvATrigger => "Env A Trigger",
+
+            // LFO 1
+            SoundParameter::LFO1Shape => "LFO 1 Shape",
+            SoundParameter::LFO1Speed => "LFO 1 Speed",
+            SoundParameter::LFO1Rise => "LFO 1 Rise",
+            SoundParameter::LFO1Phase => "LFO 1 Phase",
+
+            // LFO 2
+            SoundParameter::LFO2Shape => "LFO 2 Shape",
+            SoundParameter::LFO2Speed => "LFO 2 Speed",
+            SoundParameter::LFO2Rise => "LFO 2 Rise",
+            SoundParameter::LFO2Phase => "LFO 2 Phase",
+
+            // Arpeggiator
+            SoundParameter::ArpMode => "Arp Mode",
+            SoundParameter::ArpGrid => "Arp Grid",
+            SoundParameter::ArpTempo => "Arp Tempo",
+            SoundParameter::ArpHold => "Arp Hold",
+
+            // Amplifier
+            SoundParameter::AmpLevel => "Amp Level",
+            SoundParameter::AmpPan => "Amp Pan",
+
+            // Modulations
+            SoundParameter::ModEnvFAmount => "Mod Env F Amount",
+            SoundParameter::ModEnvFTarget => "Mod Env F Target",
+            SoundParameter::ModEnvAAmount => "Mod Env A Amount",
+            SoundParameter::ModEnvATarget => "Mod Env A Target",
+            SoundParameter::ModLFO1Amount => "Mod LFO 1 Amount",
+            SoundParameter::ModLFO1Target => "Mod LFO 1 Target",
+            SoundParameter::ModLFO2Amount => "Mod LFO 2 Amount",
+            SoundParameter::ModLFO2Target => "Mod LFO 2 Target",
+            SoundParameter::ModModwheelAmount => "Mod Modwheel Amount",
+            SoundParameter::ModModwheelTarget => "Mod Modwheel Target",
+            SoundParameter::ModPitchAmount => "Mod Pitch Amount",
+            SoundParameter::ModPitchTarget => "Mod Pitch Target",
+            SoundParameter::ModVelocityAmount => "Mod Velocity Amount",
+            SoundParameter::ModVelocityTarget => "Mod Velocity Target",
+            SoundParameter::ModAftertouchAmount => "Mod Aftertouch Amount",
+            SoundParameter::ModAftertouchTarget => "Mod Aftertouch Target",
+
+            // Misc
+            SoundParameter::BendRange => "Bend Range",
+            SoundParameter::Tune => "Tune",
+            SoundParameter::PolyMode => "Poly Mode",
+        }
+    }
+}
+
+impl std::fmt::Display for SoundParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+/// Enum containing the sections of the sound parameters
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum SoundSection {
+    Osc1,
+    Osc2,
+    Extra,
+    Filter,
+    Shaper,
+    EnvF,
+    EnvA,
+    LFO1,
+    LFO2,
+    Arp,
+    Amp,
+    Modulation,
+    Misc,
+}
+
+impl SoundSection {
+    /// All sections in display order
+    pub const ALL: [SoundSection; 13] = [
+        SoundSection::Osc1,
+        SoundSection::Osc2,
+        SoundSection::Extra,
+        SoundSection::Filter,
+        SoundSection::Shaper,
+        SoundSection::EnvF,
+        SoundSection::EnvA,
+        SoundSection::LFO1,
+        SoundSection::LFO2,
+        SoundSection::Arp,
+        SoundSection::Amp,
+        SoundSection::Modulation,
+        SoundSection::Misc,
+    ];
+
+    /// Return all parameters of the section
+    pub fn params(&self) -> impl Iterator<Item = SoundParameter> + '_ {
+        SoundParameter::ALL
+            .iter()
+            .copied()
+            .filter(move |param| param.get_section() == *self)
+    }
+
+    /// Return the section with a parallel parameter set, if any
+    pub fn get_twin(&self) -> Option<SoundSection> {
+        match self {
+            SoundSection::Osc1 => Some(SoundSection::Osc2),
+            SoundSection::Osc2 => Some(SoundSection::Osc1),
+            SoundSection::EnvF => Some(SoundSection::EnvA),
+            SoundSection::EnvA => Some(SoundSection::EnvF),
+            SoundSection::LFO1 => Some(SoundSection::LFO2),
+            SoundSection::LFO2 => Some(SoundSection::LFO1),
+            SoundSection::Filter => Some(SoundSection::Shaper),
+            SoundSection::Shaper => Some(SoundSection::Filter),
+            _ => None,
+        }
+    }
+
+    /// Return the pairs of corresponding parameters of the section and its twin
+    ///
+    /// Envelopes and LFOs include their modulation routing. Filter and shaper
+    /// only share the parameters both of them have.
+    pub fn twin_params(&self) -> Vec<(SoundParameter, SoundParameter)> {
+        let pairs: &[(SoundParameter, SoundParameter)] = match self {
+            SoundSection::Osc1 | SoundSection::Osc2 => &[
+                (SoundParameter::Osc1Wave, SoundParameter::Osc2Wave),
+                (SoundParameter::Osc1Coarse, SoundParameter::Osc2Coarse),
+                (SoundParameter::Osc1FMAmount, SoundParameter::Osc2FMAmount),
+                (SoundParameter::Osc1Level, SoundParameter::Osc2Level),
+                (SoundParameter::Osc1Table, SoundParameter::Osc2Table),
+                (SoundParameter::Osc1Fine, SoundParameter::Osc2Fine),
+                (SoundParameter::Osc1FMRate, SoundParameter::Osc2FMRate),
+                (SoundParameter::Osc1Sync, SoundParameter::Osc2Sync),
+            ],
+            SoundSection::EnvF | SoundSection::EnvA => &[
+                (SoundParameter::EnvFAttack, SoundParameter::EnvAAttack),
+                (SoundParameter::EnvFDecay, SoundParameter::EnvADecay),
+                (SoundParameter::EnvFSustain, SoundParameter::EnvASustain),
+                (SoundParameter::EnvFRelease, SoundParameter::EnvARelease),
+                (SoundParameter::EnvFVelo, SoundParameter::EnvAVelo),
+                (SoundParameter::EnvFHold, SoundParameter::EnvAHold),
+                (SoundParameter::EnvFAfter, SoundParameter::EnvAAfter),
+                (SoundParameter::EnvFTrigger, SoundParameter::EnvATrigger),
+                (SoundParameter::ModEnvFAmount, SoundParameter::ModEnvAAmount),
+                (SoundParameter::ModEnvFTarget, SoundParameter::ModEnvATarget),
+            ],
+            SoundSection::LFO1 | SoundSection::LFO2 => &[
+                (SoundParameter::LFO1Shape, SoundParameter::LFO2Shape),
+                (SoundParameter::LFO1Speed, SoundParameter::LFO2Speed),
+                (SoundParameter::LFO1Rise, SoundParameter::LFO2Rise),
+                (SoundParameter::LFO1Phase, SoundParameter::LFO2Phase),
+                (SoundParameter::ModLFO1Amount, SoundParameter::ModLFO2Amount),
+                (SoundParameter::ModLFO1Target, SoundParameter::ModLFO2Target),
+            ],
+            SoundSection::Filter | SoundSection::Shaper => &[
+                (SoundParameter::FilterCutoff, SoundParameter::ShaperCutoff),
+                (
+                    SoundParameter::FilterResonance,
+                    SoundParameter::ShaperResonance,
+                ),
+                (SoundParameter::FilterTrack, SoundParameter::ShaperTrack),
+                (
+                    SoundParameter::FilterEnvFAmount,
+                    SoundParameter::ShaperEnvAAmount,
+                ),
+                (
+                    SoundParameter::FilterLFO1Amount,
+                    SoundParameter::ShaperLFO2Amount,
+                ),
+            ],
+            _ => &[],
+        };
+
+        // Pairs are ordered (section, twin)
+        let first = matches!(
+            self,
+            SoundSection::Osc1 | SoundSection::EnvF | SoundSection::LFO1 | SoundSection::Filter
+        );
+
+        pairs
+            .iter()
+            .map(|(a, b)| if first { (*a, *b) } else { (*b, *a) })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for SoundSection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                SoundSection::Osc1 => "Osc 1",
+                SoundSection::Osc2 => "Osc 2",
+                SoundSection::Extra => "Extra",
+                SoundSection::Filter => "Filter",
+                SoundSection::Shaper => "Shaper",
+                SoundSection::EnvF => "Env F",
+                SoundSection::EnvA => "Env A",
+                SoundSection::LFO1 => "LFO 1",
+                SoundSection::LFO2 => "LFO 2",
+                SoundSection::Arp => "Arpeggiator",
+                SoundSection::Amp => "Amp",
+                SoundSection::Modulation => "Modulation",
+                SoundSection::Misc => "Misc",
+            }
+        )
+    }
 }
 
 /// Hashmap type for preset parameters
@@ -215,6 +710,37 @@ pub enum MultiParameter {
 }
 
 impl MultiParameter {
+    /// All multi parameters
+    pub const ALL: [MultiParameter; 22] = [
+        // Preset IDs
+        MultiParameter::PresetPart1,
+        MultiParameter::PresetPart2,
+        MultiParameter::PresetPart3,
+        MultiParameter::PresetPart4,
+        // MIDI channels
+        MultiParameter::ChannelPart1,
+        MultiParameter::ChannelPart2,
+        MultiParameter::ChannelPart3,
+        MultiParameter::ChannelPart4,
+        // Volumes
+        MultiParameter::VolumePart1,
+        MultiParameter::VolumePart2,
+        MultiParameter::VolumePart3,
+        MultiParameter::VolumePart4,
+        // Balances
+        MultiParameter::BalancePart1,
+        MultiParameter::BalancePart2,
+        MultiParameter::BalancePart3,
+        MultiParameter::BalancePart4,
+        // FX
+        MultiParameter::FXLength,
+        MultiParameter::FXFeedback,
+        MultiParameter::FXMix,
+        MultiParameter::FXMode,
+        MultiParameter::FXSpeed,
+        MultiParameter::FXDepth,
+    ];
+
     /// Return the value range of the parameter
     pub fn get_range(&self) -> RangeInclusive<i32> {
         match self {
@@ -256,6 +782,95 @@ impl MultiParameter {
     pub fn get_default(&self) -> i32 {
         0
     }
+
+    /// Return the full name of the parameter
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            // Preset IDs
+            MultiParameter::PresetPart1 => "Part 1 Preset",
+            MultiParameter::PresetPart2 => "Part 2 Preset",
+            MultiParameter::PresetPart3 => "Part 3 Preset",
+            MultiParameter::PresetPart4 => "Part 4 Preset",
+
+            // MIDI channels
+            MultiParameter::ChannelPart1 => "Part 1 Channel",
+            MultiParameter::ChannelPart2 => "Part 2 Channel",
+            MultiParameter::ChannelPart3 => "Part 3 Channel",
+            MultiParameter::ChannelPart4 => "Part 4 Channel",
+
+            // Volumes
+            MultiParameter::VolumePart1 => "Part 1 Volume",
+            MultiParameter::VolumePart2 => "Part 2 Volume",
+            MultiParameter::VolumePart3 => "Part 3 Volume",
+            MultiParameter::VolumePart4 => "Part 4 Volume",
+
+            // Balances
+            MultiParameter::BalancePart1 => "Part 1 Balance",
+            MultiParameter::BalancePart2 => "Part 2 Balance",
+            MultiParameter::BalancePart3 => "Part 3 Balance",
+            MultiParameter::BalancePart4 => "Part 4 Balance",
+
+            // FX
+            MultiParameter::FXLength => "FX Length",
+            MultiParameter::FXFeedback => "FX Feedback",
+            MultiParameter::FXMix => "FX Mix",
+            MultiParameter::FXMode => "FX Mode",
+            MultiParameter::FXSpeed => "FX Speed",
+            MultiParameter::FXDepth => "FX Depth",
+        }
+    }
+
+    /// Return the preset assignment parameter of a part
+    ///
+    /// - `part_id`     Part id 0-3 for part 1-4
+    pub fn from_preset_part(part_id: u8) -> Self {
+        match part_id {
+            0 => MultiParameter::PresetPart1,
+            1 => MultiParameter::PresetPart2,
+            2 => MultiParameter::PresetPart3,
+            _ => MultiParameter::PresetPart4,
+        }
+    }
+
+    /// Return true if the parameter has a continuous range of values,
+    /// false for lists
+    pub fn is_continuous(&self) -> bool {
+        !matches!(
+            self,
+            MultiParameter::PresetPart1
+                | MultiParameter::PresetPart2
+                | MultiParameter::PresetPart3
+                | MultiParameter::PresetPart4
+                | MultiParameter::ChannelPart1
+                | MultiParameter::ChannelPart2
+                | MultiParameter::ChannelPart3
+                | MultiParameter::ChannelPart4
+                | MultiParameter::FXMode
+        )
+    }
+
+    /// Return the MIDI channel parameter of a part
+    ///
+    /// - `part_id`     Part id 0-3 for part 1-4
+    pub fn from_channel_part(part_id: u8) -> Self {
+        match part_id {
+            0 => MultiParameter::ChannelPart1,
+            1 => MultiParameter::ChannelPart2,
+            2 => MultiParameter::ChannelPart3,
+            _ => MultiParameter::ChannelPart4,
+        }
+    }
+
+    /// Return the part id 0-3 if the parameter is the preset assignment of a part
+    pub fn get_preset_part(&self) -> Option<u8> {
+        match self {
+            MultiParameter::PresetPart1 => Some(0),
+            MultiParameter::PresetPart2 => Some(1),
+            MultiParameter::PresetPart3 => Some(2),
+            MultiParameter::PresetPart4 => Some(3),
+            _ => None,
+        }
+    }
 }
 
 /// Hashmap type for preset parameters
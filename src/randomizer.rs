@@ -0,0 +1,179 @@
+//! Generation of random sounds
+//!
+//! New values are generated within the range of each parameter, either
+//! fully random or as a variation of the current sound. Locked parameters
+//! are never changed and a few constraints prevent unusable results.
+
+use std::collections::HashSet;
+
+use rand::Rng;
+
+use crate::params::{GetValue, SoundParameter, SoundParameterValues, SoundSection};
+
+/// Minimum amp level of a generated sound
+const MIN_AMP_LEVEL: i32 = 96;
+
+/// Minimum level of the louder oscillator of a generated sound
+const MIN_OSC_LEVEL: i32 = 64;
+
+/// Parameters affected by randomization
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RandomizeScope {
+    /// All parameters
+    All,
+
+    /// Parameters of a single section
+    Section(SoundSection),
+}
+
+impl RandomizeScope {
+    /// Return all scopes in display order
+    pub fn all() -> Vec<RandomizeScope> {
+        std::iter::once(RandomizeScope::All)
+            .chain(
+                SoundSection::ALL
+                    .iter()
+                    .map(|s| RandomizeScope::Section(*s)),
+            )
+            .collect()
+    }
+
+    /// Return true if the parameter is part of the scope
+    pub fn contains(&self, param: SoundParameter) -> bool {
+        match self {
+            RandomizeScope::All => true,
+            RandomizeScope::Section(section) => param.get_section() == *section,
+        }
+    }
+
+    /// Return true if the section is part of the scope
+    pub fn contains_section(&self, section: SoundSection) -> bool {
+        match self {
+            RandomizeScope::All => true,
+            RandomizeScope::Section(scope_section) => *scope_section == section,
+        }
+    }
+}
+
+impl std::fmt::Display for RandomizeScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RandomizeScope::All => write!(f, "All sections"),
+            RandomizeScope::Section(section) => write!(f, "{}", section),
+        }
+    }
+}
+
+/// Settings of the randomizer
+pub struct Randomizer {
+    /// Parameters affected by randomization
+    pub scope: RandomizeScope,
+
+    /// Amount of change in percent, 100 for fully random values
+    pub amount: i32,
+
+    /// Parameters never changed by the randomizer
+    pub locks: HashSet<SoundParameter>,
+}
+
+impl Randomizer {
+    /// Constructs a new instance
+    pub fn new() -> Self {
+        Self {
+            scope: RandomizeScope::All,
+            amount: 100,
+            locks: HashSet::new(),
+        }
+    }
+
+    /// Return a new sound generated from the current one
+    ///
+    /// - `current`     Current sound parameters
+    /// - `rng`         Random number generator
+    pub fn generate(
+        &self,
+        current: &SoundParameterValues,
+        rng: &mut impl Rng,
+    ) -> SoundParameterValues {
+        let mut values = current.clone();
+        let amount = self.amount.clamp(0, 100);
+
+        let changed: Vec<SoundParameter> = SoundParameter::ALL
+            .iter()
+            .copied()
+            .filter(|param| self.scope.contains(*param) && !self.locks.contains(param))
+            .collect();
+
+        for param in changed.iter() {
+            let value = vary(*param, current.get_value(*param), amount, rng);
+            values.insert(*param, value);
+        }
+
+        // Constraints are only applied to the parameters changed in this run
+        if changed.contains(&SoundParameter::AmpLevel)
+            && values.get_value(SoundParameter::AmpLevel) < MIN_AMP_LEVEL
+        {
+            let range = SoundParameter::AmpLevel.get_range();
+            values.insert(
+                SoundParameter::AmpLevel,
+                rng.gen_range(MIN_AMP_LEVEL..=*range.end()),
+            );
+        }
+
+        let osc_levels = [SoundParameter::Osc1Level, SoundParameter::Osc2Level];
+        if osc_levels
+            .iter()
+            .all(|param| values.get_value(*param) < MIN_OSC_LEVEL)
+        {
+            let adjustable: Vec<SoundParameter> = osc_levels
+                .iter()
+                .copied()
+                .filter(|param| changed.contains(param))
+                .collect();
+            if !adjustable.is_empty() {
+                let param = adjustable[rng.gen_range(0..adjustable.len())];
+                let range = param.get_range();
+                values.insert(param, rng.gen_range(MIN_OSC_LEVEL..=*range.end()));
+            }
+        }
+
+        values
+    }
+}
+
+/// Return a variation of a sound, changing all parameters without any constraints
+///
+/// - `current`     Sound parameters to be varied
+/// - `amount`      Amount of change in percent, 100 for fully random values
+/// - `rng`         Random number generator
+pub fn mutate(
+    current: &SoundParameterValues,
+    amount: i32,
+    rng: &mut impl Rng,
+) -> SoundParameterValues {
+    let amount = amount.clamp(0, 100);
+
+    SoundParameter::ALL
+        .iter()
+        .map(|param| (*param, vary(*param, current.get_value(*param), amount, rng)))
+        .collect()
+}
+
+/// Return a variation of a parameter value
+///
+/// Continuous values are moved by up to `amount` percent of their range,
+/// lists and switches change to a random value with a probability of `amount`.
+fn vary(param: SoundParameter, value: i32, amount: i32, rng: &mut impl Rng) -> i32 {
+    let range = param.get_range();
+
+    if amount >= 100 {
+        rng.gen_range(range)
+    } else if param.is_continuous() {
+        let span = (range.end() - range.start()) * amount / 100;
+        (value + rng.gen_range(-span..=span)).clamp(*range.start(), *range.end())
+    } else if rng.gen_bool(amount as f64 / 100.0) {
+        rng.gen_range(range)
+    } else {
+        value
+    }
+}
@@ -0,0 +1,174 @@
+//! Panel for the evolutionary breeding of sounds
+
+use iced::widget::{Button, Checkbox, Column, Container, Row, Scrollable, Text};
+use iced::{theme, Alignment, Element, Length};
+
+use super::style;
+use crate::breeding::{Breeder, MIN_PARENTS};
+use crate::messages::Message;
+use crate::ui::elements::slider_widget::Slider;
+use crate::ui::elements::text_button::text_button;
+
+pub struct BreedingPanel {}
+
+impl BreedingPanel {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn view(&self, breeder: &Breeder) -> Element<'_, Message> {
+        // Parents
+        let mut parents = Column::new()
+            .padding(style::SECTION_PADDING)
+            .spacing(5)
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .align_items(Alignment::Center)
+                    .push(
+                        Text::new(if breeder.can_breed() {
+                            format!("Parents ({})", breeder.parents.len())
+                        } else {
+                            format!(
+                                "Parents ({}, at least {} needed)",
+                                breeder.parents.len(),
+                                MIN_PARENTS
+                            )
+                        })
+                        .size(style::section_label_text_size())
+                        .width(Length::Fill),
+                    )
+                    .push(text_button(
+                        "Add current sound",
+                        Some(Message::BreedingAddCurrent),
+                    ))
+                    .push(text_button(
+                        "Add syx files...",
+                        Some(Message::BreedingAddFiles),
+                    ))
+                    .push(text_button("Clear", Some(Message::BreedingClearParents))),
+            );
+
+        for (index, parent) in breeder.parents.iter().enumerate() {
+            parents = parents.push(
+                Row::new()
+                    .spacing(10)
+                    .align_items(Alignment::Center)
+                    .push(
+                        Text::new(parent.name.clone())
+                            .size(style::param_label_text_size())
+                            .width(Length::Fill),
+                    )
+                    .push(text_button(
+                        "Remove",
+                        Some(Message::BreedingRemoveParent(index)),
+                    )),
+            );
+        }
+
+        // Breeding controls
+        let controls = Row::new()
+            .padding(style::SECTION_PADDING)
+            .spacing(15)
+            .align_items(Alignment::Center)
+            .push(Text::new("Mutation").size(style::param_label_text_size()))
+            .push(
+                Column::new()
+                    .push(
+                        Slider::new(
+                            0..=50,
+                            breeder.mutation,
+                            10,
+                            Message::BreedingMutationChange,
+                        )
+                        .style(style::Slider),
+                    )
+                    .width(200),
+            )
+            .push(
+                Text::new(format!("{}%", breeder.mutation))
+                    .size(style::param_label_text_size())
+                    .width(40),
+            )
+            .push(Column::new().width(Length::Fill))
+            .push(text_button(
+                "Breed",
+                breeder.can_breed().then_some(Message::Breed),
+            ))
+            .push(text_button(
+                "Next generation",
+                breeder
+                    .can_advance()
+                    .then_some(Message::BreedingNextGeneration),
+            ));
+
+        // Children
+        let mut children = Column::new()
+            .padding(style::SECTION_PADDING)
+            .spacing(5)
+            .push(
+                Text::new(if breeder.generation == 0 {
+                    String::from("Children")
+                } else {
+                    format!("Children of generation {}", breeder.generation)
+                })
+                .size(style::section_label_text_size()),
+            );
+
+        for (index, child) in breeder.children.iter().enumerate() {
+            let auditioned = breeder.auditioned == Some(index);
+            children = children.push(
+                Row::new()
+                    .spacing(10)
+                    .align_items(Alignment::Center)
+                    .push(
+                        Text::new(child.individual.name.clone())
+                            .size(style::param_label_text_size())
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        Button::new(Text::new("Audition").size(style::button_text_size()))
+                            .style(if auditioned {
+                                theme::Button::Positive
+                            } else {
+                                theme::Button::Primary
+                            })
+                            .on_press(Message::BreedingAudition(index)),
+                    )
+                    .push(
+                        Checkbox::new("Keep", child.keep, move |v| {
+                            Message::BreedingKeepToggle(index, v)
+                        })
+                        .style(style::Checkbox)
+                        .text_size(style::list_item_text_size())
+                        .spacing(7),
+                    )
+                    .push(text_button(
+                        "Save syx file...",
+                        Some(Message::BreedingSaveChild(index)),
+                    )),
+            );
+        }
+
+        Container::new(
+            Column::new()
+                .spacing(10)
+                .push(
+                    Container::new(Scrollable::new(parents).height(150))
+                        .style(style::OverviewSection)
+                        .width(Length::Fill),
+                )
+                .push(Container::new(controls).style(style::OverviewSection))
+                .push(
+                    Container::new(Scrollable::new(children).height(Length::Fill))
+                        .style(style::OverviewSection)
+                        .width(Length::Fill)
+                        .height(Length::Fill),
+                ),
+        )
+        .padding(10)
+        .height(Length::Fill)
+        .style(style::MainWindow)
+        .into()
+    }
+}
@@ -0,0 +1,134 @@
+//! Panel containing the command palette and its results
+
+use std::collections::HashMap;
+
+use iced::widget::{text_input, Button, Column, Container, Row, Text, TextInput};
+use iced::{theme, Alignment, Element, Length};
+
+use super::style;
+use crate::command_palette::{CommandPalette, PaletteItem, PaletteMatch};
+use crate::key_bindings::KeyAction;
+use crate::messages::Message;
+
+/// Return the id of the search input
+pub fn input_id() -> text_input::Id {
+    text_input::Id::new("command_palette")
+}
+
+pub struct CommandPalettePanel {}
+
+impl CommandPalettePanel {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Returns the panel
+    ///
+    /// - `palette`     State of the palette
+    /// - `bindings`    Shortcuts replacing the defaults of their actions
+    pub fn view(
+        &self,
+        palette: &CommandPalette,
+        bindings: &HashMap<KeyAction, String>,
+    ) -> Element<'_, Message> {
+        let input = Row::new()
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .push(
+                TextInput::new(
+                    "Search parameters, sections, pages and actions",
+                    &palette.query,
+                )
+                .id(input_id())
+                .on_input(Message::PaletteChange)
+                .on_submit(Message::PaletteSubmit)
+                .size(style::param_label_text_size())
+                .padding(5)
+                .width(Length::Fill),
+            )
+            .push(
+                Button::new(Text::new("Close").size(style::button_text_size()))
+                    .style(theme::Button::Primary)
+                    .on_press(Message::PaletteClose),
+            );
+
+        let mut results = Column::new().spacing(2);
+        for (index, found) in palette.matches.iter().enumerate() {
+            results = results.push(result_button(
+                index,
+                found,
+                index == palette.selected,
+                bindings,
+            ));
+        }
+
+        let hint = if palette.query.trim().is_empty() {
+            "Type a name, add a value to set it directly, for example \"cutoff 200\""
+        } else if palette.matches.is_empty() {
+            "No matches"
+        } else {
+            "Up/Down to select, Enter to apply, Esc twice to close"
+        };
+
+        Container::new(
+            Container::new(
+                Column::new()
+                    .padding(style::SECTION_PADDING)
+                    .spacing(10)
+                    .push(Text::new("Command Palette").size(style::section_label_text_size()))
+                    .push(input)
+                    .push(Text::new(hint).size(style::status_text_size()))
+                    .push(results),
+            )
+            .style(style::OverviewSection)
+            .width(Length::Fill)
+            .height(Length::Fill),
+        )
+        .padding(10)
+        .height(Length::Fill)
+        .style(style::MainWindow)
+        .into()
+    }
+}
+
+/// Returns a result line, highlighted if selected
+fn result_button<'a>(
+    index: usize,
+    found: &PaletteMatch,
+    selected: bool,
+    bindings: &HashMap<KeyAction, String>,
+) -> Button<'a, Message> {
+    let detail = match (found.item, found.value) {
+        (item, Some(value)) => format!("Set to {}", item.format_value(value)),
+        (PaletteItem::Action(action), None) => action.get_shortcuts(bindings).to_string(),
+        _ => String::new(),
+    };
+
+    Button::new(
+        Row::new()
+            .spacing(10)
+            .push(
+                Text::new(found.item.get_name())
+                    .size(style::param_label_text_size())
+                    .width(Length::Fill),
+            )
+            .push(
+                Text::new(detail)
+                    .size(style::param_label_text_size())
+                    .width(150),
+            )
+            .push(
+                Text::new(found.item.get_kind())
+                    .size(style::param_label_text_size())
+                    .width(120),
+            ),
+    )
+    .style(if selected {
+        theme::Button::Primary
+    } else {
+        theme::Button::Text
+    })
+    .padding([2, 5])
+    .width(Length::Fill)
+    .on_press(Message::PalettePick(index))
+}
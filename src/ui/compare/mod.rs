@@ -0,0 +1,115 @@
+//! Panel listing the differences between the A/B compare slots
+
+use iced::widget::{Button, Column, Container, Row, Scrollable, Text};
+use iced::{theme, Alignment, Element, Length};
+
+use super::style;
+use crate::compare::{CompareSlots, Slot};
+use crate::messages::Message;
+use crate::params::SoundParameterValues;
+use crate::ui::elements::slot_button::slot_button;
+
+pub struct ComparePanel {}
+
+impl ComparePanel {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Returns the panel
+    ///
+    /// - `part_id`     Current part id 0-3
+    /// - `slots`       Compare slots of the current part
+    /// - `params`      Sound parameters of the current part
+    pub fn view(
+        &self,
+        part_id: u8,
+        slots: &CompareSlots,
+        params: &SoundParameterValues,
+    ) -> Element<'_, Message> {
+        let differences = slots.differences(params);
+
+        let controls = Row::new()
+            .padding(style::SECTION_PADDING)
+            .spacing(15)
+            .align_items(Alignment::Center)
+            .push(
+                Text::new(format!(
+                    "Part {}, slot {} active, {} differences",
+                    part_id + 1,
+                    slots.active(),
+                    differences.len()
+                ))
+                .size(style::section_label_text_size()),
+            )
+            .push(Column::new().width(Length::Fill))
+            .push(slot_button(Slot::A, slots.active()))
+            .push(slot_button(Slot::B, slots.active()))
+            .push(
+                Button::new(Text::new("Copy A to B").size(style::button_text_size()))
+                    .style(theme::Button::Primary)
+                    .on_press(Message::CompareCopyAToB),
+            );
+
+        let mut entries = Column::new()
+            .padding(style::SECTION_PADDING)
+            .spacing(2)
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .push(
+                        Text::new("Parameter")
+                            .size(style::param_label_text_size())
+                            .width(200),
+                    )
+                    .push(
+                        Text::new("A")
+                            .size(style::param_label_text_size())
+                            .width(60),
+                    )
+                    .push(
+                        Text::new("B")
+                            .size(style::param_label_text_size())
+                            .width(60),
+                    ),
+            );
+
+        for (param, value_a, value_b) in differences {
+            entries = entries.push(
+                Row::new()
+                    .spacing(10)
+                    .push(
+                        Text::new(param.get_name())
+                            .size(style::param_label_text_size())
+                            .width(200),
+                    )
+                    .push(
+                        Text::new(value_a.to_string())
+                            .size(style::param_label_text_size())
+                            .width(60),
+                    )
+                    .push(
+                        Text::new(value_b.to_string())
+                            .size(style::param_label_text_size())
+                            .width(60),
+                    ),
+            );
+        }
+
+        Container::new(
+            Column::new()
+                .spacing(10)
+                .push(Container::new(controls).style(style::OverviewSection))
+                .push(
+                    Container::new(Scrollable::new(entries).height(Length::Fill))
+                        .style(style::OverviewSection)
+                        .width(Length::Fill)
+                        .height(Length::Fill),
+                ),
+        )
+        .padding(10)
+        .height(Length::Fill)
+        .style(style::MainWindow)
+        .into()
+    }
+}
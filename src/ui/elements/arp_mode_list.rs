@@ -2,6 +2,7 @@
 
 use iced::widget::{Column, Container, PickList, Row, Text};
 
+use super::param_tooltip::sound_tooltip;
 use crate::messages::Message;
 use crate::params::SoundParameter;
 use crate::style;
@@ -22,21 +23,23 @@ pub fn arp_mode_list(label: &str, sound_param: SoundParameter, value: i32) -> Co
         Message::SoundParameterChange(sound_param, v as i32)
     })
     .style(style::PickList)
-    .text_size(style::LIST_ITEM_TEXT_SIZE);
+    .text_size(style::list_item_text_size());
 
-    Container::new(
+    Container::new(sound_tooltip(
         Row::new()
             .push(
                 Column::new()
                     .push(
                         Text::new(label)
-                            .size(style::PARAM_LABEL_TEXT_SIZE)
+                            .size(style::param_label_text_size())
                             .width(style::PARAM_LABEL_WIDTH),
                     )
                     .padding([4, 0, 0, 0]),
             )
             .push(pick_list),
-    )
+        sound_param,
+        &[],
+    ))
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
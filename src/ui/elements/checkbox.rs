@@ -2,6 +2,7 @@
 
 use iced::widget::{Checkbox, Container, Row, Text};
 
+use super::param_tooltip::sound_tooltip;
 use crate::messages::Message;
 use crate::params::SoundParameter;
 use crate::style;
@@ -16,16 +17,18 @@ pub fn checkbox_with_labels<'a>(
         Message::SoundParameterChange(sound_param, v as i32)
     })
     .style(style::Checkbox)
-    .text_size(style::LIST_ITEM_TEXT_SIZE)
+    .text_size(style::list_item_text_size())
     .spacing(7);
 
-    Container::new(
+    Container::new(sound_tooltip(
         Row::new()
             .push(
                 Text::new(label)
-                    .size(style::PARAM_LABEL_TEXT_SIZE)
+                    .size(style::param_label_text_size())
                     .width(style::PARAM_LABEL_WIDTH),
             )
             .push(checkbox),
-    )
+        sound_param,
+        &[],
+    ))
 }
@@ -0,0 +1,70 @@
+//! Dropdown menu for the control style of a sound section
+
+use iced::widget::{Column, Container, PickList, Row, Text};
+use serde::{Deserialize, Serialize};
+
+use crate::messages::Message;
+use crate::params::SoundSection;
+use crate::style;
+
+pub fn control_style_list<'a>(
+    section: SoundSection,
+    value: ControlStyle,
+) -> Container<'a, Message> {
+    let pick_list = PickList::new(&ControlStyle::ALL[..], Some(value), move |v| {
+        Message::ControlStyleChange(section, v)
+    })
+    .style(style::PickList)
+    .text_size(style::list_item_text_size())
+    .width(120);
+
+    Container::new(
+        Row::new()
+            .push(
+                Column::new()
+                    .push(
+                        Text::new(section.to_string())
+                            .size(style::param_label_text_size())
+                            .width(120),
+                    )
+                    .padding([4, 0, 0, 0]),
+            )
+            .push(pick_list),
+    )
+}
+
+/// Controls used for the parameters of a sound section
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ControlStyle {
+    /// Horizontal sliders with label and value in one row
+    #[default]
+    Slider,
+
+    /// Rotary knobs in a compact grid
+    Knob,
+
+    /// Vertical faders in a compact grid
+    Fader,
+}
+
+impl ControlStyle {
+    pub const ALL: [ControlStyle; 3] = [
+        ControlStyle::Slider,
+        ControlStyle::Knob,
+        ControlStyle::Fader,
+    ];
+}
+
+impl std::fmt::Display for ControlStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ControlStyle::Slider => "Sliders",
+                ControlStyle::Knob => "Knobs",
+                ControlStyle::Fader => "Faders",
+            }
+        )
+    }
+}
@@ -0,0 +1,384 @@
+//! Graphical envelope editor with draggable breakpoints
+
+use iced::alignment;
+use iced::widget::canvas::{self, event, Canvas, Cursor, Frame, Geometry, LineDash, Path, Stroke};
+use iced::{mouse, Color, Element, Length, Point, Rectangle, Size, Theme};
+
+use super::env_trigger_list::EnvTrigger;
+use crate::messages::Message;
+use crate::params::{GetValue, SoundParameter, SoundParameterValues};
+use crate::style;
+
+/// Maximum value of the envelope parameters
+const MAX_VALUE: f32 = 255.0;
+
+/// Distance of the graph from the canvas border
+const MARGIN: f32 = 4.0;
+
+/// Space above the graph for the labels
+const LABEL_HEIGHT: f32 = 16.0;
+
+/// Radius of the breakpoint handles
+const HANDLE_RADIUS: f32 = 3.0;
+
+/// Distance from a handle within which it can be grabbed
+const GRAB_DISTANCE: f32 = 8.0;
+
+/// Parameters of an envelope
+pub struct EnvParams {
+    pub attack: SoundParameter,
+    pub hold: SoundParameter,
+    pub decay: SoundParameter,
+    pub sustain: SoundParameter,
+    pub release: SoundParameter,
+    pub velo: SoundParameter,
+    pub after: SoundParameter,
+    pub trigger: SoundParameter,
+}
+
+/// Parameters of the filter envelope
+pub const ENV_F: EnvParams = EnvParams {
+    attack: SoundParameter::EnvFAttack,
+    hold: SoundParameter::EnvFHold,
+    decay: SoundParameter::EnvFDecay,
+    sustain: SoundParameter::EnvFSustain,
+    release: SoundParameter::EnvFRelease,
+    velo: SoundParameter::EnvFVelo,
+    after: SoundParameter::EnvFAfter,
+    trigger: SoundParameter::EnvFTrigger,
+};
+
+/// Parameters of the amplifier envelope
+pub const ENV_A: EnvParams = EnvParams {
+    attack: SoundParameter::EnvAAttack,
+    hold: SoundParameter::EnvAHold,
+    decay: SoundParameter::EnvADecay,
+    sustain: SoundParameter::EnvASustain,
+    release: SoundParameter::EnvARelease,
+    velo: SoundParameter::EnvAVelo,
+    after: SoundParameter::EnvAAfter,
+    trigger: SoundParameter::EnvATrigger,
+};
+
+/// Returns the envelope editor
+///
+/// - `label`       Name of the envelope drawn in the top left corner
+/// - `env`         Parameters of the envelope
+/// - `params`      Current sound parameters
+pub fn env_graph<'a>(
+    label: &'static str,
+    env: &'static EnvParams,
+    params: &SoundParameterValues,
+) -> Element<'a, Message> {
+    Canvas::new(EnvGraph {
+        label,
+        env,
+        attack: params.get_value(env.attack),
+        hold: params.get_value(env.hold),
+        decay: params.get_value(env.decay),
+        sustain: params.get_value(env.sustain),
+        release: params.get_value(env.release),
+        velo: params.get_value(env.velo),
+        after: params.get_value(env.after),
+        trigger: params.get_value(env.trigger),
+    })
+    .width(Length::Fill)
+    .height(style::GRAPH_HEIGHT)
+    .into()
+}
+
+/// Breakpoint of the envelope that can be dragged
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Handle {
+    Attack,
+    Hold,
+    Decay,
+    Sustain,
+    Release,
+}
+
+impl Handle {
+    const ALL: [Handle; 5] = [
+        Handle::Attack,
+        Handle::Hold,
+        Handle::Decay,
+        Handle::Sustain,
+        Handle::Release,
+    ];
+}
+
+/// Interaction state of the editor
+#[derive(Default)]
+struct State {
+    /// Breakpoint being dragged
+    dragging: Option<Handle>,
+}
+
+/// Envelope values to be drawn
+struct EnvGraph {
+    label: &'static str,
+    env: &'static EnvParams,
+    attack: i32,
+    hold: i32,
+    decay: i32,
+    sustain: i32,
+    release: i32,
+    velo: i32,
+    after: i32,
+    trigger: i32,
+}
+
+/// Positions of the envelope stages within the canvas
+struct Layout {
+    /// Width of a stage at the maximum value
+    stage_width: f32,
+
+    /// Y position of the maximum level
+    top: f32,
+
+    /// Y position of level zero
+    bottom: f32,
+
+    /// Start, end of attack, hold, decay, sustain and release
+    points: [Point; 6],
+}
+
+impl Layout {
+    /// Return the y position of a level 0.0-1.0
+    fn level_y(&self, level: f32) -> f32 {
+        self.bottom - (self.bottom - self.top) * level.clamp(0.0, 1.0)
+    }
+
+    /// Return the envelope path with all levels scaled
+    fn path(&self, scale: f32) -> Path {
+        Path::new(|builder| {
+            builder.move_to(self.points[0]);
+            for point in &self.points[1..] {
+                let level = (self.bottom - point.y) / (self.bottom - self.top);
+                builder.line_to(Point::new(point.x, self.level_y(level * scale)));
+            }
+        })
+    }
+}
+
+impl EnvGraph {
+    /// Return the layout of the envelope for the canvas size
+    fn layout(&self, size: Size) -> Layout {
+        // Attack, hold, decay, sustain and release share the width equally at maximum values
+        let stage_width = (size.width - 2.0 * MARGIN) / 5.0;
+        let top = LABEL_HEIGHT;
+        let bottom = size.height - MARGIN;
+        let stage = |value: i32| stage_width * value as f32 / MAX_VALUE;
+        let sustain_y = bottom - (bottom - top) * self.sustain as f32 / MAX_VALUE;
+
+        let start = Point::new(MARGIN, bottom);
+        let attack = Point::new(start.x + stage(self.attack), top);
+        let hold = Point::new(attack.x + stage(self.hold), top);
+        let decay = Point::new(hold.x + stage(self.decay), sustain_y);
+        let sustain = Point::new(decay.x + stage_width, sustain_y);
+        let release = Point::new(sustain.x + stage(self.release), bottom);
+
+        Layout {
+            stage_width,
+            top,
+            bottom,
+            points: [start, attack, hold, decay, sustain, release],
+        }
+    }
+
+    /// Return the position of a handle
+    fn handle_position(&self, layout: &Layout, handle: Handle) -> Point {
+        let points = &layout.points;
+        match handle {
+            Handle::Attack => points[1],
+            Handle::Hold => points[2],
+            Handle::Decay => points[3],
+            Handle::Sustain => Point::new((points[3].x + points[4].x) / 2.0, points[3].y),
+            Handle::Release => points[5],
+        }
+    }
+
+    /// Return the handle close to a position
+    fn handle_at(&self, layout: &Layout, position: Point) -> Option<Handle> {
+        // Later handles are preferred, so that stages of length zero can be extended
+        Handle::ALL.iter().rev().copied().find(|handle| {
+            self.handle_position(layout, *handle).distance(position) <= GRAB_DISTANCE
+        })
+    }
+
+    /// Return the message for dragging a handle to a position
+    fn drag(&self, layout: &Layout, handle: Handle, position: Point) -> Message {
+        let points = &layout.points;
+        let time = |from: Point| {
+            ((position.x - from.x) / layout.stage_width * MAX_VALUE)
+                .round()
+                .clamp(0.0, MAX_VALUE) as i32
+        };
+
+        match handle {
+            Handle::Attack => Message::SoundParameterChange(self.env.attack, time(points[0])),
+            Handle::Hold => Message::SoundParameterChange(self.env.hold, time(points[1])),
+            Handle::Decay => Message::SoundParameterChange(self.env.decay, time(points[2])),
+            Handle::Sustain => {
+                let level = ((layout.bottom - position.y) / (layout.bottom - layout.top)
+                    * MAX_VALUE)
+                    .round()
+                    .clamp(0.0, MAX_VALUE) as i32;
+                Message::SoundParameterChange(self.env.sustain, level)
+            }
+            Handle::Release => Message::SoundParameterChange(self.env.release, time(points[4])),
+        }
+    }
+}
+
+impl canvas::Program<Message> for EnvGraph {
+    type State = State;
+
+    fn update(
+        &self,
+        state: &mut State,
+        event: canvas::Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (event::Status, Option<Message>) {
+        let layout = self.layout(bounds.size());
+
+        match event {
+            canvas::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_in(&bounds) {
+                    if let Some(handle) = self.handle_at(&layout, position) {
+                        state.dragging = Some(handle);
+                        return (event::Status::Captured, None);
+                    }
+                }
+            }
+            canvas::Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let (Some(handle), Some(position)) =
+                    (state.dragging, cursor.position_from(bounds.position()))
+                {
+                    return (
+                        event::Status::Captured,
+                        Some(self.drag(&layout, handle, position)),
+                    );
+                }
+            }
+            canvas::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.dragging.take().is_some() =>
+            {
+                return (
+                    event::Status::Captured,
+                    Some(Message::ParameterEditFinished),
+                );
+            }
+            _ => {}
+        }
+
+        (event::Status::Ignored, None)
+    }
+
+    fn draw(
+        &self,
+        state: &State,
+        _theme: &Theme,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(bounds.size());
+        let layout = self.layout(bounds.size());
+        let points = &layout.points;
+
+        // Level range added by aftertouch during the sustain stage
+        let sustain_level = self.sustain as f32 / MAX_VALUE;
+        let after_level = self.after as f32 / MAX_VALUE;
+        if self.after > 0 {
+            let after_y = layout.level_y(sustain_level + after_level);
+            frame.fill_rectangle(
+                Point::new(points[3].x, after_y),
+                Size::new(points[4].x - points[3].x, points[3].y - after_y),
+                Color {
+                    a: 0.3,
+                    ..style::graph_mod_color()
+                },
+            );
+        }
+
+        // Envelope at the lowest velocity
+        if self.velo > 0 {
+            frame.stroke(
+                &layout.path(1.0 - self.velo as f32 / MAX_VALUE),
+                Stroke {
+                    line_dash: LineDash {
+                        segments: &[3.0, 3.0],
+                        offset: 0,
+                    },
+                    ..Stroke::default()
+                        .with_color(style::graph_mod_color())
+                        .with_width(1.0)
+                },
+            );
+        }
+
+        frame.stroke(
+            &layout.path(1.0),
+            Stroke::default()
+                .with_color(style::graph_line_color())
+                .with_width(2.0),
+        );
+
+        let hovered = cursor
+            .position_in(&bounds)
+            .and_then(|position| self.handle_at(&layout, position));
+
+        for handle in Handle::ALL {
+            let radius = if state.dragging == Some(handle) || hovered == Some(handle) {
+                HANDLE_RADIUS * 1.5
+            } else {
+                HANDLE_RADIUS
+            };
+            frame.fill(
+                &Path::circle(self.handle_position(&layout, handle), radius),
+                style::graph_line_color(),
+            );
+        }
+
+        frame.fill_text(canvas::Text {
+            content: self.label.to_string(),
+            position: Point::ORIGIN,
+            color: style::graph_line_color(),
+            size: style::section_label_text_size() as f32,
+            ..canvas::Text::default()
+        });
+
+        if let Some(trigger) = EnvTrigger::from_value(self.trigger) {
+            frame.fill_text(canvas::Text {
+                content: format!("Trigger {}", trigger),
+                position: Point::new(bounds.width - MARGIN, 0.0),
+                color: style::graph_line_color(),
+                size: style::list_item_text_size() as f32,
+                horizontal_alignment: alignment::Horizontal::Right,
+                ..canvas::Text::default()
+            });
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        if state.dragging.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let layout = self.layout(bounds.size());
+        match cursor.position_in(&bounds) {
+            Some(position) if self.handle_at(&layout, position).is_some() => {
+                mouse::Interaction::Grab
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+}
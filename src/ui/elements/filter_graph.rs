@@ -0,0 +1,250 @@
+//! Approximate magnitude response of the filter and the shaper
+//!
+//! The curves are computed from a resonant two-pole model on a logarithmic
+//! frequency axis, intended to judge the settings at a glance rather than to
+//! match the device exactly.
+
+use iced::alignment;
+use iced::widget::canvas::{self, Canvas, Cursor, Frame, Geometry, LineDash, Path, Stroke};
+use iced::{Color, Element, Length, Point, Rectangle, Theme};
+
+use super::shaper_mode_list::ShaperMode;
+use crate::messages::Message;
+use crate::params::{GetValue, SoundParameter, SoundParameterValues};
+use crate::style;
+
+/// Maximum value of cutoff and resonance
+const MAX_VALUE: f32 = 255.0;
+
+/// Maximum absolute modulation amount
+const MAX_MOD_AMOUNT: f32 = 128.0;
+
+/// Number of octaves covered by the frequency axis
+const OCTAVES: f32 = 10.0;
+
+/// Lowest level shown in dB
+const MIN_DB: f32 = -36.0;
+
+/// Highest level shown in dB
+const MAX_DB: f32 = 18.0;
+
+/// Quality factor at resonance zero
+const MIN_Q: f32 = 0.5;
+
+/// Quality factor at the maximum resonance
+const MAX_Q: f32 = 10.0;
+
+/// Number of line segments of the curve
+const SEGMENTS: usize = 100;
+
+/// Distance of the graph from the canvas border
+const MARGIN: f32 = 4.0;
+
+/// Space above the graph for the labels
+const LABEL_HEIGHT: f32 = 16.0;
+
+/// Parameters of a filter stage
+pub struct FilterParams {
+    pub cutoff: SoundParameter,
+    pub resonance: SoundParameter,
+
+    /// Envelope modulation amount
+    pub env_amount: SoundParameter,
+
+    /// LFO modulation amount
+    pub lfo_amount: SoundParameter,
+
+    /// Mode selection, `None` for a fixed low-pass
+    pub mode: Option<SoundParameter>,
+
+    /// Number of cascaded two-pole stages
+    pub poles: u8,
+}
+
+/// Parameters of the filter
+pub const FILTER: FilterParams = FilterParams {
+    cutoff: SoundParameter::FilterCutoff,
+    resonance: SoundParameter::FilterResonance,
+    env_amount: SoundParameter::FilterEnvFAmount,
+    lfo_amount: SoundParameter::FilterLFO1Amount,
+    mode: None,
+    poles: 4,
+};
+
+/// Parameters of the shaper
+pub const SHAPER: FilterParams = FilterParams {
+    cutoff: SoundParameter::ShaperCutoff,
+    resonance: SoundParameter::ShaperResonance,
+    env_amount: SoundParameter::ShaperEnvAAmount,
+    lfo_amount: SoundParameter::ShaperLFO2Amount,
+    mode: Some(SoundParameter::ShaperMode),
+    poles: 2,
+};
+
+/// Returns the response plot
+///
+/// - `label`       Name of the stage drawn in the top left corner
+/// - `filter`      Parameters of the stage
+/// - `params`      Current sound parameters
+pub fn filter_graph<'a>(
+    label: &'static str,
+    filter: &FilterParams,
+    params: &SoundParameterValues,
+) -> Element<'a, Message> {
+    let mode = match filter.mode {
+        Some(param) => ShaperMode::from_value(params.get_value(param)),
+        None => Some(ShaperMode::Lowpass),
+    };
+
+    Canvas::new(FilterGraph {
+        label,
+        mode: mode.unwrap_or(ShaperMode::Lowpass),
+        poles: filter.poles,
+        cutoff: params.get_value(filter.cutoff),
+        resonance: params.get_value(filter.resonance),
+        env_amount: params.get_value(filter.env_amount),
+        lfo_amount: params.get_value(filter.lfo_amount),
+    })
+    .width(Length::Fill)
+    .height(style::GRAPH_HEIGHT)
+    .into()
+}
+
+/// Filter values to be drawn
+struct FilterGraph {
+    label: &'static str,
+    mode: ShaperMode,
+    poles: u8,
+    cutoff: i32,
+    resonance: i32,
+    env_amount: i32,
+    lfo_amount: i32,
+}
+
+impl FilterGraph {
+    /// Return the level in dB at a frequency position 0.0-1.0 for a cutoff 0.0-1.0
+    fn level(&self, position: f32, cutoff: f32) -> f32 {
+        let q = MIN_Q + (MAX_Q - MIN_Q) * self.resonance as f32 / MAX_VALUE;
+        let ratio = 2_f32.powf((position - cutoff) * OCTAVES);
+        let denominator = ((1.0 - ratio * ratio).powi(2) + (ratio / q).powi(2)).sqrt();
+
+        let magnitude = match self.mode {
+            ShaperMode::Lowpass => 1.0 / denominator,
+            ShaperMode::Bandpass => ratio / q / denominator,
+            ShaperMode::Highpass => ratio * ratio / denominator,
+        };
+
+        20.0 * magnitude.max(f32::MIN_POSITIVE).log10() * (self.poles / 2) as f32
+    }
+
+    /// Return the lowest and highest cutoff 0.0-1.0 reached by the modulations
+    fn cutoff_range(&self) -> (f32, f32) {
+        let cutoff = self.cutoff as f32 / MAX_VALUE;
+        let env = self.env_amount as f32 / MAX_MOD_AMOUNT;
+        let lfo = (self.lfo_amount as f32 / MAX_MOD_AMOUNT).abs();
+
+        (
+            (cutoff + env.min(0.0) - lfo).clamp(0.0, 1.0),
+            (cutoff + env.max(0.0) + lfo).clamp(0.0, 1.0),
+        )
+    }
+}
+
+impl canvas::Program<Message> for FilterGraph {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &(),
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(bounds.size());
+
+        let left = MARGIN;
+        let width = bounds.width - 2.0 * MARGIN;
+        let top = LABEL_HEIGHT;
+        let bottom = bounds.height - MARGIN;
+        let level_y = |level: f32| {
+            bottom - (bottom - top) * (level.clamp(MIN_DB, MAX_DB) - MIN_DB) / (MAX_DB - MIN_DB)
+        };
+        let point = |segment: usize, cutoff: f32| {
+            let position = segment as f32 / SEGMENTS as f32;
+            Point::new(
+                left + width * position,
+                level_y(self.level(position, cutoff)),
+            )
+        };
+
+        // Unity gain reference
+        frame.stroke(
+            &Path::line(
+                Point::new(left, level_y(0.0)),
+                Point::new(left + width, level_y(0.0)),
+            ),
+            Stroke {
+                line_dash: LineDash {
+                    segments: &[2.0, 4.0],
+                    offset: 0,
+                },
+                ..Stroke::default()
+                    .with_color(style::graph_mod_color())
+                    .with_width(1.0)
+            },
+        );
+
+        // Band between the curves at the lowest and highest modulated cutoff
+        let (low, high) = self.cutoff_range();
+        if high > low {
+            frame.fill(
+                &Path::new(|builder| {
+                    builder.move_to(point(0, high));
+                    for segment in 1..=SEGMENTS {
+                        builder.line_to(point(segment, high));
+                    }
+                    for segment in (0..=SEGMENTS).rev() {
+                        builder.line_to(point(segment, low));
+                    }
+                    builder.close();
+                }),
+                Color {
+                    a: 0.3,
+                    ..style::graph_mod_color()
+                },
+            );
+        }
+
+        let cutoff = self.cutoff as f32 / MAX_VALUE;
+        frame.stroke(
+            &Path::new(|builder| {
+                builder.move_to(point(0, cutoff));
+                for segment in 1..=SEGMENTS {
+                    builder.line_to(point(segment, cutoff));
+                }
+            }),
+            Stroke::default()
+                .with_color(style::graph_line_color())
+                .with_width(2.0),
+        );
+
+        frame.fill_text(canvas::Text {
+            content: self.label.to_string(),
+            position: Point::ORIGIN,
+            color: style::graph_line_color(),
+            size: style::section_label_text_size() as f32,
+            ..canvas::Text::default()
+        });
+
+        frame.fill_text(canvas::Text {
+            content: format!("{}, {} pole", self.mode, self.poles),
+            position: Point::new(bounds.width - MARGIN, 0.0),
+            color: style::graph_line_color(),
+            size: style::list_item_text_size() as f32,
+            horizontal_alignment: alignment::Horizontal::Right,
+            ..canvas::Text::default()
+        });
+
+        vec![frame.into_geometry()]
+    }
+}
@@ -0,0 +1,425 @@
+//! Rotary knob and vertical fader selecting a single value from a range
+//!
+//! Both behave like the [`Slider`](super::slider_widget::Slider):
+//!     - Pointer shape mouse cursor when hovering the control.
+//!     - Mouse wheel support.
+//!     - Control-click/right-click resets the control to a default value.
+//!     - Shift-drag enables fine control.
+//!     - Overlay of modulation ranges.
+//!
+//! Knobs are dragged vertically from the click position, faders jump to the
+//! click position like the slider does.
+
+use std::f32::consts::PI;
+use std::ops::RangeInclusive;
+
+use iced::keyboard;
+use iced::mouse::ScrollDelta;
+use iced::widget::canvas::{self, event, path::Arc, Canvas, Cursor, Frame, Geometry, Path, Stroke};
+use iced::{mouse, Color, Element, Point, Rectangle, Size, Theme};
+
+use super::slider_widget::ModulationRange;
+use crate::messages::Message;
+use crate::style;
+
+/// Angle of the minimum value, clockwise from the positive x axis
+const START_ANGLE: f32 = 0.75 * PI;
+
+/// Angle of the maximum value
+const END_ANGLE: f32 = 2.25 * PI;
+
+/// Vertical mouse distance for dragging a knob over the full range
+const KNOB_DRAG_DISTANCE: f32 = 150.0;
+
+/// Factor reducing the drag speed while shift is pressed
+const FINE_FACTOR: f32 = 4.0;
+
+/// Width of the fader handle
+const FADER_HANDLE_WIDTH: f32 = 16.0;
+
+/// Height of the fader handle
+const FADER_HANDLE_HEIGHT: f32 = 6.0;
+
+/// Appearance of the control
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Shape {
+    Rotary,
+    Fader,
+}
+
+/// Returns a rotary knob
+///
+/// - `range`       Range of values
+/// - `value`       Current value
+/// - `default`     Value set by control-click/right-click
+/// - `on_change`   Produces the message for a new value
+pub fn knob<'a>(
+    range: RangeInclusive<i32>,
+    value: i32,
+    default: i32,
+    on_change: impl Fn(i32) -> Message + 'a,
+) -> Knob<'a> {
+    Knob::new(Shape::Rotary, range, value, default, on_change)
+}
+
+/// Returns a vertical fader
+///
+/// - `range`       Range of values
+/// - `value`       Current value
+/// - `default`     Value set by control-click/right-click
+/// - `on_change`   Produces the message for a new value
+pub fn fader<'a>(
+    range: RangeInclusive<i32>,
+    value: i32,
+    default: i32,
+    on_change: impl Fn(i32) -> Message + 'a,
+) -> Knob<'a> {
+    Knob::new(Shape::Fader, range, value, default, on_change)
+}
+
+/// A knob or fader
+pub struct Knob<'a> {
+    shape: Shape,
+    range: RangeInclusive<i32>,
+    value: i32,
+    default: i32,
+    on_change: Box<dyn Fn(i32) -> Message + 'a>,
+    on_release: Option<Message>,
+    modulation: Vec<ModulationRange>,
+}
+
+/// Drag in progress
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    /// Window y position where the drag started or the shift key changed
+    origin: f32,
+
+    /// Value at the origin
+    value: i32,
+}
+
+/// Interaction state of the control
+#[derive(Default)]
+pub struct State {
+    drag: Option<Drag>,
+    modifiers: keyboard::Modifiers,
+}
+
+impl<'a> Knob<'a> {
+    fn new(
+        shape: Shape,
+        range: RangeInclusive<i32>,
+        value: i32,
+        default: i32,
+        on_change: impl Fn(i32) -> Message + 'a,
+    ) -> Self {
+        Self {
+            shape,
+            value: value.clamp(*range.start(), *range.end()),
+            range,
+            default,
+            on_change: Box::new(on_change),
+            on_release: None,
+            modulation: Vec::new(),
+        }
+    }
+
+    /// Sets the message produced when the mouse is released after dragging
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the modulation ranges drawn over the control
+    pub fn modulation(mut self, modulation: Vec<ModulationRange>) -> Self {
+        self.modulation = modulation;
+        self
+    }
+
+    /// Returns the control as canvas of a fixed size
+    pub fn view(self, width: u16, height: u16) -> Element<'a, Message> {
+        Canvas::new(self).width(width).height(height).into()
+    }
+
+    /// Return the span of the range
+    fn span(&self) -> f32 {
+        (self.range.end() - self.range.start()) as f32
+    }
+
+    /// Return the position 0.0-1.0 of a value within the range
+    fn position(&self, value: f32) -> f32 {
+        if self.span() <= 0.0 {
+            0.0
+        } else {
+            ((value - *self.range.start() as f32) / self.span()).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Return the value clamped to the range
+    fn clamp(&self, value: f32) -> i32 {
+        (value.round() as i32).clamp(*self.range.start(), *self.range.end())
+    }
+
+    /// Return the message for a new value, `None` if the value is unchanged
+    fn change(&self, value: i32) -> Option<Message> {
+        if value == self.value {
+            None
+        } else {
+            Some((self.on_change)(value))
+        }
+    }
+
+    /// Return the messages of an edit completed by a single action, e.g. a wheel step
+    fn step(&self, value: i32) -> Option<Message> {
+        let change = self.change(value)?;
+
+        Some(match &self.on_release {
+            Some(on_release) => Message::Sequence(vec![change, on_release.clone()]),
+            None => change,
+        })
+    }
+
+    /// Return the value at a position of the fader track
+    fn fader_value(&self, bounds: Rectangle, y: f32) -> i32 {
+        let track = fader_track(bounds.size());
+        let position = (track.1 - y) / (track.1 - track.0);
+        self.clamp(*self.range.start() as f32 + position.clamp(0.0, 1.0) * self.span())
+    }
+
+    /// Return the value position used as start of the value indication
+    fn origin_position(&self) -> f32 {
+        if *self.range.start() < 0 && *self.range.end() > 0 {
+            self.position(0.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Return the top and bottom y positions of the fader track
+fn fader_track(size: Size) -> (f32, f32) {
+    (
+        FADER_HANDLE_HEIGHT / 2.0,
+        size.height - FADER_HANDLE_HEIGHT / 2.0,
+    )
+}
+
+/// Return the knob angle for a position 0.0-1.0
+fn angle(position: f32) -> f32 {
+    START_ANGLE + (END_ANGLE - START_ANGLE) * position
+}
+
+/// Return an arc between two positions 0.0-1.0
+fn arc(center: Point, radius: f32, from: f32, to: f32) -> Path {
+    Path::new(|builder| {
+        builder.arc(Arc {
+            center,
+            radius,
+            start_angle: angle(from.min(to)),
+            end_angle: angle(from.max(to)),
+        })
+    })
+}
+
+impl<'a> canvas::Program<Message> for Knob<'a> {
+    type State = State;
+
+    fn update(
+        &self,
+        state: &mut State,
+        event: canvas::Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (event::Status, Option<Message>) {
+        match event {
+            canvas::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                // Restart the drag at the current position, so that the value doesn't jump
+                if modifiers.shift() != state.modifiers.shift() {
+                    if let (Some(drag), Some(position)) = (state.drag.as_mut(), cursor.position()) {
+                        drag.origin = position.y;
+                        drag.value = self.value;
+                    }
+                }
+                state.modifiers = modifiers;
+            }
+            canvas::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_in(&bounds) {
+                    if state.modifiers.control() {
+                        return (event::Status::Captured, self.step(self.default));
+                    }
+
+                    let value = match self.shape {
+                        Shape::Rotary => self.value,
+                        Shape::Fader => self.fader_value(bounds, position.y),
+                    };
+                    state.drag = Some(Drag {
+                        origin: bounds.y + position.y,
+                        value,
+                    });
+                    return (event::Status::Captured, self.change(value));
+                }
+            }
+            canvas::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+                if cursor.is_over(&bounds) =>
+            {
+                return (event::Status::Captured, self.step(self.default));
+            }
+            canvas::Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let (Some(drag), Some(position)) = (state.drag, cursor.position()) {
+                    let distance = match self.shape {
+                        Shape::Rotary => KNOB_DRAG_DISTANCE,
+                        Shape::Fader => {
+                            let track = fader_track(bounds.size());
+                            track.1 - track.0
+                        }
+                    };
+                    let mut delta = (drag.origin - position.y) / distance * self.span();
+                    if state.modifiers.shift() {
+                        delta /= FINE_FACTOR;
+                    }
+                    return (
+                        event::Status::Captured,
+                        self.change(self.clamp(drag.value as f32 + delta)),
+                    );
+                }
+            }
+            canvas::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.drag.take().is_some() =>
+            {
+                return (event::Status::Captured, self.on_release.clone());
+            }
+            canvas::Event::Mouse(mouse::Event::WheelScrolled {
+                delta: ScrollDelta::Lines { y, .. },
+            }) if cursor.is_over(&bounds) => {
+                let steps = if y.is_sign_positive() {
+                    y.ceil()
+                } else {
+                    y.floor()
+                };
+                return (
+                    event::Status::Captured,
+                    self.step(self.clamp(self.value as f32 + steps)),
+                );
+            }
+            _ => {}
+        }
+
+        (event::Status::Ignored, None)
+    }
+
+    fn draw(
+        &self,
+        state: &State,
+        _theme: &Theme,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(bounds.size());
+        let color = if state.drag.is_some() || cursor.is_over(&bounds) {
+            style::control_hovered_color()
+        } else {
+            style::control_color()
+        };
+        let track_color = Color {
+            a: 0.1,
+            ..style::control_color()
+        };
+        let origin = self.origin_position();
+        let position = self.position(self.value as f32);
+        let modulation_position =
+            |range: &ModulationRange| self.position(self.value as f32 + range.depth);
+
+        match self.shape {
+            Shape::Rotary => {
+                let center = frame.center();
+                let radius = bounds.width.min(bounds.height) / 2.0 - 2.0;
+
+                frame.stroke(
+                    &arc(center, radius, 0.0, 1.0),
+                    Stroke::default().with_color(track_color).with_width(3.0),
+                );
+                frame.stroke(
+                    &arc(center, radius, origin, position),
+                    Stroke::default().with_color(color).with_width(3.0),
+                );
+
+                // One thin arc per modulation source inside the value arc
+                for (index, range) in self.modulation.iter().enumerate() {
+                    frame.stroke(
+                        &arc(
+                            center,
+                            radius - 4.0 - 3.0 * index as f32,
+                            position,
+                            modulation_position(range),
+                        ),
+                        Stroke::default().with_color(range.color).with_width(2.0),
+                    );
+                }
+
+                let pointer = angle(position);
+                frame.stroke(
+                    &Path::line(
+                        center,
+                        Point::new(
+                            center.x + radius * pointer.cos(),
+                            center.y + radius * pointer.sin(),
+                        ),
+                    ),
+                    Stroke::default().with_color(color).with_width(2.0),
+                );
+            }
+            Shape::Fader => {
+                let (top, bottom) = fader_track(bounds.size());
+                let x = bounds.width / 2.0;
+                let y = |position: f32| bottom - (bottom - top) * position;
+
+                frame.fill_rectangle(
+                    Point::new(x - 1.0, top),
+                    Size::new(2.0, bottom - top),
+                    track_color,
+                );
+                frame.fill_rectangle(
+                    Point::new(x - 1.0, y(origin.max(position))),
+                    Size::new(2.0, (y(origin) - y(position)).abs()),
+                    color,
+                );
+
+                // One thin bar per modulation source right of the track
+                for (index, range) in self.modulation.iter().enumerate() {
+                    let to = modulation_position(range);
+                    frame.fill_rectangle(
+                        Point::new(x + 4.0 + 3.0 * index as f32, y(position.max(to))),
+                        Size::new(2.0, (y(position) - y(to)).abs().max(1.0)),
+                        range.color,
+                    );
+                }
+
+                frame.fill_rectangle(
+                    Point::new(
+                        x - FADER_HANDLE_WIDTH / 2.0,
+                        y(position) - FADER_HANDLE_HEIGHT / 2.0,
+                    ),
+                    Size::new(FADER_HANDLE_WIDTH, FADER_HANDLE_HEIGHT),
+                    color,
+                );
+            }
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        if state.drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(&bounds) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
@@ -0,0 +1,273 @@
+//! Animated preview of an LFO waveform
+//!
+//! The waveforms and rates are approximations of the device behaviour,
+//! intended to judge the modulation at a glance.
+
+use std::f32::consts::PI;
+use std::time::{Duration, Instant};
+
+use iced::alignment;
+use iced::widget::canvas::{self, Canvas, Cursor, Frame, Geometry, LineDash, Path, Stroke};
+use iced::{Element, Length, Point, Rectangle, Theme};
+
+use super::animation::animation;
+use super::lfo_phase_list::LFOPhase;
+use super::lfo_shape_list::LFOShape;
+use crate::messages::Message;
+use crate::params::{GetValue, SoundParameter, SoundParameterValues};
+use crate::style;
+
+/// Maximum value of speed and rise
+const MAX_VALUE: f32 = 255.0;
+
+/// Approximate rate at the minimum speed in Hz
+const MIN_RATE: f32 = 0.05;
+
+/// Approximate rate at the maximum speed in Hz
+const MAX_RATE: f32 = 50.0;
+
+/// Number of cycles shown in the preview
+const CYCLES: f32 = 2.0;
+
+/// Number of line segments of the waveform
+const SEGMENTS: usize = 120;
+
+/// Distance of the graph from the canvas border
+const MARGIN: f32 = 4.0;
+
+/// Space above the graph for the labels
+const LABEL_HEIGHT: f32 = 16.0;
+
+/// Radius of the marker of the current position
+const MARKER_RADIUS: f32 = 3.0;
+
+/// Interval of the animation frames
+const FRAME_INTERVAL: Duration = Duration::from_millis(40);
+
+/// Parameters of an LFO
+pub struct LFOParams {
+    pub shape: SoundParameter,
+    pub speed: SoundParameter,
+    pub rise: SoundParameter,
+    pub phase: SoundParameter,
+}
+
+/// Parameters of LFO 1
+pub const LFO_1: LFOParams = LFOParams {
+    shape: SoundParameter::LFO1Shape,
+    speed: SoundParameter::LFO1Speed,
+    rise: SoundParameter::LFO1Rise,
+    phase: SoundParameter::LFO1Phase,
+};
+
+/// Parameters of LFO 2
+pub const LFO_2: LFOParams = LFOParams {
+    shape: SoundParameter::LFO2Shape,
+    speed: SoundParameter::LFO2Speed,
+    rise: SoundParameter::LFO2Rise,
+    phase: SoundParameter::LFO2Phase,
+};
+
+/// Returns the LFO preview
+///
+/// - `label`       Name of the LFO drawn in the top left corner
+/// - `lfo`         Parameters of the LFO
+/// - `params`      Current sound parameters
+/// - `start`       Start time of the animation
+pub fn lfo_graph<'a>(
+    label: &'static str,
+    lfo: &LFOParams,
+    params: &SoundParameterValues,
+    start: Instant,
+) -> Element<'a, Message> {
+    let canvas = Canvas::new(LFOGraph {
+        label,
+        shape: LFOShape::from_value(params.get_value(lfo.shape)),
+        speed: params.get_value(lfo.speed),
+        rise: params.get_value(lfo.rise),
+        phase: LFOPhase::from_value(params.get_value(lfo.phase)),
+        start,
+    })
+    .width(Length::Fill)
+    .height(style::GRAPH_HEIGHT);
+
+    animation(canvas, FRAME_INTERVAL).into()
+}
+
+/// Return the approximate rate in Hz for a speed value
+fn rate(speed: i32) -> f32 {
+    MIN_RATE * (MAX_RATE / MIN_RATE).powf(speed as f32 / MAX_VALUE)
+}
+
+/// LFO values to be drawn
+struct LFOGraph {
+    label: &'static str,
+    shape: Option<LFOShape>,
+    speed: i32,
+    rise: i32,
+    phase: Option<LFOPhase>,
+    start: Instant,
+}
+
+impl LFOGraph {
+    /// Return the start phase 0.0-1.0, `None` for free running and random phases
+    fn start_phase(&self) -> Option<f32> {
+        match self.phase? {
+            LFOPhase::Phase0 => Some(0.0),
+            LFOPhase::Phase90 => Some(0.25),
+            LFOPhase::Phase180 => Some(0.5),
+            LFOPhase::Phase270 => Some(0.75),
+            LFOPhase::Free | LFOPhase::Random => None,
+        }
+    }
+
+    /// Return the waveform value -1.0 to 1.0 at a position given in cycles
+    fn waveform(&self, position: f32) -> f32 {
+        let cycle = position.floor();
+        let phase = position - cycle;
+
+        let shape = match self.shape {
+            Some(shape) => shape,
+            None => return 0.0,
+        };
+
+        match shape {
+            LFOShape::Triangle => {
+                if phase < 0.25 {
+                    4.0 * phase
+                } else if phase < 0.75 {
+                    2.0 - 4.0 * phase
+                } else {
+                    4.0 * phase - 4.0
+                }
+            }
+            LFOShape::RampUp => 2.0 * phase - 1.0,
+            LFOShape::RampDown => 1.0 - 2.0 * phase,
+            LFOShape::Square => {
+                if phase < 0.5 {
+                    1.0
+                } else {
+                    -1.0
+                }
+            }
+            LFOShape::MWave => 2.0 * (2.0 * PI * phase).sin().abs() - 1.0,
+            // A new random level per cycle
+            LFOShape::Random => random_level(cycle as i32),
+            // Random levels with smooth transitions
+            LFOShape::Slew => {
+                let from = random_level(cycle as i32);
+                let to = random_level(cycle as i32 + 1);
+                let smooth = phase * phase * (3.0 - 2.0 * phase);
+                from + (to - from) * smooth
+            }
+            // Sine with a slower amplitude modulation
+            LFOShape::AM => (2.0 * PI * phase).sin() * (0.6 + 0.4 * (PI * position / CYCLES).cos()),
+        }
+    }
+
+    /// Return the amplitude 0.0-1.0 of the rise fade-in at a position given in cycles
+    fn rise_amount(&self, position: f32) -> f32 {
+        let rise_cycles = CYCLES * self.rise as f32 / MAX_VALUE;
+        if rise_cycles <= 0.0 {
+            1.0
+        } else {
+            (position / rise_cycles).min(1.0)
+        }
+    }
+
+    /// Return the modulation value at a position given in cycles since the key was pressed
+    fn value(&self, position: f32) -> f32 {
+        let offset = self.start_phase().unwrap_or(0.0);
+        self.waveform(position + offset) * self.rise_amount(position)
+    }
+}
+
+/// Return a pseudo random level -1.0 to 1.0 for a cycle
+fn random_level(cycle: i32) -> f32 {
+    let mut x = (cycle as u32).wrapping_mul(0x9E37_79B9) ^ 0x85EB_CA6B;
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x2C1B_3C6D);
+    x ^= x >> 12;
+    (x % 2001) as f32 / 1000.0 - 1.0
+}
+
+impl canvas::Program<Message> for LFOGraph {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &(),
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(bounds.size());
+
+        let left = MARGIN;
+        let width = bounds.width - 2.0 * MARGIN;
+        let center = (LABEL_HEIGHT + bounds.height - MARGIN) / 2.0;
+        let amplitude = (bounds.height - MARGIN - LABEL_HEIGHT) / 2.0;
+        let point = |position: f32| {
+            Point::new(
+                left + width * position / CYCLES,
+                center - amplitude * self.value(position),
+            )
+        };
+
+        frame.stroke(
+            &Path::line(Point::new(left, center), Point::new(left + width, center)),
+            Stroke {
+                line_dash: LineDash {
+                    segments: &[2.0, 4.0],
+                    offset: 0,
+                },
+                ..Stroke::default()
+                    .with_color(style::graph_mod_color())
+                    .with_width(1.0)
+            },
+        );
+
+        frame.stroke(
+            &Path::new(|builder| {
+                builder.move_to(point(0.0));
+                for segment in 1..=SEGMENTS {
+                    builder.line_to(point(CYCLES * segment as f32 / SEGMENTS as f32));
+                }
+            }),
+            Stroke::default()
+                .with_color(style::graph_line_color())
+                .with_width(2.0),
+        );
+
+        // Current position, restarting after the shown cycles like a repeated key press
+        let time = self.start.elapsed().as_secs_f32();
+        let position = (time * rate(self.speed)) % CYCLES;
+        frame.fill(
+            &Path::circle(point(position), MARKER_RADIUS),
+            style::graph_mod_color(),
+        );
+
+        frame.fill_text(canvas::Text {
+            content: self.label.to_string(),
+            position: Point::ORIGIN,
+            color: style::graph_line_color(),
+            size: style::section_label_text_size() as f32,
+            ..canvas::Text::default()
+        });
+
+        let rate = rate(self.speed);
+        frame.fill_text(canvas::Text {
+            content: match self.start_phase() {
+                Some(_) => format!("~{:.2} Hz", rate),
+                None => format!("~{:.2} Hz, free phase", rate),
+            },
+            position: Point::new(bounds.width - MARGIN, 0.0),
+            color: style::graph_line_color(),
+            size: style::list_item_text_size() as f32,
+            horizontal_alignment: alignment::Horizontal::Right,
+            ..canvas::Text::default()
+        });
+
+        vec![frame.into_geometry()]
+    }
+}
@@ -2,40 +2,33 @@
 
 use iced::widget::{Column, Container, PickList, Row, Text};
 
+use super::param_tooltip::sound_tooltip;
 use crate::messages::Message;
 use crate::params::SoundParameter;
 use crate::style;
 
 pub fn lfo_phase_list(label: &str, sound_param: SoundParameter, value: i32) -> Container<Message> {
-    let value = match value {
-        0 => Some(LFOPhase::Free),
-        1 => Some(LFOPhase::Random),
-        2 => Some(LFOPhase::Phase0),
-        3 => Some(LFOPhase::Phase90),
-        4 => Some(LFOPhase::Phase180),
-        5 => Some(LFOPhase::Phase270),
-
-        _ => None,
-    };
-    let pick_list = PickList::new(&LFOPhase::ALL[..], value, move |v| {
+    let pick_list = PickList::new(&LFOPhase::ALL[..], LFOPhase::from_value(value), move |v| {
         Message::SoundParameterChange(sound_param, v as i32)
     })
     .style(style::PickList)
-    .text_size(style::LIST_ITEM_TEXT_SIZE);
+    .text_size(style::list_item_text_size());
 
-    Container::new(
+    Container::new(sound_tooltip(
         Row::new()
             .push(
                 Column::new()
                     .push(
                         Text::new(label)
-                            .size(style::PARAM_LABEL_TEXT_SIZE)
+                            .size(style::param_label_text_size())
                             .width(style::PARAM_LABEL_WIDTH),
                     )
                     .padding([4, 0, 0, 0]),
             )
             .push(pick_list),
-    )
+        sound_param,
+        &[],
+    ))
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -57,6 +50,19 @@ impl LFOPhase {
         LFOPhase::Phase180,
         LFOPhase::Phase270,
     ];
+
+    /// Return the phase for a parameter value
+    pub fn from_value(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(LFOPhase::Free),
+            1 => Some(LFOPhase::Random),
+            2 => Some(LFOPhase::Phase0),
+            3 => Some(LFOPhase::Phase90),
+            4 => Some(LFOPhase::Phase180),
+            5 => Some(LFOPhase::Phase270),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for LFOPhase {
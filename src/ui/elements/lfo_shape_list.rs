@@ -2,41 +2,33 @@
 
 use iced::widget::{Column, Container, PickList, Row, Text};
 
+use super::param_tooltip::sound_tooltip;
 use crate::messages::Message;
 use crate::params::SoundParameter;
 use crate::style;
 
 pub fn lfo_shape_list(label: &str, sound_param: SoundParameter, value: i32) -> Container<Message> {
-    let value = match value {
-        0 => Some(LFOShape::Triangle),
-        1 => Some(LFOShape::RampUp),
-        2 => Some(LFOShape::RampDown),
-        3 => Some(LFOShape::Square),
-        4 => Some(LFOShape::MWave),
-        5 => Some(LFOShape::Random),
-        6 => Some(LFOShape::Slew),
-        7 => Some(LFOShape::AM),
-        _ => None,
-    };
-    let pick_list = PickList::new(&LFOShape::ALL[..], value, move |v| {
+    let pick_list = PickList::new(&LFOShape::ALL[..], LFOShape::from_value(value), move |v| {
         Message::SoundParameterChange(sound_param, v as i32)
     })
     .style(style::PickList)
-    .text_size(style::LIST_ITEM_TEXT_SIZE);
+    .text_size(style::list_item_text_size());
 
-    Container::new(
+    Container::new(sound_tooltip(
         Row::new()
             .push(
                 Column::new()
                     .push(
                         Text::new(label)
-                            .size(style::PARAM_LABEL_TEXT_SIZE)
+                            .size(style::param_label_text_size())
                             .width(style::PARAM_LABEL_WIDTH),
                     )
                     .padding([4, 0, 0, 0]),
             )
             .push(pick_list),
-    )
+        sound_param,
+        &[],
+    ))
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -62,6 +54,21 @@ impl LFOShape {
         LFOShape::Slew,
         LFOShape::AM,
     ];
+
+    /// Return the shape for a parameter value
+    pub fn from_value(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(LFOShape::Triangle),
+            1 => Some(LFOShape::RampUp),
+            2 => Some(LFOShape::RampDown),
+            3 => Some(LFOShape::Square),
+            4 => Some(LFOShape::MWave),
+            5 => Some(LFOShape::Random),
+            6 => Some(LFOShape::Slew),
+            7 => Some(LFOShape::AM),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for LFOShape {
@@ -0,0 +1,66 @@
+//! Dropdown menu for the mode of linked part editing
+
+use iced::widget::{Container, PickList};
+
+use crate::messages::Message;
+use crate::params::PART_COUNT;
+use crate::style;
+
+pub fn link_mode_list<'a>(value: LinkMode) -> Container<'a, Message> {
+    let pick_list = PickList::new(&LinkMode::ALL[..], Some(value), Message::LinkModeChange)
+        .style(style::PickList)
+        .text_size(style::list_item_text_size());
+
+    Container::new(pick_list)
+}
+
+/// Settings for the linked editing of parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartLink {
+    /// Flag for edits applied to all linked parts
+    pub enabled: bool,
+
+    /// Flags for the parts included in linked editing
+    pub parts: [bool; PART_COUNT],
+
+    /// Way an edit is applied to the linked parts
+    pub mode: LinkMode,
+}
+
+impl Default for PartLink {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            parts: [true; PART_COUNT],
+            mode: LinkMode::default(),
+        }
+    }
+}
+
+/// Way an edit is applied to the linked parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LinkMode {
+    /// All linked parts get the same value
+    #[default]
+    Absolute,
+
+    /// All linked parts are changed by the same offset
+    Relative,
+}
+
+impl LinkMode {
+    const ALL: [LinkMode; 2] = [LinkMode::Absolute, LinkMode::Relative];
+}
+
+impl std::fmt::Display for LinkMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                LinkMode::Absolute => "Absolute",
+                LinkMode::Relative => "Relative",
+            }
+        )
+    }
+}
@@ -0,0 +1,57 @@
+//! Grid of checkboxes locking the sound parameters, grouped by section
+
+use std::collections::HashSet;
+
+use iced::widget::{Checkbox, Column, Row, Text};
+use iced::Length;
+
+use crate::messages::Message;
+use crate::params::{SoundParameter, SoundSection};
+use crate::style;
+
+/// Number of lock checkboxes per row
+const LOCKS_PER_ROW: usize = 4;
+
+/// Returns the lock checkboxes of all sections
+///
+/// - `locks`           Locked parameters
+/// - `on_toggle`       Message locking or unlocking a parameter
+/// - `section_label`   Label of a section
+pub fn lock_grid<'a>(
+    locks: &HashSet<SoundParameter>,
+    on_toggle: fn(SoundParameter, bool) -> Message,
+    section_label: impl Fn(SoundSection) -> String,
+) -> Column<'a, Message> {
+    let mut content = Column::new().padding(style::SECTION_PADDING).spacing(8);
+
+    for section in SoundSection::ALL {
+        let mut section_content = Column::new()
+            .spacing(4)
+            .push(Text::new(section_label(section)).size(style::section_label_text_size()));
+
+        let params: Vec<_> = section.params().collect();
+        for chunk in params.chunks(LOCKS_PER_ROW) {
+            let mut row = Row::new().spacing(10);
+            for param in chunk {
+                let param = *param;
+                row = row.push(
+                    Checkbox::new(param.get_name(), locks.contains(&param), move |v| {
+                        on_toggle(param, v)
+                    })
+                    .style(style::Checkbox)
+                    .text_size(style::list_item_text_size())
+                    .spacing(7)
+                    .width(Length::FillPortion(1)),
+                );
+            }
+            for _ in chunk.len()..LOCKS_PER_ROW {
+                row = row.push(Column::new().width(Length::FillPortion(1)));
+            }
+            section_content = section_content.push(row);
+        }
+
+        content = content.push(section_content);
+    }
+
+    content
+}
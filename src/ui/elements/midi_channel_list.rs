@@ -2,6 +2,7 @@
 
 use iced::widget::{Column, Container, PickList, Row, Text};
 
+use super::param_tooltip::multi_tooltip;
 use crate::messages::Message;
 use crate::params::MultiParameter;
 use crate::style;
@@ -35,21 +36,22 @@ pub fn midi_channel_list(
         Message::MultiParameterChange(multi_param, v as i32)
     })
     .style(style::PickList)
-    .text_size(style::LIST_ITEM_TEXT_SIZE);
+    .text_size(style::list_item_text_size());
 
-    Container::new(
+    Container::new(multi_tooltip(
         Row::new()
             .push(
                 Column::new()
                     .push(
                         Text::new(label)
-                            .size(style::PARAM_LABEL_TEXT_SIZE)
+                            .size(style::param_label_text_size())
                             .width(style::PARAM_LABEL_WIDTH),
                     )
                     .padding([4, 0, 0, 0]),
             )
             .push(pick_list),
-    )
+        multi_param,
+    ))
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
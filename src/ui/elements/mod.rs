@@ -1,16 +1,31 @@
 //! Individual control elements used in various places
 
+pub mod animation;
 pub mod arp_grid_list;
 pub mod arp_mode_list;
 pub mod checkbox;
+pub mod control_style_list;
+pub mod env_graph;
 pub mod env_trigger_list;
+pub mod filter_graph;
 pub mod fx_mode_list;
+pub mod knob_widget;
+pub mod lfo_graph;
 pub mod lfo_phase_list;
 pub mod lfo_shape_list;
+pub mod link_mode_list;
+pub mod lock_grid;
 pub mod midi_channel_list;
 pub mod mod_target_list;
+pub mod page_list;
+pub mod param_tooltip;
 pub mod part_list;
+pub mod preset_list;
 pub mod shaper_mode_list;
 pub mod slider;
 pub mod slider_widget;
+pub mod slot_button;
+pub mod text_button;
+pub mod value_entry;
 pub mod wavetable_list;
+pub mod xy_pad_widget;
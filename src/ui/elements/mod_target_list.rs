@@ -2,55 +2,35 @@
 
 use iced::widget::{Column, Container, PickList, Row, Text};
 
+use super::param_tooltip::sound_tooltip;
 use crate::messages::Message;
 use crate::params::SoundParameter;
 use crate::style;
 
 pub fn mod_target_list(label: &str, sound_param: SoundParameter, value: i32) -> Container<Message> {
-    let value = match value {
-        0 => Some(ModTarget::Osc1Wave),
-        1 => Some(ModTarget::Osc2Wave),
-        2 => Some(ModTarget::Osc1Pitch),
-        3 => Some(ModTarget::Osc2Pitch),
-        4 => Some(ModTarget::Osc1FMAmount),
-        5 => Some(ModTarget::Osc2FMAmount),
-        6 => Some(ModTarget::Osc1FMRate),
-        7 => Some(ModTarget::Osc2FMRate),
-        8 => Some(ModTarget::Osc1Sync),
-        9 => Some(ModTarget::Osc2Sync),
-        10 => Some(ModTarget::Osc1Level),
-        11 => Some(ModTarget::Osc2Level),
-        12 => Some(ModTarget::ExtraNoise),
-        13 => Some(ModTarget::ExtraRingMod),
-        14 => Some(ModTarget::FilterCutoff),
-        15 => Some(ModTarget::ShaperCutoff),
-        16 => Some(ModTarget::FilterResonance),
-        17 => Some(ModTarget::ShaperResonance),
-        18 => Some(ModTarget::LFO1Speed),
-        19 => Some(ModTarget::LFO2Speed),
-        20 => Some(ModTarget::AmpLevel),
-        21 => Some(ModTarget::AmpPan),
-        _ => None,
-    };
-    let pick_list = PickList::new(&ModTarget::ALL[..], value, move |v| {
-        Message::SoundParameterChange(sound_param, v as i32)
-    })
+    let pick_list = PickList::new(
+        &ModTarget::ALL[..],
+        ModTarget::from_value(value),
+        move |v| Message::SoundParameterChange(sound_param, v as i32),
+    )
     .style(style::PickList)
-    .text_size(style::LIST_ITEM_TEXT_SIZE);
+    .text_size(style::list_item_text_size());
 
-    Container::new(
+    Container::new(sound_tooltip(
         Row::new()
             .push(
                 Column::new()
                     .push(
                         Text::new(label)
-                            .size(style::PARAM_LABEL_TEXT_SIZE)
+                            .size(style::param_label_text_size())
                             .width(style::PARAM_LABEL_WIDTH),
                     )
                     .padding([4, 0, 0, 0]),
             )
             .push(pick_list),
-    )
+        sound_param,
+        &[],
+    ))
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -80,7 +60,7 @@ pub enum ModTarget {
 }
 
 impl ModTarget {
-    const ALL: [ModTarget; 22] = [
+    pub const ALL: [ModTarget; 22] = [
         ModTarget::Osc1Wave,
         ModTarget::Osc2Wave,
         ModTarget::Osc1Pitch,
@@ -104,6 +84,91 @@ impl ModTarget {
         ModTarget::AmpLevel,
         ModTarget::AmpPan,
     ];
+
+    /// Return an abbreviation of the target for narrow displays
+    pub fn get_short_name(&self) -> &'static str {
+        match self {
+            ModTarget::Osc1Wave => "W1",
+            ModTarget::Osc2Wave => "W2",
+            ModTarget::Osc1Pitch => "P1",
+            ModTarget::Osc2Pitch => "P2",
+            ModTarget::Osc1FMAmount => "FA1",
+            ModTarget::Osc2FMAmount => "FA2",
+            ModTarget::Osc1FMRate => "FR1",
+            ModTarget::Osc2FMRate => "FR2",
+            ModTarget::Osc1Sync => "S1",
+            ModTarget::Osc2Sync => "S2",
+            ModTarget::Osc1Level => "L1",
+            ModTarget::Osc2Level => "L2",
+            ModTarget::ExtraNoise => "NS",
+            ModTarget::ExtraRingMod => "RM",
+            ModTarget::FilterCutoff => "FC",
+            ModTarget::ShaperCutoff => "SC",
+            ModTarget::FilterResonance => "FQ",
+            ModTarget::ShaperResonance => "SQ",
+            ModTarget::LFO1Speed => "LS1",
+            ModTarget::LFO2Speed => "LS2",
+            ModTarget::AmpLevel => "AL",
+            ModTarget::AmpPan => "AP",
+        }
+    }
+
+    /// Return the sound parameter affected by the target
+    pub fn get_param(&self) -> SoundParameter {
+        match self {
+            ModTarget::Osc1Wave => SoundParameter::Osc1Wave,
+            ModTarget::Osc2Wave => SoundParameter::Osc2Wave,
+            ModTarget::Osc1Pitch => SoundParameter::Osc1Coarse,
+            ModTarget::Osc2Pitch => SoundParameter::Osc2Coarse,
+            ModTarget::Osc1FMAmount => SoundParameter::Osc1FMAmount,
+            ModTarget::Osc2FMAmount => SoundParameter::Osc2FMAmount,
+            ModTarget::Osc1FMRate => SoundParameter::Osc1FMRate,
+            ModTarget::Osc2FMRate => SoundParameter::Osc2FMRate,
+            ModTarget::Osc1Sync => SoundParameter::Osc1Sync,
+            ModTarget::Osc2Sync => SoundParameter::Osc2Sync,
+            ModTarget::Osc1Level => SoundParameter::Osc1Level,
+            ModTarget::Osc2Level => SoundParameter::Osc2Level,
+            ModTarget::ExtraNoise => SoundParameter::ExtraNoise,
+            ModTarget::ExtraRingMod => SoundParameter::ExtraRingMod,
+            ModTarget::FilterCutoff => SoundParameter::FilterCutoff,
+            ModTarget::ShaperCutoff => SoundParameter::ShaperCutoff,
+            ModTarget::FilterResonance => SoundParameter::FilterResonance,
+            ModTarget::ShaperResonance => SoundParameter::ShaperResonance,
+            ModTarget::LFO1Speed => SoundParameter::LFO1Speed,
+            ModTarget::LFO2Speed => SoundParameter::LFO2Speed,
+            ModTarget::AmpLevel => SoundParameter::AmpLevel,
+            ModTarget::AmpPan => SoundParameter::AmpPan,
+        }
+    }
+
+    /// Return the target for a parameter value
+    pub fn from_value(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(ModTarget::Osc1Wave),
+            1 => Some(ModTarget::Osc2Wave),
+            2 => Some(ModTarget::Osc1Pitch),
+            3 => Some(ModTarget::Osc2Pitch),
+            4 => Some(ModTarget::Osc1FMAmount),
+            5 => Some(ModTarget::Osc2FMAmount),
+            6 => Some(ModTarget::Osc1FMRate),
+            7 => Some(ModTarget::Osc2FMRate),
+            8 => Some(ModTarget::Osc1Sync),
+            9 => Some(ModTarget::Osc2Sync),
+            10 => Some(ModTarget::Osc1Level),
+            11 => Some(ModTarget::Osc2Level),
+            12 => Some(ModTarget::ExtraNoise),
+            13 => Some(ModTarget::ExtraRingMod),
+            14 => Some(ModTarget::FilterCutoff),
+            15 => Some(ModTarget::ShaperCutoff),
+            16 => Some(ModTarget::FilterResonance),
+            17 => Some(ModTarget::ShaperResonance),
+            18 => Some(ModTarget::LFO1Speed),
+            19 => Some(ModTarget::LFO2Speed),
+            20 => Some(ModTarget::AmpLevel),
+            21 => Some(ModTarget::AmpPan),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for ModTarget {
@@ -0,0 +1,73 @@
+//! Dropdown menu for page selection
+
+use iced::widget::{Container, PickList};
+
+use crate::messages::Message;
+use crate::style;
+
+pub fn page_list<'a>(value: Page) -> Container<'a, Message> {
+    let pick_list = PickList::new(&Page::ALL[..], Some(value), Message::PageChange)
+        .style(style::PickList)
+        .text_size(style::list_item_text_size());
+
+    Container::new(pick_list)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Page {
+    Sound,
+    Overview,
+    Compare,
+    ModMatrix,
+    Sections,
+    Locks,
+    Layout,
+    Macros,
+    Randomizer,
+    Morph,
+    XYPad,
+    Breeding,
+    Monitor,
+}
+
+impl Page {
+    pub const ALL: [Page; 13] = [
+        Page::Sound,
+        Page::Overview,
+        Page::Compare,
+        Page::ModMatrix,
+        Page::Sections,
+        Page::Locks,
+        Page::Layout,
+        Page::Macros,
+        Page::Randomizer,
+        Page::Morph,
+        Page::XYPad,
+        Page::Breeding,
+        Page::Monitor,
+    ];
+}
+
+impl std::fmt::Display for Page {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Page::Sound => "Sound",
+                Page::Overview => "Parts Overview",
+                Page::Compare => "A/B Compare",
+                Page::ModMatrix => "Mod Matrix",
+                Page::Sections => "Copy & Swap",
+                Page::Locks => "Preset Locks",
+                Page::Layout => "Layout & Theme",
+                Page::Macros => "Macros",
+                Page::Randomizer => "Randomizer",
+                Page::Morph => "Morph",
+                Page::XYPad => "XY Pad",
+                Page::Breeding => "Breeding",
+                Page::Monitor => "MIDI Monitor",
+            }
+        )
+    }
+}
@@ -0,0 +1,49 @@
+//! Tooltips documenting the parameter of a control
+
+use iced::widget::{tooltip, Tooltip};
+use iced::Element;
+
+use crate::messages::Message;
+use crate::param_docs::ParamDoc;
+use crate::params::{MultiParameter, SoundParameter};
+use crate::style;
+
+/// Returns the control with a tooltip documenting a sound (preset) parameter
+///
+/// - `content`     Control of the parameter
+/// - `sound_param` Documented parameter
+/// - `extra`       Additional lines appended to the documentation
+pub fn sound_tooltip<'a>(
+    content: impl Into<Element<'a, Message>>,
+    sound_param: SoundParameter,
+    extra: &[String],
+) -> Tooltip<'a, Message> {
+    let mut text = ParamDoc::of_sound(sound_param).tooltip();
+    for line in extra {
+        text.push('\n');
+        text.push_str(line);
+    }
+
+    param_tooltip(content, text)
+}
+
+/// Returns the control with a tooltip documenting a multi parameter
+pub fn multi_tooltip<'a>(
+    content: impl Into<Element<'a, Message>>,
+    multi_param: MultiParameter,
+) -> Tooltip<'a, Message> {
+    param_tooltip(content, ParamDoc::of_multi(multi_param).tooltip())
+}
+
+/// Returns the control with a tooltip showing the given text
+///
+/// - `content`     Control of the parameter
+/// - `text`        Documentation shown in the tooltip
+fn param_tooltip<'a>(
+    content: impl Into<Element<'a, Message>>,
+    text: String,
+) -> Tooltip<'a, Message> {
+    Tooltip::new(content, text, tooltip::Position::Top)
+        .size(style::param_label_text_size())
+        .style(style::Tooltip)
+}
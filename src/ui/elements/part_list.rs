@@ -17,7 +17,7 @@ pub fn part_list<'a>(value: u8) -> Container<'a, Message> {
         Message::PartChange(v as u8)
     })
     .style(style::PickList)
-    .text_size(style::LIST_ITEM_TEXT_SIZE);
+    .text_size(style::list_item_text_size());
 
     Container::new(pick_list)
 }
@@ -0,0 +1,73 @@
+//! Dropdown menus listing the stored presets
+
+use iced::widget::{Container, PickList};
+
+use crate::messages::Message;
+use crate::params::MultiParameter;
+use crate::style;
+
+/// Returns a dropdown menu listing the stored presets with their names
+///
+/// - `multi_param`     Preset assignment parameter of the part
+/// - `value`           Currently assigned preset id
+/// - `preset_names`    Names of the stored presets, empty if not known yet
+pub fn preset_list<'a>(
+    multi_param: MultiParameter,
+    value: i32,
+    preset_names: &[String],
+) -> Container<'a, Message> {
+    stored_preset_list(Some(value), preset_names, move |id| {
+        Message::MultiParameterChange(multi_param, id)
+    })
+}
+
+/// Returns a dropdown menu selecting a stored preset
+///
+/// - `value`           Selected preset id, `None` if nothing is selected
+/// - `preset_names`    Names of the stored presets, empty if not known yet
+/// - `on_selected`     Message for the selected preset id
+pub fn stored_preset_list<'a>(
+    value: Option<i32>,
+    preset_names: &[String],
+    on_selected: impl Fn(i32) -> Message + 'a,
+) -> Container<'a, Message> {
+    let entries: Vec<PresetListEntry> = preset_names
+        .iter()
+        .enumerate()
+        .map(|(id, name)| PresetListEntry {
+            id: id as i32,
+            name: String::from(name.trim_end()),
+        })
+        .collect();
+    let selected = entries
+        .iter()
+        .find(|entry| Some(entry.id) == value)
+        .cloned();
+
+    let pick_list = PickList::new(entries, selected, move |v| on_selected(v.id))
+        .placeholder("Stored preset")
+        .style(style::PickList)
+        .text_size(style::list_item_text_size());
+
+    Container::new(pick_list)
+}
+
+/// Entry of the preset list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PresetListEntry {
+    /// Preset id 0-99
+    id: i32,
+
+    /// Stored name of the preset
+    name: String,
+}
+
+impl std::fmt::Display for PresetListEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.name.is_empty() {
+            write!(f, "{:02}", self.id)
+        } else {
+            write!(f, "{:02} {}", self.id, self.name)
+        }
+    }
+}
@@ -2,6 +2,7 @@
 
 use iced::widget::{Column, Container, PickList, Row, Text};
 
+use super::param_tooltip::sound_tooltip;
 use crate::messages::Message;
 use crate::params::SoundParameter;
 use crate::style;
@@ -11,31 +12,29 @@ pub fn shaper_mode_list(
     sound_param: SoundParameter,
     value: i32,
 ) -> Container<Message> {
-    let value = match value {
-        0 => Some(ShaperMode::Lowpass),
-        1 => Some(ShaperMode::Bandpass),
-        2 => Some(ShaperMode::Highpass),
-        _ => None,
-    };
-    let pick_list = PickList::new(&ShaperMode::ALL[..], value, move |v| {
-        Message::SoundParameterChange(sound_param, v as i32)
-    })
+    let pick_list = PickList::new(
+        &ShaperMode::ALL[..],
+        ShaperMode::from_value(value),
+        move |v| Message::SoundParameterChange(sound_param, v as i32),
+    )
     .style(style::PickList)
-    .text_size(style::LIST_ITEM_TEXT_SIZE);
+    .text_size(style::list_item_text_size());
 
-    Container::new(
+    Container::new(sound_tooltip(
         Row::new()
             .push(
                 Column::new()
                     .push(
                         Text::new(label)
-                            .size(style::PARAM_LABEL_TEXT_SIZE)
+                            .size(style::param_label_text_size())
                             .width(style::PARAM_LABEL_WIDTH),
                     )
                     .padding([4, 0, 0, 0]),
             )
             .push(pick_list),
-    )
+        sound_param,
+        &[],
+    ))
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -51,6 +50,16 @@ impl ShaperMode {
         ShaperMode::Bandpass,
         ShaperMode::Highpass,
     ];
+
+    /// Return the mode for a parameter value
+    pub fn from_value(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(ShaperMode::Lowpass),
+            1 => Some(ShaperMode::Bandpass),
+            2 => Some(ShaperMode::Highpass),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for ShaperMode {
@@ -1,49 +1,223 @@
 //! Slider control wrapped in a container with label and value display
 
-use iced::alignment;
+use std::ops::RangeInclusive;
+
 use iced::widget::{Column, Container, Row, Text};
+use iced::{alignment, Alignment, Element, Length};
 
-use super::slider_widget::Slider;
+use super::control_style_list::ControlStyle;
+use super::knob_widget::{fader, knob};
+use super::param_tooltip::{multi_tooltip, sound_tooltip};
+use super::slider_widget::{ModulationRange, Slider};
+use super::value_entry::{value_button, ValueTarget};
 
 use crate::messages::Message;
-use crate::params::{MultiParameter, SoundParameter};
+use crate::modulation::{self, ModSource, Routing};
+use crate::params::{GetValue, MultiParameter, SoundParameter, SoundParameterValues};
 use crate::style;
 
-/// Returns a slider for a sound (preset) parameter
-pub fn slider_with_labels(
-    label: &str,
+/// Maximum absolute modulation amount
+const MAX_MOD_AMOUNT: f32 = 128.0;
+
+/// Number of knobs or faders per row
+const CONTROLS_PER_ROW: usize = 4;
+
+/// Returns the controls of several sound (preset) parameters in a style
+///
+/// Sliders are stacked, knobs and faders are arranged in a grid.
+pub fn sound_controls<'a>(
+    controls: &[(&'a str, SoundParameter)],
+    params: &SoundParameterValues,
+    control_style: ControlStyle,
+    focus: Option<SoundParameter>,
+) -> Element<'a, Message> {
+    let mut content = Column::new().spacing(style::SECTION_SPACING);
+
+    if control_style == ControlStyle::Slider {
+        for (label, sound_param) in controls {
+            content = content.push(slider_with_labels(
+                label,
+                ValueTarget::Sound(*sound_param),
+                params.get_value(*sound_param),
+                &modulation::modulations_of(params, *sound_param),
+                focus == Some(*sound_param),
+            ));
+        }
+        return content.into();
+    }
+
+    for chunk in controls.chunks(CONTROLS_PER_ROW) {
+        let mut row = Row::new();
+        for (label, sound_param) in chunk {
+            row = row.push(knob_with_labels(
+                label,
+                *sound_param,
+                params,
+                control_style,
+                focus == Some(*sound_param),
+            ));
+        }
+        for _ in chunk.len()..CONTROLS_PER_ROW {
+            row = row.push(Column::new().width(Length::FillPortion(1)));
+        }
+        content = content.push(row);
+    }
+
+    content.spacing(5).into()
+}
+
+/// Returns a knob or fader for a sound (preset) parameter, with the label above
+/// and the value below
+pub fn knob_with_labels<'a>(
+    label: &'a str,
     sound_param: SoundParameter,
+    params: &SoundParameterValues,
+    control_style: ControlStyle,
+    focused: bool,
+) -> Container<'a, Message> {
+    let value = params.get_value(sound_param);
+    let routings = modulation::modulations_of(params, sound_param);
+    let on_change = move |v| Message::SoundParameterChange(sound_param, v);
+
+    let (control, width, height) = match control_style {
+        ControlStyle::Fader => (
+            fader(
+                sound_param.get_range(),
+                value,
+                sound_param.get_default(),
+                on_change,
+            ),
+            style::KNOB_SIZE,
+            style::FADER_HEIGHT,
+        ),
+        ControlStyle::Slider | ControlStyle::Knob => (
+            knob(
+                sound_param.get_range(),
+                value,
+                sound_param.get_default(),
+                on_change,
+            ),
+            style::KNOB_SIZE,
+            style::KNOB_SIZE,
+        ),
+    };
+
+    let content: Element<Message> = Column::new()
+        .align_items(Alignment::Center)
+        .width(Length::Fill)
+        .push(
+            Text::new(label)
+                .size(style::knob_text_size())
+                .style(label_color(focused))
+                .horizontal_alignment(alignment::Horizontal::Center),
+        )
+        .push(
+            control
+                .modulation(modulation_ranges(sound_param.get_range(), &routings))
+                .on_release(Message::ParameterEditFinished)
+                .view(width, height),
+        )
+        .push(value_button(ValueTarget::Sound(sound_param), value).width(Length::Fill))
+        .into();
+
+    with_tooltip(content, ValueTarget::Sound(sound_param), &routings).width(Length::FillPortion(1))
+}
+
+/// Returns a slider for a sound (preset) or multi parameter
+///
+/// Active modulations of the parameter are shown as ranges over the slider
+/// and listed in the tooltip documenting the parameter.
+///
+/// - `label`       Label left of the slider
+/// - `target`      Parameter changed by the slider
+/// - `value`       Current value
+/// - `routings`    Modulations of the parameter, empty for multi parameters
+/// - `focused`     Flag for the parameter having the keyboard focus
+pub fn slider_with_labels<'a>(
+    label: &'a str,
+    target: ValueTarget,
     value: i32,
-) -> Container<Message> {
-    let range = sound_param.get_range();
-    let slider = Slider::new(range, value, sound_param.get_default(), move |v| {
-        Message::SoundParameterChange(sound_param, v)
+    routings: &[Routing],
+    focused: bool,
+) -> Container<'a, Message> {
+    let slider = Slider::new(target.get_range(), value, target.get_default(), move |v| {
+        target.message(v)
     })
+    .modulation(modulation_ranges(target.get_range(), routings))
+    .on_release(Message::ParameterEditFinished)
     .style(style::Slider);
 
-    Container::new(
-        Row::new()
-            .push(
-                Column::new()
-                    .push(
-                        Text::new(label)
-                            .size(style::PARAM_LABEL_TEXT_SIZE)
-                            .width(style::PARAM_LABEL_WIDTH),
-                    )
-                    .padding([3, 0, 0, 0]),
-            )
-            .push(slider)
-            .push(
-                Column::new()
-                    .push(
-                        Text::new(format!("{}", value))
-                            .size(style::PARAM_LABEL_TEXT_SIZE)
-                            .horizontal_alignment(alignment::Horizontal::Right)
-                            .width(style::PARAM_VALUE_WIDTH),
-                    )
-                    .padding([3, 0, 0, 5]),
-            ),
-    )
+    let content: Element<Message> = Row::new()
+        .push(
+            Column::new()
+                .push(
+                    Text::new(label)
+                        .size(style::param_label_text_size())
+                        .style(label_color(focused))
+                        .width(style::PARAM_LABEL_WIDTH),
+                )
+                .padding([3, 0, 0, 0]),
+        )
+        .push(slider)
+        .push(
+            Column::new()
+                .push(value_button(target, value))
+                .padding([3, 0, 0, 5]),
+        )
+        .into();
+
+    with_tooltip(content, target, routings)
+}
+
+/// Return the label color of a control, highlighted if it has the keyboard focus
+fn label_color(focused: bool) -> iced::theme::Text {
+    if focused {
+        iced::theme::Text::Color(style::focus_color())
+    } else {
+        iced::theme::Text::Default
+    }
+}
+
+/// Return the overlay ranges of the modulations of a parameter
+fn modulation_ranges(range: RangeInclusive<i32>, routings: &[Routing]) -> Vec<ModulationRange> {
+    let span = (range.end() - range.start()) as f32;
+
+    // Full amount modulates the whole parameter range
+    routings
+        .iter()
+        .map(|routing| ModulationRange {
+            depth: span * routing.amount as f32 / MAX_MOD_AMOUNT,
+            color: source_color(routing.source),
+        })
+        .collect()
+}
+
+/// Returns the content with a tooltip documenting the parameter and listing its modulations
+fn with_tooltip<'a>(
+    content: Element<'a, Message>,
+    target: ValueTarget,
+    routings: &[Routing],
+) -> Container<'a, Message> {
+    match target {
+        ValueTarget::Sound(sound_param) | ValueTarget::Part(_, sound_param) => {
+            let sources: Vec<String> = routings
+                .iter()
+                .map(|routing| format!("{}: {:+}", routing.source, routing.amount))
+                .collect();
+
+            Container::new(sound_tooltip(content, sound_param, &sources))
+        }
+        ValueTarget::Multi(multi_param) => Container::new(multi_tooltip(content, multi_param)),
+    }
+}
+
+/// Return the overlay color of a modulation source
+fn source_color(source: ModSource) -> iced::Color {
+    let index = ModSource::ALL
+        .iter()
+        .position(|s| *s == source)
+        .unwrap_or_default();
+    style::mod_source_colors()[index]
 }
 
 /// Returns a slider for a multi parameter
@@ -51,34 +225,6 @@ pub fn multi_slider_with_labels(
     label: &str,
     multi_param: MultiParameter,
     value: i32,
-) -> Container<Message> {
-    let range = multi_param.get_range();
-    let slider = Slider::new(range, value, multi_param.get_default(), move |v| {
-        Message::MultiParameterChange(multi_param, v)
-    })
-    .style(style::Slider);
-
-    Container::new(
-        Row::new()
-            .push(
-                Column::new()
-                    .push(
-                        Text::new(label)
-                            .size(style::PARAM_LABEL_TEXT_SIZE)
-                            .width(style::PARAM_LABEL_WIDTH),
-                    )
-                    .padding([3, 0, 0, 0]),
-            )
-            .push(slider)
-            .push(
-                Column::new()
-                    .push(
-                        Text::new(format!("{}", value))
-                            .size(style::PARAM_LABEL_TEXT_SIZE)
-                            .horizontal_alignment(alignment::Horizontal::Right)
-                            .width(style::PARAM_VALUE_WIDTH),
-                    )
-                    .padding([3, 0, 0, 5]),
-            ),
-    )
+) -> Container<'_, Message> {
+    slider_with_labels(label, ValueTarget::Multi(multi_param), value, &[], false)
 }
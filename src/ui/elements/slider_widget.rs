@@ -7,6 +7,7 @@
 //!     - Control-click/right-click resets slider to a default value.
 //!     - Shift-drag enables fine control.
 //!     - Clippy related fixes.
+//!     - Overlay of modulation ranges.
 //!
 //! A [`Slider`] has some local [`State`].
 
@@ -63,11 +64,22 @@ where
     default: T,
     on_change: Box<dyn Fn(T) -> Message + 'a>,
     on_release: Option<Message>,
+    modulation: Vec<ModulationRange>,
     width: Length,
     height: u16,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
+/// Range of a modulation drawn over the rail, starting at the slider value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModulationRange {
+    /// Modulation depth in slider value units, negative for downwards modulation
+    pub depth: f32,
+
+    /// Color of the range
+    pub color: Color,
+}
+
 impl<'a, T, Message, Renderer> Slider<'a, T, Message, Renderer>
 where
     T: Copy + From<u8> + std::cmp::PartialOrd,
@@ -109,6 +121,7 @@ where
             default,
             on_change: Box::new(on_change),
             on_release: None,
+            modulation: Vec::new(),
             width: Length::Fill,
             height: Self::DEFAULT_HEIGHT,
             style: Default::default(),
@@ -126,6 +139,12 @@ where
         self
     }
 
+    /// Sets the modulation ranges drawn over the rail of the [`Slider`].
+    pub fn modulation(mut self, modulation: Vec<ModulationRange>) -> Self {
+        self.modulation = modulation;
+        self
+    }
+
     /// Sets the width of the [`Slider`].
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -181,6 +200,7 @@ where
                 if state.control_pressed {
                     let new_value = default;
                     shell.publish((on_change)(new_value));
+                    publish_release(shell, on_release);
                     *value = new_value;
                 } else {
                     let step = step.into();
@@ -206,9 +226,7 @@ where
         | Event::Touch(touch::Event::FingerLifted { .. })
         | Event::Touch(touch::Event::FingerLost { .. }) => {
             if is_dragging {
-                if let Some(on_release) = on_release.clone() {
-                    shell.publish(on_release);
-                }
+                publish_release(shell, on_release);
                 state.is_dragging = false;
 
                 return event::Status::Captured;
@@ -218,6 +236,7 @@ where
             if layout.bounds().contains(cursor_position) {
                 let new_value = default;
                 shell.publish((on_change)(new_value));
+                publish_release(shell, on_release);
                 *value = new_value;
             }
         }
@@ -260,6 +279,7 @@ where
                 let new_value = { *value + T::from_f32(delta).unwrap_or_default() }
                     .clamp(*range.start(), *range.end());
                 shell.publish((on_change)(new_value));
+                publish_release(shell, on_release);
 
                 *value = new_value;
 
@@ -324,6 +344,13 @@ where
     event::Status::Ignored
 }
 
+/// Publishes the release message, ending an edit made by a single action like a wheel step.
+fn publish_release<Message: Clone>(shell: &mut Shell<'_, Message>, on_release: &Option<Message>) {
+    if let Some(on_release) = on_release.clone() {
+        shell.publish(on_release);
+    }
+}
+
 /// Draws a [`Slider`].
 #[allow(clippy::too_many_arguments)]
 pub fn draw<T, R>(
@@ -333,6 +360,7 @@ pub fn draw<T, R>(
     state: &State,
     value: T,
     range: &RangeInclusive<T>,
+    modulation: &[ModulationRange],
     style_sheet: &dyn StyleSheet<Style = <R::Theme as StyleSheet>::Style>,
     style: &<R::Theme as StyleSheet>::Style,
 ) where
@@ -404,6 +432,34 @@ pub fn draw<T, R>(
         bounds.width * (value - range_start) / (range_end - range_start) - handle_width / 2.0
     };
 
+    if range_start < range_end {
+        let position = |v: f32| {
+            bounds.x
+                + bounds.width * (v.clamp(range_start, range_end) - range_start)
+                    / (range_end - range_start)
+        };
+
+        // One thin bar per modulation source above the rail
+        for (index, range) in modulation.iter().enumerate() {
+            let from = position(value);
+            let to = position(value + range.depth);
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: from.min(to),
+                        y: rail_y - 4.0 - 3.0 * index as f32,
+                        width: (to - from).abs().max(1.0),
+                        height: 2.0,
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                range.color,
+            );
+        }
+    }
+
     renderer.fill_quad(
         renderer::Quad {
             bounds: Rectangle {
@@ -528,6 +584,7 @@ where
             tree.state.downcast_ref::<State>(),
             self.value,
             &self.range,
+            &self.modulation,
             theme,
             &self.style,
         )
@@ -0,0 +1,19 @@
+//! Button activating an A/B compare slot
+
+use iced::theme;
+use iced::widget::{Button, Text};
+
+use crate::compare::Slot;
+use crate::messages::Message;
+use crate::style;
+
+/// Returns a button activating a compare slot, highlighted if the slot is active
+pub fn slot_button<'a>(slot: Slot, active: Slot) -> Button<'a, Message> {
+    Button::new(Text::new(slot.to_string()).size(style::button_text_size()))
+        .style(if slot == active {
+            theme::Button::Positive
+        } else {
+            theme::Button::Primary
+        })
+        .on_press(Message::CompareSelect(slot))
+}
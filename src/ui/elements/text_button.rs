@@ -0,0 +1,22 @@
+//! Button with a text label
+
+use std::borrow::Cow;
+
+use iced::theme;
+use iced::widget::{Button, Text};
+
+use crate::messages::Message;
+use crate::style;
+
+/// Returns a button, disabled if there is no message
+pub fn text_button<'a>(
+    text: impl Into<Cow<'a, str>>,
+    message: Option<Message>,
+) -> Button<'a, Message> {
+    let mut button =
+        Button::new(Text::new(text).size(style::button_text_size())).style(theme::Button::Primary);
+    if let Some(message) = message {
+        button = button.on_press(message);
+    }
+    button
+}
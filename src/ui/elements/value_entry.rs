@@ -0,0 +1,164 @@
+//! Text entry for typing an exact parameter value
+
+use std::ops::RangeInclusive;
+
+use iced::alignment;
+use iced::widget::{text_input, Button, Container, Row, Text, TextInput};
+use iced::{theme, Alignment, Length};
+
+use crate::messages::Message;
+use crate::params::{MultiParameter, SoundParameter};
+use crate::style;
+use crate::units;
+
+/// Parameter whose value is entered as text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueTarget {
+    /// Sound parameter of the current part
+    Sound(SoundParameter),
+
+    /// Sound parameter of a specific part
+    Part(u8, SoundParameter),
+
+    /// Multi parameter
+    Multi(MultiParameter),
+}
+
+impl ValueTarget {
+    /// Return the full name of the parameter
+    pub fn get_name(&self) -> String {
+        match self {
+            ValueTarget::Sound(param) => param.get_name().to_string(),
+            ValueTarget::Part(part_id, param) => {
+                format!("Part {} {}", part_id + 1, param.get_name())
+            }
+            ValueTarget::Multi(param) => param.get_name().to_string(),
+        }
+    }
+
+    /// Return the range of the parameter
+    pub fn get_range(&self) -> RangeInclusive<i32> {
+        match self {
+            ValueTarget::Sound(param) | ValueTarget::Part(_, param) => param.get_range(),
+            ValueTarget::Multi(param) => param.get_range(),
+        }
+    }
+
+    /// Return the default value of the parameter
+    pub fn get_default(&self) -> i32 {
+        match self {
+            ValueTarget::Sound(param) | ValueTarget::Part(_, param) => param.get_default(),
+            ValueTarget::Multi(param) => param.get_default(),
+        }
+    }
+
+    /// Return the message setting the parameter to a value
+    pub fn message(&self, value: i32) -> Message {
+        match *self {
+            ValueTarget::Sound(param) => Message::SoundParameterChange(param, value),
+            ValueTarget::Part(part_id, param) => {
+                Message::PartParameterChange(part_id, param, value)
+            }
+            ValueTarget::Multi(param) => Message::MultiParameterChange(param, value),
+        }
+    }
+
+    /// Return a value in the unit of the parameter
+    pub fn format(&self, value: i32) -> String {
+        match self {
+            ValueTarget::Sound(param) | ValueTarget::Part(_, param) => {
+                units::format_sound_value(*param, value)
+            }
+            ValueTarget::Multi(param) => units::format_multi_value(*param, value),
+        }
+    }
+
+    /// Return the message setting the value of a text, `None` if the text is invalid
+    pub fn parse(&self, text: &str) -> Option<Message> {
+        let value = match *self {
+            ValueTarget::Sound(param) | ValueTarget::Part(_, param) => {
+                units::parse_sound_value(param, text)
+            }
+            ValueTarget::Multi(param) => units::parse_multi_value(param, text),
+        };
+
+        value.map(|value| self.message(value))
+    }
+}
+
+/// A value being entered as text
+#[derive(Debug, Clone)]
+pub struct ValueEntry {
+    /// Parameter to be changed
+    pub target: ValueTarget,
+
+    /// Entered text
+    pub text: String,
+
+    /// Flag for a text that could not be converted to a value
+    pub invalid: bool,
+}
+
+impl ValueEntry {
+    /// Constructs a new entry initialized with the current value
+    pub fn new(target: ValueTarget, value: i32) -> Self {
+        Self {
+            target,
+            text: target.format(value),
+            invalid: false,
+        }
+    }
+}
+
+/// Return the id of the text input
+pub fn input_id() -> text_input::Id {
+    text_input::Id::new("value_entry")
+}
+
+/// Returns the value display of a slider, starting the text entry when clicked
+pub fn value_button<'a>(target: ValueTarget, value: i32) -> Button<'a, Message> {
+    Button::new(
+        Text::new(target.format(value))
+            .size(style::param_label_text_size())
+            .horizontal_alignment(alignment::Horizontal::Right)
+            .width(Length::Fill),
+    )
+    .style(style::ValueButton.into())
+    .padding(0)
+    .width(style::PARAM_VALUE_WIDTH)
+    .on_press(Message::ValueEntryStart(target, value))
+}
+
+/// Returns the text entry for a value
+pub fn value_entry<'a>(entry: &ValueEntry) -> Container<'a, Message> {
+    Container::new(
+        Row::new()
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .push(
+                Text::new(format!("{}:", entry.target.get_name())).size(style::status_text_size()),
+            )
+            .push(
+                TextInput::new("Value", &entry.text)
+                    .id(input_id())
+                    .on_input(Message::ValueEntryChange)
+                    .on_submit(Message::ValueEntrySubmit)
+                    .size(style::param_label_text_size())
+                    .padding(3)
+                    .width(150),
+            )
+            .push(
+                Text::new(if entry.invalid {
+                    "Invalid value"
+                } else {
+                    "Enter to apply, Esc to cancel, # for raw values"
+                })
+                .size(style::status_text_size()),
+            )
+            .push(
+                Button::new(Text::new("Cancel").size(style::button_text_size()))
+                    .style(theme::Button::Primary)
+                    .on_press(Message::ValueEntryCancel),
+            ),
+    )
+}
@@ -0,0 +1,161 @@
+//! Two-dimensional area for dragging a position controlling two parameters
+
+use iced::widget::canvas::{self, event, Canvas, Cursor, Frame, Geometry, LineDash, Path, Stroke};
+use iced::{mouse, Color, Element, Length, Point, Rectangle, Size, Theme};
+
+use crate::messages::Message;
+use crate::style;
+use crate::xy_pad::GesturePoint;
+
+/// Radius of the position marker
+const MARKER_RADIUS: f32 = 7.0;
+
+/// Returns the pad
+///
+/// - `position`    Current position, 0.0-1.0 on both axes
+/// - `gesture`     Recorded gesture drawn as trail
+pub fn xy_pad<'a>(position: (f32, f32), gesture: &[GesturePoint]) -> Element<'a, Message> {
+    Canvas::new(XYPadArea {
+        position,
+        gesture: gesture.iter().map(|point| (point.x, point.y)).collect(),
+    })
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .into()
+}
+
+/// Interaction state of the pad
+#[derive(Default)]
+struct State {
+    dragging: bool,
+}
+
+/// Values to be drawn
+struct XYPadArea {
+    position: (f32, f32),
+    gesture: Vec<(f32, f32)>,
+}
+
+/// Return the canvas point of a position
+fn to_point(size: Size, (x, y): (f32, f32)) -> Point {
+    Point::new(x * size.width, (1.0 - y) * size.height)
+}
+
+/// Return the position of a canvas point
+fn to_position(size: Size, point: Point) -> (f32, f32) {
+    (
+        (point.x / size.width).clamp(0.0, 1.0),
+        (1.0 - point.y / size.height).clamp(0.0, 1.0),
+    )
+}
+
+impl canvas::Program<Message> for XYPadArea {
+    type State = State;
+
+    fn update(
+        &self,
+        state: &mut State,
+        event: canvas::Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (event::Status, Option<Message>) {
+        match event {
+            canvas::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(point) = cursor.position_in(&bounds) {
+                    state.dragging = true;
+                    let (x, y) = to_position(bounds.size(), point);
+                    return (event::Status::Captured, Some(Message::XYPadMove(x, y)));
+                }
+            }
+            canvas::Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let (true, Some(point)) =
+                    (state.dragging, cursor.position_from(bounds.position()))
+                {
+                    let (x, y) = to_position(bounds.size(), point);
+                    return (event::Status::Captured, Some(Message::XYPadMove(x, y)));
+                }
+            }
+            canvas::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.dragging =>
+            {
+                state.dragging = false;
+                return (event::Status::Captured, Some(Message::XYPadRelease));
+            }
+            _ => {}
+        }
+
+        (event::Status::Ignored, None)
+    }
+
+    fn draw(
+        &self,
+        state: &State,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(bounds.size());
+        let size = bounds.size();
+        let dashed = Stroke {
+            line_dash: LineDash {
+                segments: &[2.0, 4.0],
+                offset: 0,
+            },
+            ..Stroke::default()
+                .with_color(style::graph_mod_color())
+                .with_width(1.0)
+        };
+
+        frame.stroke(
+            &Path::line(to_point(size, (0.5, 0.0)), to_point(size, (0.5, 1.0))),
+            dashed.clone(),
+        );
+        frame.stroke(
+            &Path::line(to_point(size, (0.0, 0.5)), to_point(size, (1.0, 0.5))),
+            dashed,
+        );
+
+        if let Some((first, rest)) = self.gesture.split_first() {
+            frame.stroke(
+                &Path::new(|builder| {
+                    builder.move_to(to_point(size, *first));
+                    for position in rest {
+                        builder.line_to(to_point(size, *position));
+                    }
+                }),
+                Stroke::default()
+                    .with_color(Color {
+                        a: 0.5,
+                        ..style::graph_mod_color()
+                    })
+                    .with_width(2.0),
+            );
+        }
+
+        frame.fill(
+            &Path::circle(to_point(size, self.position), MARKER_RADIUS),
+            if state.dragging {
+                style::control_hovered_color()
+            } else {
+                style::control_color()
+            },
+        );
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        if state.dragging {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(&bounds) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Panel containing the control style of each sound section and the theme selection
+
+use std::collections::HashMap;
+
+use iced::widget::{Button, Column, Container, PickList, Row, Text};
+use iced::{theme, Alignment, Element, Length};
+
+use super::style;
+use crate::messages::Message;
+use crate::params::SoundSection;
+use crate::ui::elements::control_style_list::{control_style_list, ControlStyle};
+use crate::ui::theme::EditorTheme;
+
+pub struct LayoutPanel {}
+
+impl LayoutPanel {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Returns the panel
+    ///
+    /// - `controls`    Control style of the sections, sliders if missing
+    /// - `themes`      Built-in and user themes
+    /// - `selected`    Name of the theme in use
+    pub fn view(
+        &self,
+        controls: &HashMap<SoundSection, ControlStyle>,
+        themes: &[EditorTheme],
+        selected: &str,
+    ) -> Element<'_, Message> {
+        let names: Vec<String> = themes.iter().map(|theme| theme.name.clone()).collect();
+        let theme_row = Row::new()
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .push(
+                Text::new("Theme")
+                    .size(style::param_label_text_size())
+                    .width(style::PARAM_LABEL_WIDTH),
+            )
+            .push(
+                PickList::new(names, Some(selected.to_string()), Message::ThemeChange)
+                    .style(style::PickList)
+                    .text_size(style::list_item_text_size())
+                    .width(200),
+            )
+            .push(
+                Button::new(Text::new("Reload user themes").size(style::button_text_size()))
+                    .style(theme::Button::Primary)
+                    .on_press(Message::ThemeReload),
+            );
+
+        let mut all = Row::new()
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .push(Text::new("All sections").size(style::param_label_text_size()));
+        for control_style in ControlStyle::ALL {
+            all = all.push(
+                Button::new(Text::new(control_style.to_string()).size(style::button_text_size()))
+                    .style(theme::Button::Primary)
+                    .on_press(Message::ControlStyleChangeAll(control_style)),
+            );
+        }
+
+        let mut content = Column::new()
+            .padding(style::SECTION_PADDING)
+            .spacing(8)
+            .push(
+                Text::new("Controls of the sound sections").size(style::section_label_text_size()),
+            )
+            .push(all);
+
+        for section in SoundSection::ALL {
+            content = content.push(control_style_list(
+                section,
+                controls.get(&section).copied().unwrap_or_default(),
+            ));
+        }
+
+        let appearance = Column::new()
+            .padding(style::SECTION_PADDING)
+            .spacing(8)
+            .push(Text::new("Appearance").size(style::section_label_text_size()))
+            .push(theme_row)
+            .push(
+                Text::new("User themes are read from themes.ron in the config directory")
+                    .size(style::param_label_text_size()),
+            );
+
+        Container::new(
+            Column::new()
+                .spacing(10)
+                .push(
+                    Container::new(appearance)
+                        .style(style::OverviewSection)
+                        .width(Length::Fill),
+                )
+                .push(
+                    Container::new(content)
+                        .style(style::OverviewSection)
+                        .width(Length::Fill)
+                        .height(Length::Fill),
+                ),
+        )
+        .padding(10)
+        .height(Length::Fill)
+        .style(style::MainWindow)
+        .into()
+    }
+}
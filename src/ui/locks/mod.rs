@@ -0,0 +1,49 @@
+//! Panel containing the parameter locks preserved across preset loads
+
+use std::collections::HashSet;
+
+use iced::widget::{Column, Container, Scrollable, Text};
+use iced::{Element, Length};
+
+use super::style;
+use crate::messages::Message;
+use crate::params::SoundParameter;
+use crate::ui::elements::lock_grid::lock_grid;
+
+pub struct LocksPanel {}
+
+impl LocksPanel {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Returns the panel
+    ///
+    /// - `locks`       Parameters keeping their value when a preset is loaded
+    pub fn view(&self, locks: &HashSet<SoundParameter>) -> Element<'_, Message> {
+        let content = lock_grid(locks, Message::PresetLockToggle, |section| {
+            section.to_string()
+        });
+
+        Container::new(
+            Container::new(
+                Column::new()
+                    .push(
+                        Container::new(
+                            Text::new("Parameters kept when loading a preset or syx file")
+                                .size(style::section_label_text_size()),
+                        )
+                        .padding(style::SECTION_PADDING),
+                    )
+                    .push(Scrollable::new(content).height(Length::Fill)),
+            )
+            .style(style::OverviewSection)
+            .width(Length::Fill)
+            .height(Length::Fill),
+        )
+        .padding(10)
+        .height(Length::Fill)
+        .style(style::MainWindow)
+        .into()
+    }
+}
@@ -0,0 +1,195 @@
+//! Panel containing the macro strip and the macro editor
+
+use iced::widget::{Button, Column, Container, PickList, Row, Scrollable, Text, TextInput};
+use iced::{theme, Alignment, Element, Length};
+
+use super::style;
+use crate::macros::{Macro, MacroCc, MacroCurve, MacroSet, MAX_VALUE};
+use crate::messages::Message;
+use crate::params::SoundParameter;
+use crate::ui::elements::slider_widget::Slider;
+
+/// Number of macros per row of the strip
+const MACROS_PER_ROW: usize = 4;
+
+pub struct MacrosPanel {}
+
+impl MacrosPanel {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Returns the panel
+    ///
+    /// - `macros`      Macros of the current sound
+    pub fn view(&self, macros: &MacroSet) -> Element<'_, Message> {
+        let mut strip = Column::new()
+            .padding(style::SECTION_PADDING)
+            .spacing(5)
+            .push(
+                Row::new()
+                    .align_items(Alignment::Center)
+                    .push(
+                        Text::new("Macros")
+                            .size(style::section_label_text_size())
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        Button::new(Text::new("Add macro").size(style::button_text_size()))
+                            .style(theme::Button::Primary)
+                            .on_press(Message::MacroAdd),
+                    ),
+            );
+
+        let indexed: Vec<_> = macros.macros.iter().enumerate().collect();
+        for chunk in indexed.chunks(MACROS_PER_ROW) {
+            let mut row = Row::new().spacing(15);
+            for (index, m) in chunk {
+                row = row.push(macro_control(*index, m));
+            }
+            for _ in chunk.len()..MACROS_PER_ROW {
+                row = row.push(Column::new().width(Length::FillPortion(1)));
+            }
+            strip = strip.push(row);
+        }
+
+        let mut editor = Column::new().padding(style::SECTION_PADDING).spacing(12);
+
+        for (index, m) in macros.macros.iter().enumerate() {
+            editor = editor.push(macro_editor(index, m));
+        }
+
+        Container::new(
+            Column::new()
+                .spacing(10)
+                .push(Container::new(strip).style(style::OverviewSection))
+                .push(
+                    Container::new(Scrollable::new(editor).height(Length::Fill))
+                        .style(style::OverviewSection)
+                        .width(Length::Fill)
+                        .height(Length::Fill),
+                ),
+        )
+        .padding(10)
+        .height(Length::Fill)
+        .style(style::MainWindow)
+        .into()
+    }
+}
+
+/// Returns the control of a macro in the strip
+fn macro_control<'a>(index: usize, m: &Macro) -> Element<'a, Message> {
+    Column::new()
+        .spacing(3)
+        .push(Text::new(format!("{} ({})", m.name, m.cc)).size(style::param_label_text_size()))
+        .push(
+            Row::new()
+                .spacing(5)
+                .align_items(Alignment::Center)
+                .push(
+                    Slider::new(0..=MAX_VALUE, m.value, 0, move |v| {
+                        Message::MacroValueChange(index, v)
+                    })
+                    .on_release(Message::ParameterEditFinished)
+                    .style(style::Slider),
+                )
+                .push(
+                    Text::new(m.value.to_string())
+                        .size(style::param_label_text_size())
+                        .width(style::PARAM_VALUE_WIDTH),
+                ),
+        )
+        .width(Length::FillPortion(1))
+        .into()
+}
+
+/// Returns the settings and targets of a macro
+fn macro_editor<'a>(index: usize, m: &Macro) -> Element<'a, Message> {
+    let mut content = Column::new().spacing(5).push(
+        Row::new()
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .push(
+                TextInput::new("Macro name", &m.name)
+                    .on_input(move |name| Message::MacroNameChange(index, name))
+                    .size(style::param_label_text_size())
+                    .width(200),
+            )
+            .push(
+                PickList::new(MacroCc::all(), Some(m.cc), move |cc| {
+                    Message::MacroCcChange(index, cc)
+                })
+                .style(style::PickList)
+                .text_size(style::list_item_text_size())
+                .width(90),
+            )
+            .push(Column::new().width(Length::Fill))
+            .push(
+                Button::new(Text::new("Add target").size(style::button_text_size()))
+                    .style(theme::Button::Primary)
+                    .on_press(Message::MacroTargetAdd(index)),
+            )
+            .push(
+                Button::new(Text::new("Remove macro").size(style::button_text_size()))
+                    .style(theme::Button::Primary)
+                    .on_press(Message::MacroRemove(index)),
+            ),
+    );
+
+    for (target_index, target) in m.targets.iter().enumerate() {
+        let range = target.param.get_range();
+
+        content = content.push(
+            Row::new()
+                .spacing(8)
+                .align_items(Alignment::Center)
+                .push(
+                    PickList::new(&SoundParameter::ALL[..], Some(target.param), move |param| {
+                        Message::MacroTargetParamChange(index, target_index, param)
+                    })
+                    .style(style::PickList)
+                    .text_size(style::list_item_text_size())
+                    .width(170),
+                )
+                .push(Text::new("Min").size(style::param_label_text_size()))
+                .push(
+                    Slider::new(range.clone(), target.min, *range.start(), move |v| {
+                        Message::MacroTargetMinChange(index, target_index, v)
+                    })
+                    .style(style::Slider),
+                )
+                .push(
+                    Text::new(target.min.to_string())
+                        .size(style::param_label_text_size())
+                        .width(30),
+                )
+                .push(Text::new("Max").size(style::param_label_text_size()))
+                .push(
+                    Slider::new(range.clone(), target.max, *range.end(), move |v| {
+                        Message::MacroTargetMaxChange(index, target_index, v)
+                    })
+                    .style(style::Slider),
+                )
+                .push(
+                    Text::new(target.max.to_string())
+                        .size(style::param_label_text_size())
+                        .width(30),
+                )
+                .push(
+                    PickList::new(&MacroCurve::ALL[..], Some(target.curve), move |curve| {
+                        Message::MacroTargetCurveChange(index, target_index, curve)
+                    })
+                    .style(style::PickList)
+                    .text_size(style::list_item_text_size())
+                    .width(110),
+                )
+                .push(
+                    Button::new(Text::new("Remove").size(style::button_text_size()))
+                        .style(theme::Button::Primary)
+                        .on_press(Message::MacroTargetRemove(index, target_index)),
+                ),
+        );
+    }
+
+    content.into()
+}
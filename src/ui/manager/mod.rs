@@ -27,7 +27,7 @@ impl ManagerPanel {
                 Column::new()
                     .push({
                         let mut button = Button::new(
-                            Text::new("Update from device").size(style::BUTTON_TEXT_SIZE),
+                            Text::new("Update from device").size(style::button_text_size()),
                         )
                         .style(theme::Button::Primary);
                         if device_connected {
@@ -46,7 +46,7 @@ impl ManagerPanel {
                 Column::new()
                     .push({
                         let mut button = Button::new(
-                            Text::new("Load syx file...").size(style::BUTTON_TEXT_SIZE),
+                            Text::new("Load syx file...").size(style::button_text_size()),
                         )
                         .style(theme::Button::Primary);
                         if device_connected {
@@ -60,7 +60,7 @@ impl ManagerPanel {
                 Column::new()
                     .push({
                         let mut button = Button::new(
-                            Text::new("Save syx file...").size(style::BUTTON_TEXT_SIZE),
+                            Text::new("Save syx file...").size(style::button_text_size()),
                         )
                         .style(theme::Button::Primary);
                         if device_connected {
@@ -1,7 +1,22 @@
 //! Module containing the user interface elements
 
+pub mod breeding;
+pub mod command_palette;
+pub mod compare;
 pub mod elements;
+pub mod layout;
+pub mod locks;
+pub mod macros;
 pub mod manager;
+pub mod mod_matrix;
+pub mod monitor;
+pub mod morph;
 pub mod multi;
+pub mod overview;
+pub mod randomizer;
+pub mod sections;
 pub mod sound;
 pub mod style;
+pub mod theme;
+pub mod toolbar;
+pub mod xy_pad;
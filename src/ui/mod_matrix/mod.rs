@@ -0,0 +1,203 @@
+//! Panel showing the modulation routings as a matrix of sources and targets
+
+use iced::alignment;
+use iced::widget::{tooltip, Button, Column, Container, Row, Text, Tooltip};
+use iced::{theme, Alignment, Element, Length};
+
+use super::style;
+use crate::messages::Message;
+use crate::modulation::{self, ModSource, Routing};
+use crate::params::SoundParameterValues;
+use crate::ui::elements::mod_target_list::ModTarget;
+use crate::ui::elements::slider_widget::Slider;
+
+/// Width of the source names
+const SOURCE_WIDTH: u16 = 80;
+
+/// Width of the amount controls
+const AMOUNT_WIDTH: u16 = 150;
+
+/// Height of the matrix cells
+const CELL_HEIGHT: u16 = 24;
+
+/// Text size of the matrix cells
+const CELL_TEXT_SIZE: u16 = 11;
+
+pub struct ModMatrixPanel {}
+
+impl ModMatrixPanel {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Returns the panel
+    ///
+    /// - `params`      Sound parameters of the current part
+    pub fn view(&self, params: &SoundParameterValues) -> Element<'_, Message> {
+        let conflicts = modulation::conflicts(params);
+
+        let mut header = Row::new().spacing(2).align_items(Alignment::Center).push(
+            Text::new("Source")
+                .size(style::param_label_text_size())
+                .width(SOURCE_WIDTH),
+        );
+
+        for target in ModTarget::ALL {
+            header = header.push(
+                Tooltip::new(
+                    Text::new(target.get_short_name())
+                        .size(CELL_TEXT_SIZE)
+                        .horizontal_alignment(alignment::Horizontal::Center)
+                        .width(Length::Fill),
+                    target.to_string(),
+                    tooltip::Position::Top,
+                )
+                .style(style::Tooltip)
+                .size(style::list_item_text_size())
+                .padding(5),
+            );
+        }
+
+        header = header.push(
+            Text::new("Amount")
+                .size(style::param_label_text_size())
+                .horizontal_alignment(alignment::Horizontal::Center)
+                .width(AMOUNT_WIDTH),
+        );
+
+        let mut matrix = Column::new()
+            .padding(style::SECTION_PADDING)
+            .spacing(4)
+            .push(Text::new("Modulation Matrix").size(style::section_label_text_size()))
+            .push(header);
+
+        for routing in modulation::routings(params) {
+            matrix = matrix.push(source_row(routing, &conflicts));
+        }
+
+        let mut warnings = Column::new()
+            .padding(style::SECTION_PADDING)
+            .spacing(4)
+            .push(Text::new("Conflicts").size(style::section_label_text_size()));
+
+        if conflicts.is_empty() {
+            warnings = warnings.push(
+                Text::new("No target is modulated by more than one source")
+                    .size(style::param_label_text_size()),
+            );
+        }
+
+        for (target, sources) in conflicts.iter() {
+            let names: Vec<String> = sources.iter().map(|source| source.to_string()).collect();
+            warnings = warnings.push(
+                Text::new(format!(
+                    "Warning: {} is modulated by {}",
+                    target,
+                    names.join(", ")
+                ))
+                .size(style::param_label_text_size()),
+            );
+        }
+
+        Container::new(
+            Column::new()
+                .spacing(10)
+                .push(Container::new(matrix).style(style::OverviewSection))
+                .push(
+                    Container::new(warnings)
+                        .style(style::OverviewSection)
+                        .width(Length::Fill),
+                ),
+        )
+        .padding(10)
+        .height(Length::Fill)
+        .style(style::MainWindow)
+        .into()
+    }
+}
+
+/// Returns the matrix row of a source
+fn source_row<'a>(
+    routing: Routing,
+    conflicts: &[(ModTarget, Vec<ModSource>)],
+) -> Element<'a, Message> {
+    let source = routing.source;
+    let amount_param = source.amount_param();
+    let range = amount_param.get_range();
+
+    let mut row = Row::new().spacing(2).align_items(Alignment::Center).push(
+        Text::new(source.to_string())
+            .size(style::param_label_text_size())
+            .width(SOURCE_WIDTH),
+    );
+
+    for target in ModTarget::ALL {
+        let routed = routing.target == Some(target);
+        let conflicting = routed
+            && conflicts
+                .iter()
+                .any(|(conflict, sources)| *conflict == target && sources.contains(&source));
+
+        let cell = Button::new(
+            Text::new(if routed {
+                format!("{:+}", routing.amount)
+            } else {
+                String::new()
+            })
+            .size(CELL_TEXT_SIZE)
+            .horizontal_alignment(alignment::Horizontal::Center)
+            .width(Length::Fill),
+        )
+        .padding([5, 0])
+        .width(Length::Fill)
+        .height(CELL_HEIGHT)
+        .style(if conflicting {
+            theme::Button::Destructive
+        } else if routed && routing.is_active() {
+            theme::Button::Positive
+        } else if routed {
+            theme::Button::Primary
+        } else {
+            theme::Button::Secondary
+        })
+        .on_press(Message::SoundParameterChange(
+            source.target_param(),
+            target as i32,
+        ));
+
+        row = row.push(
+            Tooltip::new(
+                cell,
+                format!("{} to {}", source, target),
+                tooltip::Position::Top,
+            )
+            .style(style::Tooltip)
+            .size(style::list_item_text_size())
+            .padding(5),
+        );
+    }
+
+    row.push(
+        Row::new()
+            .spacing(5)
+            .align_items(Alignment::Center)
+            .push(
+                Slider::new(
+                    range,
+                    routing.amount,
+                    amount_param.get_default(),
+                    move |v| Message::SoundParameterChange(amount_param, v),
+                )
+                .on_release(Message::ParameterEditFinished)
+                .style(style::Slider),
+            )
+            .push(
+                Text::new(format!("{:+}", routing.amount))
+                    .size(style::param_label_text_size())
+                    .horizontal_alignment(alignment::Horizontal::Right)
+                    .width(35),
+            )
+            .width(AMOUNT_WIDTH),
+    )
+    .into()
+}
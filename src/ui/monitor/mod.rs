@@ -0,0 +1,157 @@
+//! Panel showing the MIDI traffic between editor and device
+
+use iced::widget::{Button, Checkbox, Column, Container, Row, Scrollable, Text};
+use iced::{theme, Alignment, Element, Length};
+
+use super::style;
+use crate::messages::Message;
+use crate::midi::monitor::{MidiMonitor, MonitorFilter};
+
+/// Maximum number of entries shown in the list
+const MAX_VISIBLE_ENTRIES: usize = 500;
+
+/// Maximum number of bytes shown in the hex column
+const MAX_HEX_BYTES: usize = 12;
+
+pub struct MonitorPanel {}
+
+impl MonitorPanel {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn view(
+        &self,
+        monitor: &MidiMonitor,
+        filter: &MonitorFilter,
+        tracing: bool,
+    ) -> Element<'_, Message> {
+        let filter = *filter;
+
+        let controls = Row::new()
+            .padding(style::SECTION_PADDING)
+            .spacing(15)
+            .align_items(Alignment::Center)
+            .push(filter_checkbox("Out", filter.to_device, move |v| {
+                MonitorFilter {
+                    to_device: v,
+                    ..filter
+                }
+            }))
+            .push(filter_checkbox("In", filter.from_device, move |v| {
+                MonitorFilter {
+                    from_device: v,
+                    ..filter
+                }
+            }))
+            .push(filter_checkbox("Merge", filter.from_merge, move |v| {
+                MonitorFilter {
+                    from_merge: v,
+                    ..filter
+                }
+            }))
+            .push(filter_checkbox("Sysex", filter.sysex, move |v| {
+                MonitorFilter { sysex: v, ..filter }
+            }))
+            .push(filter_checkbox("Other", filter.other, move |v| {
+                MonitorFilter { other: v, ..filter }
+            }))
+            .push(Column::new().width(Length::Fill))
+            .push(
+                Button::new(
+                    Text::new(if monitor.is_paused() {
+                        "Resume"
+                    } else {
+                        "Pause"
+                    })
+                    .size(style::button_text_size()),
+                )
+                .style(theme::Button::Primary)
+                .on_press(Message::MonitorTogglePause),
+            )
+            .push(
+                Button::new(Text::new("Clear").size(style::button_text_size()))
+                    .style(theme::Button::Primary)
+                    .on_press(Message::MonitorClear),
+            )
+            .push(
+                Button::new(Text::new("Export syx...").size(style::button_text_size()))
+                    .style(theme::Button::Primary)
+                    .on_press(Message::MonitorExportSysex),
+            )
+            .push(
+                Button::new(Text::new("Export text...").size(style::button_text_size()))
+                    .style(theme::Button::Primary)
+                    .on_press(Message::MonitorExportText),
+            )
+            .push(
+                Button::new(
+                    Text::new(if tracing {
+                        "Stop trace..."
+                    } else {
+                        "Record trace"
+                    })
+                    .size(style::button_text_size()),
+                )
+                .style(theme::Button::Primary)
+                .on_press(Message::MonitorToggleTrace),
+            );
+
+        let mut entries = Column::new().padding(style::SECTION_PADDING).spacing(2);
+
+        for entry in monitor
+            .entries()
+            .rev()
+            .filter(|entry| filter.matches(entry))
+            .take(MAX_VISIBLE_ENTRIES)
+        {
+            entries = entries.push(
+                Row::new()
+                    .spacing(10)
+                    .push(
+                        Text::new(format!("{:.3}", entry.time.as_secs_f64()))
+                            .size(style::monitor_text_size())
+                            .width(70),
+                    )
+                    .push(
+                        Text::new(entry.direction.to_string())
+                            .size(style::monitor_text_size())
+                            .width(45),
+                    )
+                    .push(
+                        Text::new(entry.description())
+                            .size(style::monitor_text_size())
+                            .width(Length::FillPortion(3)),
+                    )
+                    .push(
+                        Text::new(entry.hex(MAX_HEX_BYTES))
+                            .size(style::monitor_text_size())
+                            .width(Length::FillPortion(2)),
+                    ),
+            );
+        }
+
+        Container::new(
+            Column::new()
+                .spacing(10)
+                .push(Container::new(controls).style(style::MonitorSection))
+                .push(Scrollable::new(entries).height(Length::Fill)),
+        )
+        .padding(10)
+        .height(Length::Fill)
+        .style(style::MainWindow)
+        .into()
+    }
+}
+
+/// Returns a checkbox toggling one of the filter settings
+fn filter_checkbox<'a>(
+    label: &str,
+    value: bool,
+    f: impl Fn(bool) -> MonitorFilter + 'a,
+) -> Checkbox<'a, Message> {
+    Checkbox::new(label, value, move |v| Message::MonitorFilterChange(f(v)))
+        .style(style::Checkbox)
+        .text_size(style::list_item_text_size())
+        .spacing(7)
+}
@@ -0,0 +1,132 @@
+//! Panel for morphing between two sounds
+
+use iced::widget::{Button, Column, Container, Row, Text};
+use iced::{theme, Alignment, Element, Length};
+
+use super::style;
+use crate::messages::Message;
+use crate::morph::{Morph, MorphEnd, MorphSource, MAX_POSITION};
+use crate::ui::elements::preset_list::stored_preset_list;
+use crate::ui::elements::slider_widget::Slider;
+
+pub struct MorphPanel {}
+
+impl MorphPanel {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Returns the panel
+    ///
+    /// - `morph`           State of the morph
+    /// - `preset_names`    Names of the stored presets
+    pub fn view(&self, morph: &Morph, preset_names: &[String]) -> Element<'_, Message> {
+        let position = Row::new()
+            .padding(style::SECTION_PADDING)
+            .spacing(15)
+            .align_items(Alignment::Center)
+            .push(
+                Text::new("Position")
+                    .size(style::param_label_text_size())
+                    .width(style::PARAM_LABEL_WIDTH),
+            )
+            .push(
+                Slider::new(
+                    0..=MAX_POSITION,
+                    morph.position,
+                    0,
+                    Message::MorphPositionChange,
+                )
+                .on_release(Message::ParameterEditFinished)
+                .style(style::Slider),
+            )
+            .push(
+                Text::new(format!("{}%", morph.position))
+                    .size(style::param_label_text_size())
+                    .width(40),
+            );
+
+        let mut content = Column::new()
+            .spacing(10)
+            .push(point_row("Start", MorphEnd::Start, morph, preset_names))
+            .push(point_row("End", MorphEnd::End, morph, preset_names));
+
+        content = if morph.start.is_some() && morph.end.is_some() {
+            content.push(Container::new(position).style(style::OverviewSection))
+        } else {
+            content.push(
+                Container::new(
+                    Text::new("Select start and end sound to enable morphing.")
+                        .size(style::param_label_text_size()),
+                )
+                .padding(style::SECTION_PADDING)
+                .width(Length::Fill)
+                .style(style::OverviewSection),
+            )
+        };
+
+        Container::new(content)
+            .padding(10)
+            .height(Length::Fill)
+            .style(style::MainWindow)
+            .into()
+    }
+}
+
+/// Returns a row showing a morph point with the buttons to select it
+fn point_row<'a>(
+    label: &'a str,
+    end: MorphEnd,
+    morph: &Morph,
+    preset_names: &[String],
+) -> Container<'a, Message> {
+    let point = match end {
+        MorphEnd::Start => morph.start.as_ref(),
+        MorphEnd::End => morph.end.as_ref(),
+    };
+    let requested = match morph.requested {
+        Some((requested_end, preset_id)) if requested_end == end => Some(preset_id),
+        _ => None,
+    };
+
+    let row = Row::new()
+        .padding(style::SECTION_PADDING)
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(
+            Text::new(label)
+                .size(style::section_label_text_size())
+                .width(style::PARAM_LABEL_WIDTH),
+        )
+        .push(
+            Text::new(match (requested, point) {
+                (Some(preset_id), _) => format!("Requesting preset {:02}...", preset_id),
+                (None, Some(point)) => point.label.clone(),
+                (None, None) => String::from("-"),
+            })
+            .size(style::param_label_text_size())
+            .width(Length::Fill),
+        )
+        .push(source_button("Slot A", end, MorphSource::SlotA))
+        .push(source_button("Slot B", end, MorphSource::SlotB))
+        .push(source_button("Current", end, MorphSource::Current))
+        .push(
+            Column::new()
+                .push(stored_preset_list(
+                    requested.map(i32::from),
+                    preset_names,
+                    move |id| Message::MorphSetPoint(end, MorphSource::Stored(id as u8)),
+                ))
+                .width(200),
+        )
+        .push(source_button("Load syx file...", end, MorphSource::File));
+
+    Container::new(row).style(style::OverviewSection)
+}
+
+/// Returns a button selecting the source of a morph point
+fn source_button<'a>(text: &'a str, end: MorphEnd, source: MorphSource) -> Button<'a, Message> {
+    Button::new(Text::new(text).size(style::button_text_size()))
+        .style(theme::Button::Primary)
+        .on_press(Message::MorphSetPoint(end, source))
+}
@@ -17,7 +17,7 @@ impl FXSection {
 
     pub fn view(&self, params: &MultiParameterValues) -> Element<Message> {
         let content = Column::new()
-            .push(Text::new("FX").size(style::SECTION_LABEL_TEXT_SIZE))
+            .push(Text::new("FX").size(style::section_label_text_size()))
             .padding(style::SECTION_PADDING)
             .spacing(style::SECTION_SPACING)
             .push(fx_mode_list(
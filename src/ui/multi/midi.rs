@@ -17,7 +17,7 @@ impl MidiSection {
 
     pub fn view(&self, params: &MultiParameterValues) -> Element<Message> {
         let content = Column::new()
-            .push(Text::new("MIDI").size(style::SECTION_LABEL_TEXT_SIZE))
+            .push(Text::new("MIDI").size(style::section_label_text_size()))
             .padding(style::SECTION_PADDING)
             .spacing(style::SECTION_SPACING)
             .push(midi_channel_list(
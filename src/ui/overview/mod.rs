@@ -0,0 +1,133 @@
+//! Panel showing the key parameters of all four parts side by side
+
+use iced::widget::{Checkbox, Column, Container, Row, Text};
+use iced::{Alignment, Element, Length};
+
+use super::style;
+use crate::messages::Message;
+use crate::modulation;
+use crate::params::{
+    GetValue, MultiParameter, MultiParameterValues, SoundParameter, SoundParameterValues,
+};
+use crate::ui::elements::link_mode_list::{link_mode_list, PartLink};
+use crate::ui::elements::preset_list::preset_list;
+use crate::ui::elements::slider::slider_with_labels;
+use crate::ui::elements::value_entry::ValueTarget;
+
+/// Parameters shown for each part with their labels
+const KEY_PARAMS: [(&str, SoundParameter); 11] = [
+    ("Osc 1", SoundParameter::Osc1Level),
+    ("Osc 2", SoundParameter::Osc2Level),
+    ("Cutoff", SoundParameter::FilterCutoff),
+    ("Reso", SoundParameter::FilterResonance),
+    ("Env F", SoundParameter::FilterEnvFAmount),
+    ("Attack", SoundParameter::EnvAAttack),
+    ("Decay", SoundParameter::EnvADecay),
+    ("Sustain", SoundParameter::EnvASustain),
+    ("Release", SoundParameter::EnvARelease),
+    ("Level", SoundParameter::AmpLevel),
+    ("Pan", SoundParameter::AmpPan),
+];
+
+pub struct OverviewPanel {}
+
+impl OverviewPanel {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Returns the panel
+    ///
+    /// - `part_params`     Sound parameters of all parts
+    /// - `part_names`      Preset names of all parts
+    /// - `multi_params`    Multi parameters containing the preset assignments
+    /// - `preset_names`    Names of the stored presets
+    /// - `part_link`       Settings for linked editing
+    pub fn view<'a>(
+        &self,
+        part_params: &[SoundParameterValues],
+        part_names: &'a [String],
+        multi_params: &MultiParameterValues,
+        preset_names: &[String],
+        part_link: &PartLink,
+    ) -> Element<'a, Message> {
+        let controls = Row::new()
+            .padding(style::SECTION_PADDING)
+            .spacing(15)
+            .align_items(Alignment::Center)
+            .push(
+                Checkbox::new("Link parts", part_link.enabled, Message::LinkEnable)
+                    .style(style::Checkbox)
+                    .text_size(style::list_item_text_size())
+                    .spacing(7),
+            )
+            .push(link_mode_list(part_link.mode));
+
+        let mut parts = Row::new().spacing(10);
+
+        for (part_id, (params, name)) in part_params.iter().zip(part_names.iter()).enumerate() {
+            let part_id = part_id as u8;
+
+            let mut content = Column::new()
+                .padding(style::SECTION_PADDING)
+                .spacing(style::SECTION_SPACING)
+                .push(
+                    Row::new()
+                        .align_items(Alignment::Center)
+                        .push(
+                            Text::new(format!("Part {}", part_id + 1))
+                                .size(style::section_label_text_size())
+                                .width(Length::Fill),
+                        )
+                        .push(
+                            Checkbox::new("Link", part_link.parts[part_id as usize], move |v| {
+                                Message::LinkPartToggle(part_id, v)
+                            })
+                            .style(style::Checkbox)
+                            .text_size(style::list_item_text_size())
+                            .spacing(5),
+                        ),
+                )
+                .push(
+                    Text::new(name.trim_end())
+                        .size(style::param_label_text_size())
+                        .height(22),
+                )
+                .push({
+                    let preset_param = MultiParameter::from_preset_part(part_id);
+                    Container::new(preset_list(
+                        preset_param,
+                        multi_params.get_value(preset_param),
+                        preset_names,
+                    ))
+                    .padding([0, 0, 5, 0])
+                });
+
+            for (label, param) in KEY_PARAMS {
+                content = content.push(slider_with_labels(
+                    label,
+                    ValueTarget::Part(part_id, param),
+                    params.get_value(param),
+                    &modulation::modulations_of(params, param),
+                    false,
+                ));
+            }
+
+            parts = parts.push(
+                Container::new(content)
+                    .style(style::OverviewSection)
+                    .width(Length::FillPortion(1)),
+            );
+        }
+
+        Container::new(
+            Column::new()
+                .spacing(10)
+                .push(Container::new(controls).style(style::OverviewSection))
+                .push(parts),
+        )
+        .padding(5)
+        .height(Length::Fill)
+        .into()
+    }
+}
@@ -0,0 +1,102 @@
+//! Panel containing the randomizer settings and parameter locks
+
+use iced::widget::{Button, Column, Container, PickList, Row, Scrollable, Text};
+use iced::{theme, Alignment, Element, Length};
+
+use super::style;
+use crate::messages::Message;
+use crate::randomizer::{RandomizeScope, Randomizer};
+use crate::ui::elements::lock_grid::lock_grid;
+use crate::ui::elements::slider_widget::Slider;
+
+pub struct RandomizerPanel {}
+
+impl RandomizerPanel {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Returns the panel
+    ///
+    /// - `randomizer`  Randomizer settings and locks
+    pub fn view(&self, randomizer: &Randomizer) -> Element<'_, Message> {
+        let controls = Row::new()
+            .padding(style::SECTION_PADDING)
+            .spacing(15)
+            .align_items(Alignment::Center)
+            .push(Text::new("Scope").size(style::param_label_text_size()))
+            .push(
+                PickList::new(
+                    RandomizeScope::all(),
+                    Some(randomizer.scope),
+                    Message::RandomizerScopeChange,
+                )
+                .style(style::PickList)
+                .text_size(style::list_item_text_size())
+                .width(150),
+            )
+            .push(Text::new("Amount").size(style::param_label_text_size()))
+            .push(
+                Column::new()
+                    .push(
+                        Slider::new(
+                            0..=100,
+                            randomizer.amount,
+                            100,
+                            Message::RandomizerAmountChange,
+                        )
+                        .style(style::Slider),
+                    )
+                    .width(200),
+            )
+            .push(
+                Text::new(format!("{}%", randomizer.amount))
+                    .size(style::param_label_text_size())
+                    .width(40),
+            )
+            .push(Column::new().width(Length::Fill))
+            .push(
+                Button::new(Text::new("Randomize").size(style::button_text_size()))
+                    .style(theme::Button::Primary)
+                    .on_press(Message::Randomize),
+            );
+
+        let locks = lock_grid(
+            &randomizer.locks,
+            Message::RandomizerLockToggle,
+            |section| {
+                if randomizer.scope.contains_section(section) {
+                    section.to_string()
+                } else {
+                    format!("{} (not in scope)", section)
+                }
+            },
+        );
+
+        Container::new(
+            Column::new()
+                .spacing(10)
+                .push(Container::new(controls).style(style::OverviewSection))
+                .push(
+                    Container::new(
+                        Column::new()
+                            .push(
+                                Container::new(
+                                    Text::new("Locked parameters")
+                                        .size(style::section_label_text_size()),
+                                )
+                                .padding(style::SECTION_PADDING),
+                            )
+                            .push(Scrollable::new(locks).height(Length::Fill)),
+                    )
+                    .style(style::OverviewSection)
+                    .width(Length::Fill)
+                    .height(Length::Fill),
+                ),
+        )
+        .padding(10)
+        .height(Length::Fill)
+        .style(style::MainWindow)
+        .into()
+    }
+}
@@ -0,0 +1,95 @@
+//! Panel containing the copy and swap operations for sections and sounds
+
+use iced::widget::{Column, Container, Row, Text};
+use iced::{Alignment, Element, Length};
+
+use super::style;
+use crate::messages::Message;
+use crate::params::{SoundSection, PART_COUNT};
+use crate::ui::elements::text_button::text_button;
+
+pub struct SectionsPanel {}
+
+impl SectionsPanel {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Returns the panel
+    ///
+    /// - `part_id`     Current part id 0-3
+    pub fn view(&self, part_id: u8) -> Element<'_, Message> {
+        let mut content = Column::new()
+            .padding(style::SECTION_PADDING)
+            .spacing(5)
+            .push(
+                Text::new(format!("Sections of part {}", part_id + 1))
+                    .size(style::section_label_text_size()),
+            );
+
+        for section in SoundSection::ALL {
+            let mut row = Row::new().spacing(10).align_items(Alignment::Center).push(
+                Text::new(section.to_string())
+                    .size(style::param_label_text_size())
+                    .width(90),
+            );
+
+            row = match section.get_twin() {
+                Some(twin) => row
+                    .push(
+                        text_button(
+                            format!("Copy to {}", twin),
+                            Some(Message::SectionCopyToTwin(section)),
+                        )
+                        .width(140),
+                    )
+                    .push(
+                        text_button(
+                            format!("Swap with {}", twin),
+                            Some(Message::SectionSwapWithTwin(section)),
+                        )
+                        .width(140),
+                    ),
+                None => row.push(Column::new().width(290)),
+            };
+
+            row = row.push(Column::new().width(Length::Fill));
+
+            for target in 0..PART_COUNT as u8 {
+                row = row.push(text_button(
+                    format!("To part {}", target + 1),
+                    (target != part_id).then_some(Message::SectionCopyToPart(section, target)),
+                ));
+            }
+
+            content = content.push(row);
+        }
+
+        let mut sound_row = Row::new()
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .push(Text::new("Entire sound").size(style::param_label_text_size()))
+            .push(Column::new().width(Length::Fill));
+
+        for target in 0..PART_COUNT as u8 {
+            sound_row = sound_row.push(text_button(
+                format!("To part {}", target + 1),
+                (target != part_id).then_some(Message::SoundCopyToPart(target)),
+            ));
+        }
+
+        Container::new(
+            Column::new()
+                .spacing(10)
+                .push(Container::new(content).style(style::OverviewSection))
+                .push(
+                    Container::new(sound_row.padding(style::SECTION_PADDING))
+                        .style(style::OverviewSection),
+                ),
+        )
+        .padding(10)
+        .height(Length::Fill)
+        .style(style::MainWindow)
+        .into()
+    }
+}
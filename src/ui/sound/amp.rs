@@ -4,8 +4,8 @@ use iced::widget::{Column, Container, Text};
 use iced::Element;
 
 use crate::messages::Message;
-use crate::params::{GetValue, SoundParameter, SoundParameterValues};
-use crate::ui::elements::slider::slider_with_labels;
+use crate::params::{SoundParameter, SoundParameterValues};
+use crate::ui::elements::{control_style_list::ControlStyle, slider::sound_controls};
 use crate::ui::style;
 
 pub struct AmpSection {}
@@ -15,20 +15,24 @@ impl AmpSection {
         Self {}
     }
 
-    pub fn view(&self, params: &SoundParameterValues) -> Element<Message> {
+    pub fn view(
+        &self,
+        params: &SoundParameterValues,
+        controls: ControlStyle,
+        focus: Option<SoundParameter>,
+    ) -> Element<Message> {
         let content = Column::new()
-            .push(Text::new("Amp").size(style::SECTION_LABEL_TEXT_SIZE))
+            .push(Text::new("Amp").size(style::section_label_text_size()))
             .padding(style::SECTION_PADDING)
             .spacing(style::SECTION_SPACING)
-            .push(slider_with_labels(
-                "Level",
-                SoundParameter::AmpLevel,
-                params.get_value(SoundParameter::AmpLevel),
-            ))
-            .push(slider_with_labels(
-                "Pan",
-                SoundParameter::AmpPan,
-                params.get_value(SoundParameter::AmpPan),
+            .push(sound_controls(
+                &[
+                    ("Level", SoundParameter::AmpLevel),
+                    ("Pan", SoundParameter::AmpPan),
+                ],
+                params,
+                controls,
+                focus,
             ));
         Container::new(content).style(style::AmpSection).into()
     }
@@ -7,7 +7,7 @@ use crate::messages::Message;
 use crate::params::{GetValue, SoundParameter, SoundParameterValues};
 use crate::ui::elements::{
     arp_grid_list::arp_grid_list, arp_mode_list::arp_mode_list, checkbox::checkbox_with_labels,
-    slider::slider_with_labels,
+    control_style_list::ControlStyle, slider::sound_controls,
 };
 use crate::ui::style;
 
@@ -18,9 +18,14 @@ impl ArpSection {
         Self {}
     }
 
-    pub fn view(&self, params: &SoundParameterValues) -> Element<Message> {
+    pub fn view(
+        &self,
+        params: &SoundParameterValues,
+        controls: ControlStyle,
+        focus: Option<SoundParameter>,
+    ) -> Element<Message> {
         let content = Column::new()
-            .push(Text::new("Arp").size(style::SECTION_LABEL_TEXT_SIZE))
+            .push(Text::new("Arp").size(style::section_label_text_size()))
             .padding(style::SECTION_PADDING)
             .spacing(style::SECTION_SPACING)
             .push(arp_mode_list(
@@ -33,10 +38,11 @@ impl ArpSection {
                 SoundParameter::ArpGrid,
                 params.get_value(SoundParameter::ArpGrid),
             ))
-            .push(slider_with_labels(
-                "Tempo",
-                SoundParameter::ArpTempo,
-                params.get_value(SoundParameter::ArpTempo),
+            .push(sound_controls(
+                &[("Tempo", SoundParameter::ArpTempo)],
+                params,
+                controls,
+                focus,
             ))
             .push(checkbox_with_labels(
                 "",
@@ -1,13 +1,16 @@
 //! Section containing the amp envelope parameters
 
-use iced::widget::{Column, Container, Rule, Text};
+use iced::widget::{Column, Container, Rule};
 use iced::Element;
 
 use crate::messages::Message;
 use crate::params::{GetValue, SoundParameter, SoundParameterValues};
 use crate::ui::elements::{
-    env_trigger_list::env_trigger_list, mod_target_list::mod_target_list,
-    slider::slider_with_labels,
+    control_style_list::ControlStyle,
+    env_graph::{env_graph, ENV_A},
+    env_trigger_list::env_trigger_list,
+    mod_target_list::mod_target_list,
+    slider::sound_controls,
 };
 use crate::ui::style;
 
@@ -18,45 +21,29 @@ impl EnvASection {
         Self {}
     }
 
-    pub fn view(&self, params: &SoundParameterValues) -> Element<Message> {
+    pub fn view(
+        &self,
+        params: &SoundParameterValues,
+        controls: ControlStyle,
+        focus: Option<SoundParameter>,
+    ) -> Element<Message> {
         let content = Column::new()
-            .push(Text::new("Env A").size(style::SECTION_LABEL_TEXT_SIZE))
+            .push(env_graph("Env A", &ENV_A, params))
             .padding(style::SECTION_PADDING)
             .spacing(style::SECTION_SPACING)
-            .push(slider_with_labels(
-                "Attack",
-                SoundParameter::EnvAAttack,
-                params.get_value(SoundParameter::EnvAAttack),
-            ))
-            .push(slider_with_labels(
-                "Hold",
-                SoundParameter::EnvAHold,
-                params.get_value(SoundParameter::EnvAHold),
-            ))
-            .push(slider_with_labels(
-                "Decay",
-                SoundParameter::EnvADecay,
-                params.get_value(SoundParameter::EnvADecay),
-            ))
-            .push(slider_with_labels(
-                "Sustain",
-                SoundParameter::EnvASustain,
-                params.get_value(SoundParameter::EnvASustain),
-            ))
-            .push(slider_with_labels(
-                "Release",
-                SoundParameter::EnvARelease,
-                params.get_value(SoundParameter::EnvARelease),
-            ))
-            .push(slider_with_labels(
-                "Velo",
-                SoundParameter::EnvAVelo,
-                params.get_value(SoundParameter::EnvAVelo),
-            ))
-            .push(slider_with_labels(
-                "After",
-                SoundParameter::EnvAAfter,
-                params.get_value(SoundParameter::EnvAAfter),
+            .push(sound_controls(
+                &[
+                    ("Attack", SoundParameter::EnvAAttack),
+                    ("Hold", SoundParameter::EnvAHold),
+                    ("Decay", SoundParameter::EnvADecay),
+                    ("Sustain", SoundParameter::EnvASustain),
+                    ("Release", SoundParameter::EnvARelease),
+                    ("Velo", SoundParameter::EnvAVelo),
+                    ("After", SoundParameter::EnvAAfter),
+                ],
+                params,
+                controls,
+                focus,
             ))
             .push(env_trigger_list(
                 "Trigger",
@@ -69,10 +56,11 @@ impl EnvASection {
                 SoundParameter::ModEnvATarget,
                 params.get_value(SoundParameter::ModEnvATarget),
             ))
-            .push(slider_with_labels(
-                "Mod Amt",
-                SoundParameter::ModEnvAAmount,
-                params.get_value(SoundParameter::ModEnvAAmount),
+            .push(sound_controls(
+                &[("Mod Amt", SoundParameter::ModEnvAAmount)],
+                params,
+                controls,
+                focus,
             ));
         Container::new(content).style(style::EnvSection).into()
     }
@@ -1,13 +1,16 @@
 //! Section containing the filter envelope parameters
 
-use iced::widget::{Column, Container, Rule, Text};
+use iced::widget::{Column, Container, Rule};
 use iced::Element;
 
 use crate::messages::Message;
 use crate::params::{GetValue, SoundParameter, SoundParameterValues};
 use crate::ui::elements::{
-    env_trigger_list::env_trigger_list, mod_target_list::mod_target_list,
-    slider::slider_with_labels,
+    control_style_list::ControlStyle,
+    env_graph::{env_graph, ENV_F},
+    env_trigger_list::env_trigger_list,
+    mod_target_list::mod_target_list,
+    slider::sound_controls,
 };
 use crate::ui::style;
 
@@ -18,45 +21,29 @@ impl EnvFSection {
         Self {}
     }
 
-    pub fn view(&self, params: &SoundParameterValues) -> Element<Message> {
+    pub fn view(
+        &self,
+        params: &SoundParameterValues,
+        controls: ControlStyle,
+        focus: Option<SoundParameter>,
+    ) -> Element<Message> {
         let content = Column::new()
-            .push(Text::new("Env F").size(style::SECTION_LABEL_TEXT_SIZE))
+            .push(env_graph("Env F", &ENV_F, params))
             .padding(style::SECTION_PADDING)
             .spacing(style::SECTION_SPACING)
-            .push(slider_with_labels(
-                "Attack",
-                SoundParameter::EnvFAttack,
-                params.get_value(SoundParameter::EnvFAttack),
-            ))
-            .push(slider_with_labels(
-                "Hold",
-                SoundParameter::EnvFHold,
-                params.get_value(SoundParameter::EnvFHold),
-            ))
-            .push(slider_with_labels(
-                "Decay",
-                SoundParameter::EnvFDecay,
-                params.get_value(SoundParameter::EnvFDecay),
-            ))
-            .push(slider_with_labels(
-                "Sustain",
-                SoundParameter::EnvFSustain,
-                params.get_value(SoundParameter::EnvFSustain),
-            ))
-            .push(slider_with_labels(
-                "Release",
-                SoundParameter::EnvFRelease,
-                params.get_value(SoundParameter::EnvFRelease),
-            ))
-            .push(slider_with_labels(
-                "Velo",
-                SoundParameter::EnvFVelo,
-                params.get_value(SoundParameter::EnvFVelo),
-            ))
-            .push(slider_with_labels(
-                "After",
-                SoundParameter::EnvFAfter,
-                params.get_value(SoundParameter::EnvFAfter),
+            .push(sound_controls(
+                &[
+                    ("Attack", SoundParameter::EnvFAttack),
+                    ("Hold", SoundParameter::EnvFHold),
+                    ("Decay", SoundParameter::EnvFDecay),
+                    ("Sustain", SoundParameter::EnvFSustain),
+                    ("Release", SoundParameter::EnvFRelease),
+                    ("Velo", SoundParameter::EnvFVelo),
+                    ("After", SoundParameter::EnvFAfter),
+                ],
+                params,
+                controls,
+                focus,
             ))
             .push(env_trigger_list(
                 "Trigger",
@@ -69,10 +56,11 @@ impl EnvFSection {
                 SoundParameter::ModEnvFTarget,
                 params.get_value(SoundParameter::ModEnvFTarget),
             ))
-            .push(slider_with_labels(
-                "Mod Amt",
-                SoundParameter::ModEnvFAmount,
-                params.get_value(SoundParameter::ModEnvFAmount),
+            .push(sound_controls(
+                &[("Mod Amt", SoundParameter::ModEnvFAmount)],
+                params,
+                controls,
+                focus,
             ));
         Container::new(content).style(style::EnvSection).into()
     }
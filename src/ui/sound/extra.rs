@@ -4,8 +4,8 @@ use iced::widget::{Column, Container, Text};
 use iced::Element;
 
 use crate::messages::Message;
-use crate::params::{GetValue, SoundParameter, SoundParameterValues};
-use crate::ui::elements::slider::slider_with_labels;
+use crate::params::{SoundParameter, SoundParameterValues};
+use crate::ui::elements::{control_style_list::ControlStyle, slider::sound_controls};
 use crate::ui::style;
 
 pub struct ExtraSection {}
@@ -15,20 +15,24 @@ impl ExtraSection {
         Self {}
     }
 
-    pub fn view(&self, params: &SoundParameterValues) -> Element<Message> {
+    pub fn view(
+        &self,
+        params: &SoundParameterValues,
+        controls: ControlStyle,
+        focus: Option<SoundParameter>,
+    ) -> Element<Message> {
         let content = Column::new()
-            .push(Text::new("Extra").size(style::SECTION_LABEL_TEXT_SIZE))
+            .push(Text::new("Extra").size(style::section_label_text_size()))
             .padding(style::SECTION_PADDING)
             .spacing(style::SECTION_SPACING)
-            .push(slider_with_labels(
-                "Noise",
-                SoundParameter::ExtraNoise,
-                params.get_value(SoundParameter::ExtraNoise),
-            ))
-            .push(slider_with_labels(
-                "O1xO2",
-                SoundParameter::ExtraRingMod,
-                params.get_value(SoundParameter::ExtraRingMod),
+            .push(sound_controls(
+                &[
+                    ("Noise", SoundParameter::ExtraNoise),
+                    ("O1xO2", SoundParameter::ExtraRingMod),
+                ],
+                params,
+                controls,
+                focus,
             ));
         Container::new(content).style(style::ExtraSection).into()
     }
@@ -1,11 +1,15 @@
 //! Section containing the filter parameters
 
-use iced::widget::{Column, Container, Text};
-use iced::Element;
+use iced::widget::{Column, Container};
+use iced::{Element, Length};
 
 use crate::messages::Message;
-use crate::params::{GetValue, SoundParameter, SoundParameterValues};
-use crate::ui::elements::slider::slider_with_labels;
+use crate::params::{SoundParameter, SoundParameterValues};
+use crate::ui::elements::{
+    control_style_list::ControlStyle,
+    filter_graph::{filter_graph, FILTER},
+    slider::sound_controls,
+};
 use crate::ui::style;
 
 pub struct FilterSection {}
@@ -15,41 +19,34 @@ impl FilterSection {
         Self {}
     }
 
-    pub fn view(&self, params: &SoundParameterValues) -> Element<Message> {
+    pub fn view(
+        &self,
+        params: &SoundParameterValues,
+        controls: ControlStyle,
+        focus: Option<SoundParameter>,
+    ) -> Element<Message> {
         let content = Column::new()
-            .push(Text::new("Filter").size(style::SECTION_LABEL_TEXT_SIZE))
+            .push(filter_graph("Filter", &FILTER, params))
             .padding(style::SECTION_PADDING)
             .spacing(style::SECTION_SPACING)
-            .height(169)
-            .push(slider_with_labels(
-                "Cutoff",
-                SoundParameter::FilterCutoff,
-                params.get_value(SoundParameter::FilterCutoff),
-            ))
-            .push(slider_with_labels(
-                "Resonance",
-                SoundParameter::FilterResonance,
-                params.get_value(SoundParameter::FilterResonance),
-            ))
-            .push(slider_with_labels(
-                "Env F Amt",
-                SoundParameter::FilterEnvFAmount,
-                params.get_value(SoundParameter::FilterEnvFAmount),
-            ))
-            .push(slider_with_labels(
-                "Track",
-                SoundParameter::FilterTrack,
-                params.get_value(SoundParameter::FilterTrack),
-            ))
-            .push(slider_with_labels(
-                "After",
-                SoundParameter::FilterAfter,
-                params.get_value(SoundParameter::FilterAfter),
-            ))
-            .push(slider_with_labels(
-                "LFO 1 Amt",
-                SoundParameter::FilterLFO1Amount,
-                params.get_value(SoundParameter::FilterLFO1Amount),
+            .height(match controls {
+                ControlStyle::Slider => {
+                    Length::from(169 + style::GRAPH_HEIGHT - style::section_label_text_size())
+                }
+                _ => Length::Shrink,
+            })
+            .push(sound_controls(
+                &[
+                    ("Cutoff", SoundParameter::FilterCutoff),
+                    ("Resonance", SoundParameter::FilterResonance),
+                    ("Env F Amt", SoundParameter::FilterEnvFAmount),
+                    ("Track", SoundParameter::FilterTrack),
+                    ("After", SoundParameter::FilterAfter),
+                    ("LFO 1 Amt", SoundParameter::FilterLFO1Amount),
+                ],
+                params,
+                controls,
+                focus,
             ));
         Container::new(content).style(style::FilterSection).into()
     }
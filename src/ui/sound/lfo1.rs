@@ -1,13 +1,19 @@
 //! Section containing the LFO 1 parameters
 
-use iced::widget::{Column, Container, Rule, Text};
+use std::time::Instant;
+
+use iced::widget::{Column, Container, Rule};
 use iced::Element;
 
 use crate::messages::Message;
 use crate::params::{GetValue, SoundParameter, SoundParameterValues};
 use crate::ui::elements::{
-    lfo_phase_list::lfo_phase_list, lfo_shape_list::lfo_shape_list,
-    mod_target_list::mod_target_list, slider::slider_with_labels,
+    control_style_list::ControlStyle,
+    lfo_graph::{lfo_graph, LFO_1},
+    lfo_phase_list::lfo_phase_list,
+    lfo_shape_list::lfo_shape_list,
+    mod_target_list::mod_target_list,
+    slider::sound_controls,
 };
 use crate::ui::style;
 
@@ -18,9 +24,21 @@ impl LFO1Section {
         Self {}
     }
 
-    pub fn view(&self, params: &SoundParameterValues) -> Element<Message> {
+    /// Returns the section
+    ///
+    /// - `params`      Current sound parameters
+    /// - `start`       Start time of the LFO preview animation
+    /// - `controls`    Style of the parameter controls
+    /// - `focus`       Parameter with keyboard focus
+    pub fn view(
+        &self,
+        params: &SoundParameterValues,
+        start: Instant,
+        controls: ControlStyle,
+        focus: Option<SoundParameter>,
+    ) -> Element<Message> {
         let content = Column::new()
-            .push(Text::new("LFO 1").size(style::SECTION_LABEL_TEXT_SIZE))
+            .push(lfo_graph("LFO 1", &LFO_1, params, start))
             .padding(style::SECTION_PADDING)
             .spacing(style::SECTION_SPACING)
             .push(lfo_shape_list(
@@ -28,15 +46,14 @@ impl LFO1Section {
                 SoundParameter::LFO1Shape,
                 params.get_value(SoundParameter::LFO1Shape),
             ))
-            .push(slider_with_labels(
-                "Speed",
-                SoundParameter::LFO1Speed,
-                params.get_value(SoundParameter::LFO1Speed),
-            ))
-            .push(slider_with_labels(
-                "Rise",
-                SoundParameter::LFO1Rise,
-                params.get_value(SoundParameter::LFO1Rise),
+            .push(sound_controls(
+                &[
+                    ("Speed", SoundParameter::LFO1Speed),
+                    ("Rise", SoundParameter::LFO1Rise),
+                ],
+                params,
+                controls,
+                focus,
             ))
             .push(lfo_phase_list(
                 "Phase",
@@ -49,10 +66,11 @@ impl LFO1Section {
                 SoundParameter::ModLFO1Target,
                 params.get_value(SoundParameter::ModLFO1Target),
             ))
-            .push(slider_with_labels(
-                "Mod Amt",
-                SoundParameter::ModLFO1Amount,
-                params.get_value(SoundParameter::ModLFO1Amount),
+            .push(sound_controls(
+                &[("Mod Amt", SoundParameter::ModLFO1Amount)],
+                params,
+                controls,
+                focus,
             ));
         Container::new(content).style(style::LFOSection).into()
     }
@@ -1,13 +1,19 @@
 //! Section containing the LFO 2 parameters
 
-use iced::widget::{Column, Container, Rule, Text};
+use std::time::Instant;
+
+use iced::widget::{Column, Container, Rule};
 use iced::Element;
 
 use crate::messages::Message;
 use crate::params::{GetValue, SoundParameter, SoundParameterValues};
 use crate::ui::elements::{
-    lfo_phase_list::lfo_phase_list, lfo_shape_list::lfo_shape_list,
-    mod_target_list::mod_target_list, slider::slider_with_labels,
+    control_style_list::ControlStyle,
+    lfo_graph::{lfo_graph, LFO_2},
+    lfo_phase_list::lfo_phase_list,
+    lfo_shape_list::lfo_shape_list,
+    mod_target_list::mod_target_list,
+    slider::sound_controls,
 };
 use crate::ui::style;
 
@@ -18,9 +24,21 @@ impl LFO2Section {
         Self {}
     }
 
-    pub fn view(&self, params: &SoundParameterValues) -> Element<Message> {
+    /// Returns the section
+    ///
+    /// - `params`      Current sound parameters
+    /// - `start`       Start time of the LFO preview animation
+    /// - `controls`    Style of the parameter controls
+    /// - `focus`       Parameter with keyboard focus
+    pub fn view(
+        &self,
+        params: &SoundParameterValues,
+        start: Instant,
+        controls: ControlStyle,
+        focus: Option<SoundParameter>,
+    ) -> Element<Message> {
         let content = Column::new()
-            .push(Text::new("LFO 2").size(style::SECTION_LABEL_TEXT_SIZE))
+            .push(lfo_graph("LFO 2", &LFO_2, params, start))
             .padding(style::SECTION_PADDING)
             .spacing(style::SECTION_SPACING)
             .push(lfo_shape_list(
@@ -28,15 +46,14 @@ impl LFO2Section {
                 SoundParameter::LFO2Shape,
                 params.get_value(SoundParameter::LFO2Shape),
             ))
-            .push(slider_with_labels(
-                "Speed",
-                SoundParameter::LFO2Speed,
-                params.get_value(SoundParameter::LFO2Speed),
-            ))
-            .push(slider_with_labels(
-                "Rise",
-                SoundParameter::LFO2Rise,
-                params.get_value(SoundParameter::LFO2Rise),
+            .push(sound_controls(
+                &[
+                    ("Speed", SoundParameter::LFO2Speed),
+                    ("Rise", SoundParameter::LFO2Rise),
+                ],
+                params,
+                controls,
+                focus,
             ))
             .push(lfo_phase_list(
                 "Phase",
@@ -49,10 +66,11 @@ impl LFO2Section {
                 SoundParameter::ModLFO2Target,
                 params.get_value(SoundParameter::ModLFO2Target),
             ))
-            .push(slider_with_labels(
-                "Mod Amt",
-                SoundParameter::ModLFO2Amount,
-                params.get_value(SoundParameter::ModLFO2Amount),
+            .push(sound_controls(
+                &[("Mod Amt", SoundParameter::ModLFO2Amount)],
+                params,
+                controls,
+                focus,
             ));
         Container::new(content).style(style::LFOSection).into()
     }
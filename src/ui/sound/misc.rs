@@ -5,7 +5,9 @@ use iced::Element;
 
 use crate::messages::Message;
 use crate::params::{GetValue, SoundParameter, SoundParameterValues};
-use crate::ui::elements::{checkbox::checkbox_with_labels, slider::slider_with_labels};
+use crate::ui::elements::{
+    checkbox::checkbox_with_labels, control_style_list::ControlStyle, slider::sound_controls,
+};
 use crate::ui::style;
 
 pub struct MiscSection {}
@@ -15,20 +17,24 @@ impl MiscSection {
         Self {}
     }
 
-    pub fn view(&self, params: &SoundParameterValues) -> Element<Message> {
+    pub fn view(
+        &self,
+        params: &SoundParameterValues,
+        controls: ControlStyle,
+        focus: Option<SoundParameter>,
+    ) -> Element<Message> {
         let content = Column::new()
-            .push(Text::new("Misc").size(style::SECTION_LABEL_TEXT_SIZE))
+            .push(Text::new("Misc").size(style::section_label_text_size()))
             .padding(style::SECTION_PADDING)
             .spacing(style::SECTION_SPACING)
-            .push(slider_with_labels(
-                "Bend Amt",
-                SoundParameter::BendRange,
-                params.get_value(SoundParameter::BendRange),
-            ))
-            .push(slider_with_labels(
-                "Tune",
-                SoundParameter::Tune,
-                params.get_value(SoundParameter::Tune),
+            .push(sound_controls(
+                &[
+                    ("Bend Amt", SoundParameter::BendRange),
+                    ("Tune", SoundParameter::Tune),
+                ],
+                params,
+                controls,
+                focus,
             ))
             .push(checkbox_with_labels(
                 "",
@@ -14,18 +14,85 @@ mod osc1;
 mod osc2;
 mod shaper;
 
+use std::collections::HashMap;
+use std::time::Instant;
+
 use iced::widget::{Column, Container, Row};
 use iced::{Element, Length};
 
+use super::elements::control_style_list::ControlStyle;
 use super::style;
 use crate::messages::Message;
-use crate::params::SoundParameterValues;
+use crate::params::{SoundParameter, SoundParameterValues, SoundSection};
 use {
     amp::AmpSection, arp::ArpSection, enva::EnvASection, envf::EnvFSection, extra::ExtraSection,
     filter::FilterSection, lfo1::LFO1Section, lfo2::LFO2Section, misc::MiscSection,
     modulation::ModSection, osc1::Osc1Section, osc2::Osc2Section, shaper::ShaperSection,
 };
 
+/// Parameters with slider, knob or fader controls in the order of the panel layout,
+/// column by column, used for the keyboard focus
+pub const FOCUS_ORDER: [SoundParameter; 58] = [
+    SoundParameter::Osc1Wave,
+    SoundParameter::Osc1Coarse,
+    SoundParameter::Osc1Fine,
+    SoundParameter::Osc1FMAmount,
+    SoundParameter::Osc1FMRate,
+    SoundParameter::Osc1Sync,
+    SoundParameter::Osc1Level,
+    SoundParameter::LFO1Speed,
+    SoundParameter::LFO1Rise,
+    SoundParameter::ModLFO1Amount,
+    SoundParameter::ArpTempo,
+    SoundParameter::Osc2Wave,
+    SoundParameter::Osc2Coarse,
+    SoundParameter::Osc2Fine,
+    SoundParameter::Osc2FMAmount,
+    SoundParameter::Osc2FMRate,
+    SoundParameter::Osc2Sync,
+    SoundParameter::Osc2Level,
+    SoundParameter::LFO2Speed,
+    SoundParameter::LFO2Rise,
+    SoundParameter::ModLFO2Amount,
+    SoundParameter::BendRange,
+    SoundParameter::Tune,
+    SoundParameter::ShaperCutoff,
+    SoundParameter::ShaperResonance,
+    SoundParameter::ShaperEnvAAmount,
+    SoundParameter::ShaperTrack,
+    SoundParameter::ShaperLFO2Amount,
+    SoundParameter::ExtraNoise,
+    SoundParameter::ExtraRingMod,
+    SoundParameter::EnvFAttack,
+    SoundParameter::EnvFHold,
+    SoundParameter::EnvFDecay,
+    SoundParameter::EnvFSustain,
+    SoundParameter::EnvFRelease,
+    SoundParameter::EnvFVelo,
+    SoundParameter::EnvFAfter,
+    SoundParameter::ModEnvFAmount,
+    SoundParameter::FilterCutoff,
+    SoundParameter::FilterResonance,
+    SoundParameter::FilterEnvFAmount,
+    SoundParameter::FilterTrack,
+    SoundParameter::FilterAfter,
+    SoundParameter::FilterLFO1Amount,
+    SoundParameter::AmpLevel,
+    SoundParameter::AmpPan,
+    SoundParameter::EnvAAttack,
+    SoundParameter::EnvAHold,
+    SoundParameter::EnvADecay,
+    SoundParameter::EnvASustain,
+    SoundParameter::EnvARelease,
+    SoundParameter::EnvAVelo,
+    SoundParameter::EnvAAfter,
+    SoundParameter::ModEnvAAmount,
+    SoundParameter::ModModwheelAmount,
+    SoundParameter::ModPitchAmount,
+    SoundParameter::ModVelocityAmount,
+    SoundParameter::ModAftertouchAmount,
+];
+
 pub struct SoundPanel {
     osc1_section: Osc1Section,
     osc2_section: Osc2Section,
@@ -61,37 +128,87 @@ impl SoundPanel {
         }
     }
 
-    pub fn view(&self, params: &SoundParameterValues) -> Element<Message> {
+    /// Returns the panel
+    ///
+    /// - `params`      Current sound parameters
+    /// - `start`       Start time of the preview animations
+    /// - `controls`    Control style of the sections, sliders if missing
+    /// - `focus`       Parameter with keyboard focus
+    pub fn view(
+        &self,
+        params: &SoundParameterValues,
+        start: Instant,
+        controls: &HashMap<SoundSection, ControlStyle>,
+        focus: Option<SoundParameter>,
+    ) -> Element<Message> {
+        let style_of = |section| controls.get(&section).copied().unwrap_or_default();
+
         let sound_col1 = Column::new()
             .padding(5)
             .spacing(10)
-            .push(self.osc1_section.view(params))
-            .push(self.lfo1_section.view(params))
-            .push(self.arp_section.view(params))
+            .push(
+                self.osc1_section
+                    .view(params, style_of(SoundSection::Osc1), focus),
+            )
+            .push(
+                self.lfo1_section
+                    .view(params, start, style_of(SoundSection::LFO1), focus),
+            )
+            .push(
+                self.arp_section
+                    .view(params, style_of(SoundSection::Arp), focus),
+            )
             .width(Length::FillPortion(4));
 
         let sound_col2 = Column::new()
             .padding(5)
             .spacing(10)
-            .push(self.osc2_section.view(params))
-            .push(self.lfo2_section.view(params))
-            .push(self.misc_section.view(params))
+            .push(
+                self.osc2_section
+                    .view(params, style_of(SoundSection::Osc2), focus),
+            )
+            .push(
+                self.lfo2_section
+                    .view(params, start, style_of(SoundSection::LFO2), focus),
+            )
+            .push(
+                self.misc_section
+                    .view(params, style_of(SoundSection::Misc), focus),
+            )
             .width(Length::FillPortion(4));
 
         let sound_col3 = Column::new()
             .padding(5)
             .spacing(10)
-            .push(self.shaper_section.view(params))
-            .push(self.extra_section.view(params))
-            .push(self.envf_section.view(params))
+            .push(
+                self.shaper_section
+                    .view(params, style_of(SoundSection::Shaper), focus),
+            )
+            .push(
+                self.extra_section
+                    .view(params, style_of(SoundSection::Extra), focus),
+            )
+            .push(
+                self.envf_section
+                    .view(params, style_of(SoundSection::EnvF), focus),
+            )
             .width(Length::FillPortion(4));
 
         let sound_col4 = Column::new()
             .padding(5)
             .spacing(10)
-            .push(self.filter_section.view(params))
-            .push(self.amp_section.view(params))
-            .push(self.enva_section.view(params))
+            .push(
+                self.filter_section
+                    .view(params, style_of(SoundSection::Filter), focus),
+            )
+            .push(
+                self.amp_section
+                    .view(params, style_of(SoundSection::Amp), focus),
+            )
+            .push(
+                self.enva_section
+                    .view(params, style_of(SoundSection::EnvA), focus),
+            )
             .width(Length::FillPortion(4));
 
         Container::new(
@@ -103,7 +220,11 @@ impl SoundPanel {
                         .push(sound_col3)
                         .push(sound_col4),
                 )
-                .push(Row::new().padding(5).push(self.mod_section.view(params))),
+                .push(Row::new().padding(5).push(self.mod_section.view(
+                    params,
+                    style_of(SoundSection::Modulation),
+                    focus,
+                ))),
         )
         .padding(5)
         .height(Length::Fill)
@@ -5,7 +5,9 @@ use iced::{Element, Length};
 
 use crate::messages::Message;
 use crate::params::{GetValue, SoundParameter, SoundParameterValues};
-use crate::ui::elements::{mod_target_list::mod_target_list, slider::slider_with_labels};
+use crate::ui::elements::{
+    control_style_list::ControlStyle, mod_target_list::mod_target_list, slider::sound_controls,
+};
 use crate::ui::style;
 
 pub struct ModSection {}
@@ -15,7 +17,12 @@ impl ModSection {
         Self {}
     }
 
-    pub fn view(&self, params: &SoundParameterValues) -> Element<Message> {
+    pub fn view(
+        &self,
+        params: &SoundParameterValues,
+        controls: ControlStyle,
+        focus: Option<SoundParameter>,
+    ) -> Element<Message> {
         let content = Column::new().push(
             Row::new()
                 .push(
@@ -27,10 +34,11 @@ impl ModSection {
                             SoundParameter::ModModwheelTarget,
                             params.get_value(SoundParameter::ModModwheelTarget),
                         ))
-                        .push(slider_with_labels(
-                            "MD Amt",
-                            SoundParameter::ModModwheelAmount,
-                            params.get_value(SoundParameter::ModModwheelAmount),
+                        .push(sound_controls(
+                            &[("MD Amt", SoundParameter::ModModwheelAmount)],
+                            params,
+                            controls,
+                            focus,
                         ))
                         .width(Length::FillPortion(4)),
                 )
@@ -43,10 +51,11 @@ impl ModSection {
                             SoundParameter::ModPitchTarget,
                             params.get_value(SoundParameter::ModPitchTarget),
                         ))
-                        .push(slider_with_labels(
-                            "PI Amt",
-                            SoundParameter::ModPitchAmount,
-                            params.get_value(SoundParameter::ModPitchAmount),
+                        .push(sound_controls(
+                            &[("PI Amt", SoundParameter::ModPitchAmount)],
+                            params,
+                            controls,
+                            focus,
                         ))
                         .width(Length::FillPortion(4)),
                 )
@@ -59,10 +68,11 @@ impl ModSection {
                             SoundParameter::ModVelocityTarget,
                             params.get_value(SoundParameter::ModVelocityTarget),
                         ))
-                        .push(slider_with_labels(
-                            "VL Amt",
-                            SoundParameter::ModVelocityAmount,
-                            params.get_value(SoundParameter::ModVelocityAmount),
+                        .push(sound_controls(
+                            &[("VL Amt", SoundParameter::ModVelocityAmount)],
+                            params,
+                            controls,
+                            focus,
                         ))
                         .width(Length::FillPortion(4)),
                 )
@@ -75,10 +85,11 @@ impl ModSection {
                             SoundParameter::ModAftertouchTarget,
                             params.get_value(SoundParameter::ModAftertouchTarget),
                         ))
-                        .push(slider_with_labels(
-                            "AF Amt",
-                            SoundParameter::ModAftertouchAmount,
-                            params.get_value(SoundParameter::ModAftertouchAmount),
+                        .push(sound_controls(
+                            &[("AF Amt", SoundParameter::ModAftertouchAmount)],
+                            params,
+                            controls,
+                            focus,
                         ))
                         .width(Length::FillPortion(4)),
                 ),
@@ -5,7 +5,9 @@ use iced::Element;
 
 use crate::messages::Message;
 use crate::params::{GetValue, SoundParameter, SoundParameterValues};
-use crate::ui::elements::{slider::slider_with_labels, wavetable_list::wavetable_list};
+use crate::ui::elements::{
+    control_style_list::ControlStyle, slider::sound_controls, wavetable_list::wavetable_list,
+};
 use crate::ui::style;
 
 pub struct Osc1Section {}
@@ -15,9 +17,14 @@ impl Osc1Section {
         Self {}
     }
 
-    pub fn view(&self, params: &SoundParameterValues) -> Element<Message> {
+    pub fn view(
+        &self,
+        params: &SoundParameterValues,
+        controls: ControlStyle,
+        focus: Option<SoundParameter>,
+    ) -> Element<Message> {
         let content = Column::new()
-            .push(Text::new("Osc 1").size(style::SECTION_LABEL_TEXT_SIZE))
+            .push(Text::new("Osc 1").size(style::section_label_text_size()))
             .padding(style::SECTION_PADDING)
             .spacing(style::SECTION_SPACING)
             .push(wavetable_list(
@@ -25,40 +32,19 @@ impl Osc1Section {
                 SoundParameter::Osc1Table,
                 params.get_value(SoundParameter::Osc1Table),
             ))
-            .push(slider_with_labels(
-                "Wave",
-                SoundParameter::Osc1Wave,
-                params.get_value(SoundParameter::Osc1Wave),
-            ))
-            .push(slider_with_labels(
-                "Coarse",
-                SoundParameter::Osc1Coarse,
-                params.get_value(SoundParameter::Osc1Coarse),
-            ))
-            .push(slider_with_labels(
-                "Fine",
-                SoundParameter::Osc1Fine,
-                params.get_value(SoundParameter::Osc1Fine),
-            ))
-            .push(slider_with_labels(
-                "FM Amt",
-                SoundParameter::Osc1FMAmount,
-                params.get_value(SoundParameter::Osc1FMAmount),
-            ))
-            .push(slider_with_labels(
-                "FM Rate",
-                SoundParameter::Osc1FMRate,
-                params.get_value(SoundParameter::Osc1FMRate),
-            ))
-            .push(slider_with_labels(
-                "Sync",
-                SoundParameter::Osc1Sync,
-                params.get_value(SoundParameter::Osc1Sync),
-            ))
-            .push(slider_with_labels(
-                "Level",
-                SoundParameter::Osc1Level,
-                params.get_value(SoundParameter::Osc1Level),
+            .push(sound_controls(
+                &[
+                    ("Wave", SoundParameter::Osc1Wave),
+                    ("Coarse", SoundParameter::Osc1Coarse),
+                    ("Fine", SoundParameter::Osc1Fine),
+                    ("FM Amt", SoundParameter::Osc1FMAmount),
+                    ("FM Rate", SoundParameter::Osc1FMRate),
+                    ("Sync", SoundParameter::Osc1Sync),
+                    ("Level", SoundParameter::Osc1Level),
+                ],
+                params,
+                controls,
+                focus,
             ));
         Container::new(content).style(style::OscSection).into()
     }
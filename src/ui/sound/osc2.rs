@@ -5,7 +5,9 @@ use iced::Element;
 
 use crate::messages::Message;
 use crate::params::{GetValue, SoundParameter, SoundParameterValues};
-use crate::ui::elements::{slider::slider_with_labels, wavetable_list::wavetable_list};
+use crate::ui::elements::{
+    control_style_list::ControlStyle, slider::sound_controls, wavetable_list::wavetable_list,
+};
 use crate::ui::style;
 
 pub struct Osc2Section {}
@@ -15,9 +17,14 @@ impl Osc2Section {
         Self {}
     }
 
-    pub fn view(&self, params: &SoundParameterValues) -> Element<Message> {
+    pub fn view(
+        &self,
+        params: &SoundParameterValues,
+        controls: ControlStyle,
+        focus: Option<SoundParameter>,
+    ) -> Element<Message> {
         let content = Column::new()
-            .push(Text::new("Osc 2").size(style::SECTION_LABEL_TEXT_SIZE))
+            .push(Text::new("Osc 2").size(style::section_label_text_size()))
             .padding(style::SECTION_PADDING)
             .spacing(style::SECTION_SPACING)
             .push(wavetable_list(
@@ -25,40 +32,19 @@ impl Osc2Section {
                 SoundParameter::Osc2Table,
                 params.get_value(SoundParameter::Osc2Table),
             ))
-            .push(slider_with_labels(
-                "Wave",
-                SoundParameter::Osc2Wave,
-                params.get_value(SoundParameter::Osc2Wave),
-            ))
-            .push(slider_with_labels(
-                "Coarse",
-                SoundParameter::Osc2Coarse,
-                params.get_value(SoundParameter::Osc2Coarse),
-            ))
-            .push(slider_with_labels(
-                "Fine",
-                SoundParameter::Osc2Fine,
-                params.get_value(SoundParameter::Osc2Fine),
-            ))
-            .push(slider_with_labels(
-                "FM Amt",
-                SoundParameter::Osc2FMAmount,
-                params.get_value(SoundParameter::Osc2FMAmount),
-            ))
-            .push(slider_with_labels(
-                "FM Rate",
-                SoundParameter::Osc2FMRate,
-                params.get_value(SoundParameter::Osc2FMRate),
-            ))
-            .push(slider_with_labels(
-                "Sync",
-                SoundParameter::Osc2Sync,
-                params.get_value(SoundParameter::Osc2Sync),
-            ))
-            .push(slider_with_labels(
-                "Level",
-                SoundParameter::Osc2Level,
-                params.get_value(SoundParameter::Osc2Level),
+            .push(sound_controls(
+                &[
+                    ("Wave", SoundParameter::Osc2Wave),
+                    ("Coarse", SoundParameter::Osc2Coarse),
+                    ("Fine", SoundParameter::Osc2Fine),
+                    ("FM Amt", SoundParameter::Osc2FMAmount),
+                    ("FM Rate", SoundParameter::Osc2FMRate),
+                    ("Sync", SoundParameter::Osc2Sync),
+                    ("Level", SoundParameter::Osc2Level),
+                ],
+                params,
+                controls,
+                focus,
             ));
         Container::new(content).style(style::OscSection).into()
     }
@@ -1,11 +1,16 @@
 //! Section containing the shaper parameters
 
-use iced::widget::{Column, Container, Text};
+use iced::widget::{Column, Container};
 use iced::Element;
 
 use crate::messages::Message;
 use crate::params::{GetValue, SoundParameter, SoundParameterValues};
-use crate::ui::elements::{shaper_mode_list::shaper_mode_list, slider::slider_with_labels};
+use crate::ui::elements::{
+    control_style_list::ControlStyle,
+    filter_graph::{filter_graph, SHAPER},
+    shaper_mode_list::shaper_mode_list,
+    slider::sound_controls,
+};
 use crate::ui::style;
 
 pub struct ShaperSection {}
@@ -15,40 +20,37 @@ impl ShaperSection {
         Self {}
     }
 
-    pub fn view(&self, params: &SoundParameterValues) -> Element<Message> {
+    pub fn view(
+        &self,
+        params: &SoundParameterValues,
+        controls: ControlStyle,
+        focus: Option<SoundParameter>,
+    ) -> Element<Message> {
         let content = Column::new()
-            .push(Text::new("Shaper").size(style::SECTION_LABEL_TEXT_SIZE))
+            .push(filter_graph("Shaper", &SHAPER, params))
             .padding(style::SECTION_PADDING)
             .spacing(style::SECTION_SPACING)
-            .push(slider_with_labels(
-                "Cutoff",
-                SoundParameter::ShaperCutoff,
-                params.get_value(SoundParameter::ShaperCutoff),
-            ))
-            .push(slider_with_labels(
-                "Resonance",
-                SoundParameter::ShaperResonance,
-                params.get_value(SoundParameter::ShaperResonance),
-            ))
-            .push(slider_with_labels(
-                "Env A Amt",
-                SoundParameter::ShaperEnvAAmount,
-                params.get_value(SoundParameter::ShaperEnvAAmount),
-            ))
-            .push(slider_with_labels(
-                "Track",
-                SoundParameter::ShaperTrack,
-                params.get_value(SoundParameter::ShaperTrack),
+            .push(sound_controls(
+                &[
+                    ("Cutoff", SoundParameter::ShaperCutoff),
+                    ("Resonance", SoundParameter::ShaperResonance),
+                    ("Env A Amt", SoundParameter::ShaperEnvAAmount),
+                    ("Track", SoundParameter::ShaperTrack),
+                ],
+                params,
+                controls,
+                focus,
             ))
             .push(shaper_mode_list(
                 "Mode",
                 SoundParameter::ShaperMode,
                 params.get_value(SoundParameter::ShaperMode),
             ))
-            .push(slider_with_labels(
-                "LFO 2 Amt",
-                SoundParameter::ShaperLFO2Amount,
-                params.get_value(SoundParameter::ShaperLFO2Amount),
+            .push(sound_controls(
+                &[("LFO 2 Amt", SoundParameter::ShaperLFO2Amount)],
+                params,
+                controls,
+                focus,
             ));
         Container::new(content).style(style::ShaperSection).into()
     }
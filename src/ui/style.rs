@@ -6,11 +6,16 @@ use iced::overlay::menu;
 use iced::widget::{button, checkbox, container, pick_list, slider};
 use iced::{Background, Color, Theme, Vector};
 
+use super::theme::{self, ThemeColor};
+
 /// Default window width
 pub const WINDOW_WIDTH: u32 = 1024;
 
 /// Default window height
-pub const WINDOW_HEIGHT: u32 = 655;
+pub const WINDOW_HEIGHT: u32 = 690;
+
+/// Height of the toolbar
+pub const TOOLBAR_HEIGHT: u16 = 35;
 
 /// Common element padding
 pub const SECTION_PADDING: u16 = 7;
@@ -18,50 +23,97 @@ pub const SECTION_PADDING: u16 = 7;
 /// Common element spacing
 pub const SECTION_SPACING: u16 = 1;
 
-/// Text size for section labels
-pub const SECTION_LABEL_TEXT_SIZE: u16 = 16;
-
-/// Text size for parameter labels
-pub const PARAM_LABEL_TEXT_SIZE: u16 = 14;
-
 /// Width of parameter labels
 pub const PARAM_LABEL_WIDTH: u16 = 65;
 
 /// Width of parameter values
-pub const PARAM_VALUE_WIDTH: u16 = 25;
+pub const PARAM_VALUE_WIDTH: u16 = 52;
+
+/// Height of the graphical editors and previews
+pub const GRAPH_HEIGHT: u16 = 56;
+
+/// Diameter of knobs
+pub const KNOB_SIZE: u16 = 32;
+
+/// Height of faders
+pub const FADER_HEIGHT: u16 = 64;
+
+/// Text size for section labels
+pub fn section_label_text_size() -> u16 {
+    theme::current().text_sizes.section_label
+}
+
+/// Text size for parameter labels
+pub fn param_label_text_size() -> u16 {
+    theme::current().text_sizes.param_label
+}
 
 /// Text size of dropdown menu items
-pub const LIST_ITEM_TEXT_SIZE: u16 = 13;
+pub fn list_item_text_size() -> u16 {
+    theme::current().text_sizes.list_item
+}
 
 /// Button text size
-pub const BUTTON_TEXT_SIZE: u16 = 14;
+pub fn button_text_size() -> u16 {
+    theme::current().text_sizes.button
+}
 
 /// Text size of status bar items
-pub const STATUS_TEXT_SIZE: u16 = 14;
-
-/// Text color for all section elements
-const SECTION_TEXT_COLOR: Color = Color::from_rgb(0_f32, 0_f32, 0_f32);
-
-/// Color for active elements
-const ACTIVE: Color = Color::from_rgb(
-    0x20 as f32 / 255.0,
-    0x20 as f32 / 255.0,
-    0x20 as f32 / 255.0,
-);
-
-/// Color for hovered elements
-const HOVERED: Color = Color::from_rgb(
-    0x67 as f32 / 255.0,
-    0x7B as f32 / 255.0,
-    0xC4 as f32 / 255.0,
-);
-
-/// Surface color for checkboxes
-const SURFACE: Color = Color::from_rgb(
-    0x20 as f32 / 255.0,
-    0x20 as f32 / 255.0,
-    0x20 as f32 / 255.0,
-);
+pub fn status_text_size() -> u16 {
+    theme::current().text_sizes.status
+}
+
+/// Text size of MIDI monitor entries
+pub fn monitor_text_size() -> u16 {
+    theme::current().text_sizes.monitor
+}
+
+/// Text size of knob and fader labels and values
+pub fn knob_text_size() -> u16 {
+    theme::current().text_sizes.knob
+}
+
+/// Line color of graphs
+pub fn graph_line_color() -> Color {
+    theme::current().section_text.0
+}
+
+/// Color of modulation indications in graphs
+pub fn graph_mod_color() -> Color {
+    theme::current().graph_mod.0
+}
+
+/// Colors of the modulation sources in slider overlays, in the order of `ModSource::ALL`
+pub fn mod_source_colors() -> [Color; 8] {
+    theme::current().mod_sources.map(|color| color.0)
+}
+
+/// Color of knobs and faders
+pub fn control_color() -> Color {
+    theme::current().active.0
+}
+
+/// Color of hovered knobs and faders
+pub fn control_hovered_color() -> Color {
+    theme::current().hovered.0
+}
+
+/// Label color of the control with keyboard focus
+pub fn focus_color() -> Color {
+    theme::current().hovered.0
+}
+
+/// Returns the appearance of a section container
+fn section_appearance(background: ThemeColor) -> container::Appearance {
+    let theme = theme::current();
+    container::Appearance {
+        text_color: Some(theme.section_text.0),
+        background: Some(Background::Color(background.0)),
+        border_width: theme.section_border_width,
+        border_color: theme.section_border.0,
+        border_radius: 5.0,
+    }
+}
 
 /// Styles for the oscillator sections
 pub struct OscSection;
@@ -70,13 +122,7 @@ impl container::StyleSheet for OscSection {
     type Style = Theme;
 
     fn appearance(&self, _style: &Self::Style) -> container::Appearance {
-        container::Appearance {
-            text_color: Some(SECTION_TEXT_COLOR),
-            background: Some(Background::Color(Color::from_rgb8(0xab, 0xa3, 0x39))),
-            border_width: 0.0,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
-            border_radius: 5.0,
-        }
+        section_appearance(theme::current().sections.osc)
     }
 }
 
@@ -93,13 +139,7 @@ impl container::StyleSheet for ExtraSection {
     type Style = Theme;
 
     fn appearance(&self, _style: &Self::Style) -> container::Appearance {
-        container::Appearance {
-            text_color: Some(SECTION_TEXT_COLOR),
-            background: Some(Background::Color(Color::from_rgb8(0xf9, 0xb0, 0x8b))),
-            border_width: 0.0,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
-            border_radius: 5.0,
-        }
+        section_appearance(theme::current().sections.extra)
     }
 }
 
@@ -116,13 +156,7 @@ impl container::StyleSheet for ShaperSection {
     type Style = Theme;
 
     fn appearance(&self, _style: &Self::Style) -> container::Appearance {
-        container::Appearance {
-            text_color: Some(SECTION_TEXT_COLOR),
-            background: Some(Background::Color(Color::from_rgb8(0xd8, 0x00, 0x00))),
-            border_width: 0.0,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
-            border_radius: 5.0,
-        }
+        section_appearance(theme::current().sections.shaper)
     }
 }
 
@@ -139,13 +173,7 @@ impl container::StyleSheet for FilterSection {
     type Style = Theme;
 
     fn appearance(&self, _style: &Self::Style) -> container::Appearance {
-        container::Appearance {
-            text_color: Some(SECTION_TEXT_COLOR),
-            background: Some(Background::Color(Color::from_rgb8(0xd8, 0x00, 0x00))),
-            border_width: 0.0,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
-            border_radius: 5.0,
-        }
+        section_appearance(theme::current().sections.filter)
     }
 }
 
@@ -162,13 +190,7 @@ impl container::StyleSheet for AmpSection {
     type Style = Theme;
 
     fn appearance(&self, _style: &Self::Style) -> container::Appearance {
-        container::Appearance {
-            text_color: Some(SECTION_TEXT_COLOR),
-            background: Some(Background::Color(Color::from_rgb8(0x65, 0xa4, 0x7e))),
-            border_width: 0.0,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
-            border_radius: 5.0,
-        }
+        section_appearance(theme::current().sections.amp)
     }
 }
 
@@ -185,13 +207,7 @@ impl container::StyleSheet for LFOSection {
     type Style = Theme;
 
     fn appearance(&self, _style: &Self::Style) -> container::Appearance {
-        container::Appearance {
-            text_color: Some(SECTION_TEXT_COLOR),
-            background: Some(Background::Color(Color::from_rgb8(0xd2, 0x6a, 0x25))),
-            border_width: 0.0,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
-            border_radius: 5.0,
-        }
+        section_appearance(theme::current().sections.lfo)
     }
 }
 
@@ -208,13 +224,7 @@ impl container::StyleSheet for EnvSection {
     type Style = Theme;
 
     fn appearance(&self, _style: &Self::Style) -> container::Appearance {
-        container::Appearance {
-            text_color: Some(SECTION_TEXT_COLOR),
-            background: Some(Background::Color(Color::from_rgb8(0xff, 0xbd, 0x00))),
-            border_width: 0.0,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
-            border_radius: 5.0,
-        }
+        section_appearance(theme::current().sections.env)
     }
 }
 
@@ -231,13 +241,7 @@ impl container::StyleSheet for ArpSection {
     type Style = Theme;
 
     fn appearance(&self, _style: &Self::Style) -> container::Appearance {
-        container::Appearance {
-            text_color: Some(SECTION_TEXT_COLOR),
-            background: Some(Background::Color(Color::from_rgb8(0xf9, 0xb0, 0x8b))),
-            border_width: 0.0,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
-            border_radius: 5.0,
-        }
+        section_appearance(theme::current().sections.arp)
     }
 }
 
@@ -254,13 +258,7 @@ impl container::StyleSheet for MiscSection {
     type Style = Theme;
 
     fn appearance(&self, _style: &Self::Style) -> container::Appearance {
-        container::Appearance {
-            text_color: Some(SECTION_TEXT_COLOR),
-            background: Some(Background::Color(Color::from_rgb8(0xC0, 0xC0, 0xC0))),
-            border_width: 0.0,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
-            border_radius: 5.0,
-        }
+        section_appearance(theme::current().sections.misc)
     }
 }
 
@@ -277,13 +275,7 @@ impl container::StyleSheet for ModSection {
     type Style = Theme;
 
     fn appearance(&self, _style: &Self::Style) -> container::Appearance {
-        container::Appearance {
-            text_color: Some(SECTION_TEXT_COLOR),
-            background: Some(Background::Color(Color::from_rgb8(0xb4, 0xcb, 0xd9))),
-            border_width: 0.0,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
-            border_radius: 5.0,
-        }
+        section_appearance(theme::current().sections.modulation)
     }
 }
 
@@ -300,13 +292,7 @@ impl container::StyleSheet for FXSection {
     type Style = Theme;
 
     fn appearance(&self, _style: &Self::Style) -> container::Appearance {
-        container::Appearance {
-            text_color: Some(SECTION_TEXT_COLOR),
-            background: Some(Background::Color(Color::from_rgb8(0x65, 0xa4, 0x7e))),
-            border_width: 0.0,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
-            border_radius: 5.0,
-        }
+        section_appearance(theme::current().sections.fx)
     }
 }
 
@@ -323,13 +309,7 @@ impl container::StyleSheet for MixerSection {
     type Style = Theme;
 
     fn appearance(&self, _style: &Self::Style) -> container::Appearance {
-        container::Appearance {
-            text_color: Some(SECTION_TEXT_COLOR),
-            background: Some(Background::Color(Color::from_rgb8(0xC0, 0xC0, 0xC0))),
-            border_width: 0.0,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
-            border_radius: 5.0,
-        }
+        section_appearance(theme::current().sections.mixer)
     }
 }
 
@@ -346,13 +326,7 @@ impl container::StyleSheet for MidiSection {
     type Style = Theme;
 
     fn appearance(&self, _style: &Self::Style) -> container::Appearance {
-        container::Appearance {
-            text_color: Some(SECTION_TEXT_COLOR),
-            background: Some(Background::Color(Color::from_rgb8(0xC0, 0xC0, 0xC0))),
-            border_width: 0.0,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
-            border_radius: 5.0,
-        }
+        section_appearance(theme::current().sections.midi)
     }
 }
 
@@ -362,6 +336,40 @@ impl From<MidiSection> for iced::theme::Container {
     }
 }
 
+/// Styles for the MIDI monitor controls
+pub struct MonitorSection;
+
+impl container::StyleSheet for MonitorSection {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style) -> container::Appearance {
+        section_appearance(theme::current().sections.monitor)
+    }
+}
+
+impl From<MonitorSection> for iced::theme::Container {
+    fn from(val: MonitorSection) -> Self {
+        Self::Custom(Box::new(val))
+    }
+}
+
+/// Styles for the parts overview
+pub struct OverviewSection;
+
+impl container::StyleSheet for OverviewSection {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style) -> container::Appearance {
+        section_appearance(theme::current().sections.overview)
+    }
+}
+
+impl From<OverviewSection> for iced::theme::Container {
+    fn from(val: OverviewSection) -> Self {
+        Self::Custom(Box::new(val))
+    }
+}
+
 /// Styles for the main window
 pub struct MainWindow;
 
@@ -369,9 +377,10 @@ impl container::StyleSheet for MainWindow {
     type Style = Theme;
 
     fn appearance(&self, _style: &Self::Style) -> container::Appearance {
+        let theme = theme::current();
         container::Appearance {
-            text_color: Some(Color::from_rgb8(0xFF, 0xFF, 0xFF)),
-            background: Some(Background::Color(Color::from_rgb8(0x20, 0x20, 0x20))),
+            text_color: Some(theme.window_text.0),
+            background: Some(Background::Color(theme.window_background.0)),
             ..Default::default()
         }
     }
@@ -386,52 +395,41 @@ impl From<MainWindow> for iced::theme::Container {
 /// Styles for all sliders
 pub struct Slider;
 
-impl slider::StyleSheet for Slider {
-    type Style = Theme;
-
-    fn active(&self, _style: &Self::Style) -> slider::Appearance {
+impl Slider {
+    /// Returns the appearance with a handle color
+    fn appearance(handle_color: Color) -> slider::Appearance {
+        let theme = theme::current();
+        let active = theme.active.0;
         slider::Appearance {
             rail: slider::Rail {
-                colors: (ACTIVE, Color { a: 0.1, ..ACTIVE }),
-                width: 0.0,
+                colors: (active, Color { a: 0.1, ..active }),
+                width: theme.slider_rail_width,
             },
             handle: slider::Handle {
-                shape: slider::HandleShape::Circle { radius: 6.0 },
-                color: ACTIVE,
+                shape: slider::HandleShape::Circle {
+                    radius: theme.slider_handle_radius,
+                },
+                color: handle_color,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
             },
         }
     }
+}
+
+impl slider::StyleSheet for Slider {
+    type Style = Theme;
+
+    fn active(&self, _style: &Self::Style) -> slider::Appearance {
+        Slider::appearance(theme::current().active.0)
+    }
 
     fn hovered(&self, _style: &Self::Style) -> slider::Appearance {
-        slider::Appearance {
-            rail: slider::Rail {
-                colors: (ACTIVE, Color { a: 0.1, ..ACTIVE }),
-                width: 0.0,
-            },
-            handle: slider::Handle {
-                shape: slider::HandleShape::Circle { radius: 6.0 },
-                color: HOVERED,
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
-            },
-        }
+        Slider::appearance(theme::current().hovered.0)
     }
 
     fn dragging(&self, _style: &Self::Style) -> slider::Appearance {
-        slider::Appearance {
-            rail: slider::Rail {
-                colors: (ACTIVE, Color { a: 0.1, ..ACTIVE }),
-                width: 0.0,
-            },
-            handle: slider::Handle {
-                shape: slider::HandleShape::Circle { radius: 6.0 },
-                color: Color::from_rgb8(0x50, 0x50, 0x50),
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
-            },
-        }
+        Slider::appearance(theme::current().dragging.0)
     }
 }
 
@@ -448,28 +446,39 @@ impl checkbox::StyleSheet for Checkbox {
     type Style = Theme;
 
     fn active(&self, _style: &Self::Style, is_checked: bool) -> checkbox::Appearance {
+        let theme = theme::current();
         checkbox::Appearance {
-            text_color: Some(SECTION_TEXT_COLOR),
-            background: if is_checked { ACTIVE } else { SURFACE }.into(),
-            icon_color: Color::WHITE,
+            text_color: Some(theme.section_text.0),
+            background: if is_checked {
+                theme.active.0
+            } else {
+                theme.surface.0
+            }
+            .into(),
+            icon_color: theme.active_text.0,
             border_radius: 2.0,
             border_width: 1.0,
-            border_color: ACTIVE,
+            border_color: theme.active.0,
         }
     }
 
     fn hovered(&self, _style: &Self::Style, is_checked: bool) -> checkbox::Appearance {
+        let theme = theme::current();
         checkbox::Appearance {
-            text_color: Some(SECTION_TEXT_COLOR),
+            text_color: Some(theme.section_text.0),
             background: Color {
                 a: 0.8,
-                ..if is_checked { ACTIVE } else { HOVERED }
+                ..if is_checked {
+                    theme.active.0
+                } else {
+                    theme.hovered.0
+                }
             }
             .into(),
-            icon_color: Color::WHITE,
+            icon_color: theme.active_text.0,
             border_radius: 2.0,
             border_width: 1.0,
-            border_color: ACTIVE,
+            border_color: theme.active.0,
         }
     }
 }
@@ -487,26 +496,28 @@ impl pick_list::StyleSheet for PickList {
     type Style = Theme;
 
     fn active(&self, _style: &Self::Style) -> pick_list::Appearance {
+        let theme = theme::current();
         pick_list::Appearance {
-            text_color: Color::from_rgb8(0xFF, 0xFF, 0xFF),
-            placeholder_color: Color::from_rgb8(0xFF, 0xFF, 0xFF),
-            background: Background::Color(Color::from_rgb8(0x20, 0x20, 0x20)),
+            text_color: theme.list_text.0,
+            placeholder_color: theme.list_text.0,
+            background: Background::Color(theme.list_background.0),
             border_radius: 5.0,
             border_width: 1.0,
-            border_color: Color::from_rgb8(0x80, 0x80, 0x80),
-            handle_color: Color::from_rgb8(0x80, 0x80, 0x80),
+            border_color: theme.list_border.0,
+            handle_color: theme.list_border.0,
         }
     }
 
     fn hovered(&self, _style: &Self::Style) -> pick_list::Appearance {
+        let theme = theme::current();
         pick_list::Appearance {
-            text_color: Color::from_rgb8(0xFF, 0xFF, 0xFF),
-            placeholder_color: Color::from_rgb8(0xFF, 0xFF, 0xFF),
-            background: Background::Color(HOVERED),
+            text_color: theme.list_text.0,
+            placeholder_color: theme.list_text.0,
+            background: Background::Color(theme.hovered.0),
             border_radius: 5.0,
             border_width: 1.0,
-            border_color: Color::from_rgb8(0x80, 0x80, 0x80),
-            handle_color: Color::from_rgb8(0x80, 0x80, 0x80),
+            border_color: theme.list_border.0,
+            handle_color: theme.list_border.0,
         }
     }
 }
@@ -517,14 +528,15 @@ impl menu::StyleSheet for Menu {
     type Style = Theme;
 
     fn appearance(&self, _style: &Self::Style) -> menu::Appearance {
+        let theme = theme::current();
         menu::Appearance {
-            text_color: Color::from_rgb8(0xFF, 0xFF, 0xFF),
-            background: Background::Color(Color::from_rgb8(0x20, 0x20, 0x20)),
+            text_color: theme.list_text.0,
+            background: Background::Color(theme.list_background.0),
             border_width: 1.0,
             border_radius: 5.0,
-            border_color: Color::from_rgb8(0x80, 0x80, 0x80),
-            selected_text_color: Color::from_rgb8(0xFF, 0xFF, 0xFF),
-            selected_background: Background::Color(Color::from_rgb8(0x80, 0x80, 0x80)),
+            border_color: theme.list_border.0,
+            selected_text_color: theme.list_text.0,
+            selected_background: Background::Color(theme.list_border.0),
         }
     }
 }
@@ -542,21 +554,23 @@ impl button::StyleSheet for Button {
     type Style = Theme;
 
     fn active(&self, _style: &Self::Style) -> button::Appearance {
+        let theme = theme::current();
         button::Appearance {
-            background: Some(Background::Color(Color::from_rgb(0.11, 0.42, 0.87))),
+            background: Some(Background::Color(theme.button.0)),
             border_radius: 5.0,
             shadow_offset: Vector::new(1.0, 1.0),
-            text_color: Color::from_rgb8(0xEE, 0xEE, 0xEE),
+            text_color: theme.button_text.0,
             ..button::Appearance::default()
         }
     }
 
     fn hovered(&self, _style: &Self::Style) -> button::Appearance {
+        let theme = theme::current();
         button::Appearance {
-            background: Some(Background::Color(Color::from_rgb(0.11, 0.42, 0.87))),
+            background: Some(Background::Color(theme.button.0)),
             border_radius: 5.0,
             shadow_offset: Vector::new(1.0, 2.0),
-            text_color: Color::WHITE,
+            text_color: theme.button_text.0,
             ..button::Appearance::default()
         }
     }
@@ -567,3 +581,56 @@ impl From<Button> for iced::theme::Button {
         Self::Custom(Box::new(val))
     }
 }
+
+/// Styles for the clickable value display of sliders
+pub struct ValueButton;
+
+impl button::StyleSheet for ValueButton {
+    type Style = Theme;
+
+    fn active(&self, _style: &Self::Style) -> button::Appearance {
+        button::Appearance {
+            background: None,
+            text_color: theme::current().section_text.0,
+            ..button::Appearance::default()
+        }
+    }
+
+    fn hovered(&self, _style: &Self::Style) -> button::Appearance {
+        button::Appearance {
+            background: None,
+            text_color: theme::current().hovered.0,
+            ..button::Appearance::default()
+        }
+    }
+}
+
+impl From<ValueButton> for iced::theme::Button {
+    fn from(val: ValueButton) -> Self {
+        Self::Custom(Box::new(val))
+    }
+}
+
+/// Styles for tooltips
+pub struct Tooltip;
+
+impl container::StyleSheet for Tooltip {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style) -> container::Appearance {
+        let theme = theme::current();
+        container::Appearance {
+            text_color: Some(theme.active_text.0),
+            background: Some(Background::Color(theme.active.0)),
+            border_width: 1.0,
+            border_color: theme.section_border.0,
+            border_radius: 5.0,
+        }
+    }
+}
+
+impl From<Tooltip> for iced::theme::Container {
+    fn from(val: Tooltip) -> Self {
+        Self::Custom(Box::new(val))
+    }
+}
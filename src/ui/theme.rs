@@ -0,0 +1,422 @@
+//! Color themes and text sizes of the user interface
+//!
+//! Besides the built-in themes, user themes are read from `themes.ron` in the
+//! config directory. The file contains a list of themes, fields missing in a
+//! user theme are taken from the dark theme. The name is required, themes
+//! without a name are ignored:
+//!
+//! ```ron
+//! [
+//!     (
+//!         name: "Night",
+//!         window_background: "#101018",
+//!         text_sizes: (param_label: 15),
+//!     ),
+//! ]
+//! ```
+//!
+//! Colors are written as `#RRGGBB` or `#RRGGBBAA`. The active theme is held
+//! globally because the style sheets have no access to the application state.
+
+use std::sync::{Arc, RwLock};
+
+use iced::theme::Palette;
+use iced::Color;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Name of the theme used if none is selected
+pub const DEFAULT_THEME: &str = "Dark";
+
+/// Theme used by the style sheets, `None` for the default theme
+static CURRENT: RwLock<Option<Arc<EditorTheme>>> = RwLock::new(None);
+
+/// Return the theme in use
+pub fn current() -> Arc<EditorTheme> {
+    CURRENT
+        .read()
+        .ok()
+        .and_then(|theme| theme.clone())
+        .unwrap_or_else(|| Arc::new(EditorTheme::dark()))
+}
+
+/// Set the theme used from now on
+pub fn set_current(theme: EditorTheme) {
+    if let Ok(mut current) = CURRENT.write() {
+        *current = Some(Arc::new(theme));
+    }
+}
+
+/// Color written as hex string in theme files
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThemeColor(pub Color);
+
+impl ThemeColor {
+    fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self(Color::from_rgb(
+            r as f32 / 255.0,
+            g as f32 / 255.0,
+            b as f32 / 255.0,
+        ))
+    }
+
+    /// Parse a color like `#1C6BDE` or `#1C6BDE80`
+    fn parse(s: &str) -> Option<Self> {
+        let hex = s.strip_prefix('#')?;
+        if !matches!(hex.len(), 6 | 8) || !hex.is_ascii() {
+            return None;
+        }
+
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        let alpha = if hex.len() == 8 { channel(6)? } else { 0xFF };
+
+        Some(Self(Color::from_rgba8(
+            channel(0)?,
+            channel(2)?,
+            channel(4)?,
+            alpha as f32 / 255.0,
+        )))
+    }
+}
+
+impl Serialize for ThemeColor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let [r, g, b, a] = self.0.into_rgba8();
+        let text = if a == 0xFF {
+            format!("#{:02X}{:02X}{:02X}", r, g, b)
+        } else {
+            format!("#{:02X}{:02X}{:02X}{:02X}", r, g, b, a)
+        };
+        serializer.serialize_str(&text)
+    }
+}
+
+impl<'de> Deserialize<'de> for ThemeColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        ThemeColor::parse(&text)
+            .ok_or_else(|| de::Error::custom(format!("invalid color \"{}\"", text)))
+    }
+}
+
+/// Base colors of the standard widgets like buttons and text inputs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemePalette {
+    pub background: ThemeColor,
+    pub text: ThemeColor,
+    pub primary: ThemeColor,
+    pub success: ThemeColor,
+    pub danger: ThemeColor,
+}
+
+impl Default for ThemePalette {
+    fn default() -> Self {
+        Self::from(Palette::DARK)
+    }
+}
+
+impl From<Palette> for ThemePalette {
+    fn from(palette: Palette) -> Self {
+        Self {
+            background: ThemeColor(palette.background),
+            text: ThemeColor(palette.text),
+            primary: ThemeColor(palette.primary),
+            success: ThemeColor(palette.success),
+            danger: ThemeColor(palette.danger),
+        }
+    }
+}
+
+impl From<&ThemePalette> for Palette {
+    fn from(palette: &ThemePalette) -> Self {
+        Self {
+            background: palette.background.0,
+            text: palette.text.0,
+            primary: palette.primary.0,
+            success: palette.success.0,
+            danger: palette.danger.0,
+        }
+    }
+}
+
+/// Text sizes of the different elements
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TextSizes {
+    pub section_label: u16,
+    pub param_label: u16,
+    pub list_item: u16,
+    pub button: u16,
+    pub status: u16,
+    pub monitor: u16,
+    pub knob: u16,
+}
+
+impl Default for TextSizes {
+    fn default() -> Self {
+        Self {
+            section_label: 16,
+            param_label: 14,
+            list_item: 13,
+            button: 14,
+            status: 14,
+            monitor: 13,
+            knob: 11,
+        }
+    }
+}
+
+/// Background colors of the section containers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SectionColors {
+    pub osc: ThemeColor,
+    pub extra: ThemeColor,
+    pub shaper: ThemeColor,
+    pub filter: ThemeColor,
+    pub amp: ThemeColor,
+    pub lfo: ThemeColor,
+    pub env: ThemeColor,
+    pub arp: ThemeColor,
+    pub misc: ThemeColor,
+    pub modulation: ThemeColor,
+    pub fx: ThemeColor,
+    pub mixer: ThemeColor,
+    pub midi: ThemeColor,
+    pub monitor: ThemeColor,
+    pub overview: ThemeColor,
+}
+
+impl Default for SectionColors {
+    fn default() -> Self {
+        Self {
+            osc: ThemeColor::rgb(0xAB, 0xA3, 0x39),
+            extra: ThemeColor::rgb(0xF9, 0xB0, 0x8B),
+            shaper: ThemeColor::rgb(0xD8, 0x00, 0x00),
+            filter: ThemeColor::rgb(0xD8, 0x00, 0x00),
+            amp: ThemeColor::rgb(0x65, 0xA4, 0x7E),
+            lfo: ThemeColor::rgb(0xD2, 0x6A, 0x25),
+            env: ThemeColor::rgb(0xFF, 0xBD, 0x00),
+            arp: ThemeColor::rgb(0xF9, 0xB0, 0x8B),
+            misc: ThemeColor::rgb(0xC0, 0xC0, 0xC0),
+            modulation: ThemeColor::rgb(0xB4, 0xCB, 0xD9),
+            fx: ThemeColor::rgb(0x65, 0xA4, 0x7E),
+            mixer: ThemeColor::rgb(0xC0, 0xC0, 0xC0),
+            midi: ThemeColor::rgb(0xC0, 0xC0, 0xC0),
+            monitor: ThemeColor::rgb(0xC0, 0xC0, 0xC0),
+            overview: ThemeColor::rgb(0xA8, 0xB4, 0xC8),
+        }
+    }
+}
+
+/// Colors and text sizes of the user interface
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "EditorTheme::unnamed")]
+pub struct EditorTheme {
+    /// Name shown in the theme selection
+    pub name: String,
+
+    /// Base colors of the standard widgets
+    pub palette: ThemePalette,
+
+    /// Background of the main window
+    pub window_background: ThemeColor,
+
+    /// Text in the main window outside of the sections
+    pub window_text: ThemeColor,
+
+    /// Background of the sections
+    pub sections: SectionColors,
+
+    /// Text, graph lines and value displays within the sections
+    pub section_text: ThemeColor,
+
+    /// Border of the sections
+    pub section_border: ThemeColor,
+
+    /// Border width of the sections, 0.0 for none
+    pub section_border_width: f32,
+
+    /// Slider rails and handles, knobs, checked checkboxes and tooltips
+    pub active: ThemeColor,
+
+    /// Text and icons on the active color
+    pub active_text: ThemeColor,
+
+    /// Hovered controls and the label of the focused control
+    pub hovered: ThemeColor,
+
+    /// Slider handle while dragging
+    pub dragging: ThemeColor,
+
+    /// Unchecked checkboxes
+    pub surface: ThemeColor,
+
+    /// Background of dropdown menus
+    pub list_background: ThemeColor,
+
+    /// Text of dropdown menus
+    pub list_text: ThemeColor,
+
+    /// Border, handle and selected entry of dropdown menus
+    pub list_border: ThemeColor,
+
+    /// Custom buttons
+    pub button: ThemeColor,
+
+    /// Text on custom buttons
+    pub button_text: ThemeColor,
+
+    /// Modulation indications in graphs
+    pub graph_mod: ThemeColor,
+
+    /// Modulation sources in slider overlays, in the order of `ModSource::ALL`
+    pub mod_sources: [ThemeColor; 8],
+
+    /// Radius of the slider handles
+    pub slider_handle_radius: f32,
+
+    /// Width of the slider rails
+    pub slider_rail_width: f32,
+
+    pub text_sizes: TextSizes,
+}
+
+impl Default for EditorTheme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl EditorTheme {
+    /// Return the dark theme without a name, the base of the user themes
+    fn unnamed() -> Self {
+        Self {
+            name: String::new(),
+            ..Self::dark()
+        }
+    }
+
+    /// Return the built-in themes
+    pub fn builtin() -> Vec<EditorTheme> {
+        vec![Self::dark(), Self::light(), Self::high_contrast()]
+    }
+
+    /// Dark window with the colors of the device panel
+    pub fn dark() -> Self {
+        Self {
+            name: String::from(DEFAULT_THEME),
+            palette: ThemePalette::from(Palette::DARK),
+            window_background: ThemeColor::rgb(0x20, 0x20, 0x20),
+            window_text: ThemeColor::rgb(0xFF, 0xFF, 0xFF),
+            sections: SectionColors::default(),
+            section_text: ThemeColor::rgb(0x00, 0x00, 0x00),
+            section_border: ThemeColor::rgb(0xB3, 0xB3, 0xB3),
+            section_border_width: 0.0,
+            active: ThemeColor::rgb(0x20, 0x20, 0x20),
+            active_text: ThemeColor::rgb(0xFF, 0xFF, 0xFF),
+            hovered: ThemeColor::rgb(0x67, 0x7B, 0xC4),
+            dragging: ThemeColor::rgb(0x50, 0x50, 0x50),
+            surface: ThemeColor::rgb(0x20, 0x20, 0x20),
+            list_background: ThemeColor::rgb(0x20, 0x20, 0x20),
+            list_text: ThemeColor::rgb(0xFF, 0xFF, 0xFF),
+            list_border: ThemeColor::rgb(0x80, 0x80, 0x80),
+            button: ThemeColor::rgb(0x1C, 0x6B, 0xDE),
+            button_text: ThemeColor::rgb(0xEE, 0xEE, 0xEE),
+            graph_mod: ThemeColor::rgb(0x20, 0x4A, 0xA0),
+            mod_sources: [
+                ThemeColor::rgb(0xD9, 0x54, 0x1A),
+                ThemeColor::rgb(0xED, 0xB0, 0x21),
+                ThemeColor::rgb(0x21, 0x4A, 0xA1),
+                ThemeColor::rgb(0x4D, 0xBF, 0xED),
+                ThemeColor::rgb(0x78, 0xAB, 0x30),
+                ThemeColor::rgb(0x7D, 0x2E, 0x8F),
+                ThemeColor::rgb(0xA3, 0x14, 0x2E),
+                ThemeColor::rgb(0x66, 0x66, 0x66),
+            ],
+            slider_handle_radius: 6.0,
+            slider_rail_width: 0.0,
+            text_sizes: TextSizes::default(),
+        }
+    }
+
+    /// Light window with the colors of the device panel
+    pub fn light() -> Self {
+        Self {
+            name: String::from("Light"),
+            palette: ThemePalette::from(Palette::LIGHT),
+            window_background: ThemeColor::rgb(0xE6, 0xE6, 0xE6),
+            window_text: ThemeColor::rgb(0x20, 0x20, 0x20),
+            surface: ThemeColor::rgb(0xF4, 0xF4, 0xF4),
+            list_background: ThemeColor::rgb(0xFF, 0xFF, 0xFF),
+            list_text: ThemeColor::rgb(0x20, 0x20, 0x20),
+            list_border: ThemeColor::rgb(0xA0, 0xA0, 0xA0),
+            ..Self::dark()
+        }
+    }
+
+    /// Black background with white text and bright controls
+    pub fn high_contrast() -> Self {
+        let black = ThemeColor::rgb(0x00, 0x00, 0x00);
+        let white = ThemeColor::rgb(0xFF, 0xFF, 0xFF);
+        let yellow = ThemeColor::rgb(0xFF, 0xFF, 0x00);
+        let cyan = ThemeColor::rgb(0x00, 0xFF, 0xFF);
+
+        Self {
+            name: String::from("High Contrast"),
+            palette: ThemePalette {
+                background: black,
+                text: white,
+                primary: ThemeColor::rgb(0xFF, 0xD7, 0x00),
+                success: ThemeColor::rgb(0x00, 0xC0, 0x00),
+                danger: ThemeColor::rgb(0xFF, 0x30, 0x30),
+            },
+            window_background: black,
+            window_text: white,
+            sections: SectionColors {
+                osc: black,
+                extra: black,
+                shaper: black,
+                filter: black,
+                amp: black,
+                lfo: black,
+                env: black,
+                arp: black,
+                misc: black,
+                modulation: black,
+                fx: black,
+                mixer: black,
+                midi: black,
+                monitor: black,
+                overview: black,
+            },
+            section_text: white,
+            section_border: white,
+            section_border_width: 1.5,
+            active: yellow,
+            active_text: black,
+            hovered: cyan,
+            dragging: white,
+            surface: black,
+            list_background: black,
+            list_text: white,
+            list_border: white,
+            button: yellow,
+            button_text: black,
+            graph_mod: cyan,
+            mod_sources: [
+                ThemeColor::rgb(0xFF, 0x60, 0x20),
+                yellow,
+                ThemeColor::rgb(0x40, 0x80, 0xFF),
+                cyan,
+                ThemeColor::rgb(0x40, 0xFF, 0x40),
+                ThemeColor::rgb(0xFF, 0x40, 0xFF),
+                ThemeColor::rgb(0xFF, 0x40, 0x40),
+                white,
+            ],
+            slider_handle_radius: 7.0,
+            slider_rail_width: 2.0,
+            text_sizes: TextSizes::default(),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! Panel containing the page selection and editing tools
+
+use iced::widget::{Button, Column, Container, Row, Text};
+use iced::{theme, Alignment, Element, Length};
+
+use super::style;
+use crate::compare::{CompareSlots, Slot};
+use crate::history::History;
+use crate::messages::Message;
+use crate::params::{GetValue, MultiParameter, MultiParameterValues};
+use crate::ui::elements::page_list::{page_list, Page};
+use crate::ui::elements::preset_list::preset_list;
+use crate::ui::elements::slot_button::slot_button;
+use crate::ui::elements::value_entry::{value_entry, ValueEntry};
+
+pub struct ToolbarPanel {}
+
+impl ToolbarPanel {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Returns the toolbar
+    ///
+    /// - `page`            Currently displayed page
+    /// - `part_id`         Current part id 0-3
+    /// - `multi_params`    Multi parameters containing the preset assignments
+    /// - `preset_names`    Names of the stored presets
+    /// - `history`         Undo/redo history
+    /// - `compare_slots`   A/B compare slots of the current part
+    /// - `entry`           Value being entered as text, replacing the tools
+    #[allow(clippy::too_many_arguments)]
+    pub fn view(
+        &self,
+        page: Page,
+        part_id: u8,
+        multi_params: &MultiParameterValues,
+        preset_names: &[String],
+        history: &History,
+        compare_slots: &CompareSlots,
+        entry: Option<&ValueEntry>,
+    ) -> Element<'_, Message> {
+        let preset_param = MultiParameter::from_preset_part(part_id);
+
+        if let Some(entry) = entry {
+            return Container::new(Row::new().padding(5).push(value_entry(entry)))
+                .padding([0, 5])
+                .width(Length::Fill)
+                .height(style::TOOLBAR_HEIGHT)
+                .style(style::MainWindow)
+                .into();
+        }
+
+        let row = Row::new()
+            .padding(5)
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .push(Text::new("Page:").size(style::status_text_size()))
+            .push(Column::new().push(page_list(page)).width(150))
+            .push(Column::new().width(20))
+            .push(
+                Text::new(format!("Part {} Preset:", part_id + 1)).size(style::status_text_size()),
+            )
+            .push(
+                Column::new()
+                    .push(preset_list(
+                        preset_param,
+                        multi_params.get_value(preset_param),
+                        preset_names,
+                    ))
+                    .width(250),
+            )
+            .push(Column::new().width(Length::Fill))
+            .push(slot_button(Slot::A, compare_slots.active()))
+            .push(slot_button(Slot::B, compare_slots.active()))
+            .push(
+                Button::new(Text::new("Copy A to B").size(style::button_text_size()))
+                    .style(theme::Button::Primary)
+                    .on_press(Message::CompareCopyAToB),
+            )
+            .push(Column::new().width(10))
+            .push({
+                let mut button = Button::new(Text::new("Undo").size(style::button_text_size()))
+                    .style(theme::Button::Primary);
+                if history.can_undo() {
+                    button = button.on_press(Message::Undo);
+                }
+                button
+            })
+            .push({
+                let mut button = Button::new(Text::new("Redo").size(style::button_text_size()))
+                    .style(theme::Button::Primary);
+                if history.can_redo() {
+                    button = button.on_press(Message::Redo);
+                }
+                button
+            });
+
+        Container::new(row)
+            .padding([0, 5])
+            .width(Length::Fill)
+            .height(style::TOOLBAR_HEIGHT)
+            .style(style::MainWindow)
+            .into()
+    }
+}
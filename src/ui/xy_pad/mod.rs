@@ -0,0 +1,115 @@
+//! Panel containing the XY pad and its assignments
+
+use iced::widget::{Button, Checkbox, Column, Container, PickList, Row, Text};
+use iced::{theme, Alignment, Element, Length};
+
+use super::style;
+use crate::messages::Message;
+use crate::ui::elements::xy_pad_widget::xy_pad;
+use crate::xy_pad::{XYAxis, XYPad, XYTarget};
+
+pub struct XYPadPanel {}
+
+impl XYPadPanel {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Returns the panel
+    ///
+    /// - `pad`         State of the XY pad
+    pub fn view(&self, pad: &XYPad) -> Element<'_, Message> {
+        let targets = XYTarget::all();
+
+        let assignments = Row::new()
+            .padding(style::SECTION_PADDING)
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .push(Text::new("X").size(style::param_label_text_size()))
+            .push(
+                PickList::new(targets.clone(), Some(pad.x_target), |target| {
+                    Message::XYPadTargetChange(XYAxis::X, target)
+                })
+                .style(style::PickList)
+                .text_size(style::list_item_text_size())
+                .width(170),
+            )
+            .push(Text::new("Y").size(style::param_label_text_size()))
+            .push(
+                PickList::new(targets, Some(pad.y_target), |target| {
+                    Message::XYPadTargetChange(XYAxis::Y, target)
+                })
+                .style(style::PickList)
+                .text_size(style::list_item_text_size())
+                .width(170),
+            )
+            .push(
+                Checkbox::new("Spring back", pad.spring, Message::XYPadSpringToggle)
+                    .style(style::Checkbox)
+                    .text_size(style::list_item_text_size())
+                    .spacing(7),
+            )
+            .push(Column::new().width(Length::Fill))
+            .push(
+                Button::new(
+                    Text::new(if pad.is_recording() {
+                        "Stop recording"
+                    } else {
+                        "Record"
+                    })
+                    .size(style::button_text_size()),
+                )
+                .style(if pad.is_recording() {
+                    theme::Button::Destructive
+                } else {
+                    theme::Button::Primary
+                })
+                .on_press(Message::XYPadRecordToggle),
+            )
+            .push({
+                let mut button = Button::new(
+                    Text::new(if pad.is_playing() {
+                        "Stop"
+                    } else {
+                        "Play loop"
+                    })
+                    .size(style::button_text_size()),
+                )
+                .style(theme::Button::Primary);
+                if pad.can_play() {
+                    button = button.on_press(Message::XYPadPlayToggle(!pad.is_playing()));
+                }
+                button
+            });
+
+        let [(_, x_value), (_, y_value)] = pad.values();
+
+        Container::new(
+            Column::new()
+                .spacing(10)
+                .push(Container::new(assignments).style(style::OverviewSection))
+                .push(
+                    Container::new(
+                        Column::new()
+                            .padding(style::SECTION_PADDING)
+                            .spacing(5)
+                            .push(
+                                Text::new(format!(
+                                    "{}: {}    {}: {}",
+                                    pad.x_target, x_value, pad.y_target, y_value
+                                ))
+                                .size(style::param_label_text_size()),
+                            )
+                            .push(xy_pad(pad.position, &pad.gesture)),
+                    )
+                    .style(style::OverviewSection)
+                    .width(Length::Fill)
+                    .height(Length::Fill),
+                ),
+        )
+        .padding(10)
+        .height(Length::Fill)
+        .style(style::MainWindow)
+        .into()
+    }
+}
@@ -0,0 +1,228 @@
+//! Conversion of raw parameter values to real units and back
+//!
+//! The conversions of times are approximations, the device does not
+//! document the exact curves.
+
+use crate::params::{MultiParameter, SoundParameter};
+
+/// Envelope time at the minimum value in milliseconds
+const MIN_TIME_MS: f32 = 1.0;
+
+/// Envelope time at the maximum value in milliseconds
+const MAX_TIME_MS: f32 = 10000.0;
+
+/// Maximum raw value of envelope times
+const MAX_TIME_VALUE: f32 = 255.0;
+
+/// Prefix for entering a raw value regardless of the unit
+const RAW_PREFIX: char = '#';
+
+/// Unit of a parameter value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    /// Raw device value
+    Raw,
+
+    /// Semitones
+    Semitones,
+
+    /// Cents
+    Cents,
+
+    /// Pitch bend range in semitones, without sign
+    BendRange,
+
+    /// Percentage of the given raw value
+    Percent(i32),
+
+    /// Stereo position with 128 as hard left or right
+    Pan,
+
+    /// Beats per minute
+    Bpm,
+
+    /// Approximate time with exponential scaling
+    Time,
+}
+
+impl Unit {
+    /// Return the unit of a sound parameter
+    fn of_sound(param: SoundParameter) -> Self {
+        match param {
+            SoundParameter::Osc1Coarse | SoundParameter::Osc2Coarse => Unit::Semitones,
+
+            SoundParameter::BendRange => Unit::BendRange,
+
+            SoundParameter::Osc1Fine | SoundParameter::Osc2Fine | SoundParameter::Tune => {
+                Unit::Cents
+            }
+
+            SoundParameter::Osc1Level
+            | SoundParameter::Osc2Level
+            | SoundParameter::ExtraNoise
+            | SoundParameter::ExtraRingMod
+            | SoundParameter::EnvFSustain
+            | SoundParameter::EnvASustain
+            | SoundParameter::AmpLevel => Unit::Percent(255),
+
+            SoundParameter::AmpPan => Unit::Pan,
+
+            SoundParameter::ArpTempo => Unit::Bpm,
+
+            SoundParameter::EnvFAttack
+            | SoundParameter::EnvFHold
+            | SoundParameter::EnvFDecay
+            | SoundParameter::EnvFRelease
+            | SoundParameter::EnvAAttack
+            | SoundParameter::EnvAHold
+            | SoundParameter::EnvADecay
+            | SoundParameter::EnvARelease => Unit::Time,
+
+            _ => Unit::Raw,
+        }
+    }
+
+    /// Return the unit of a multi parameter
+    fn of_multi(param: MultiParameter) -> Self {
+        match param {
+            MultiParameter::VolumePart1
+            | MultiParameter::VolumePart2
+            | MultiParameter::VolumePart3
+            | MultiParameter::VolumePart4
+            | MultiParameter::FXMix => Unit::Percent(255),
+
+            MultiParameter::BalancePart1
+            | MultiParameter::BalancePart2
+            | MultiParameter::BalancePart3
+            | MultiParameter::BalancePart4 => Unit::Pan,
+
+            _ => Unit::Raw,
+        }
+    }
+
+    /// Return the raw value in the unit
+    fn format(&self, value: i32) -> String {
+        match self {
+            Unit::Raw => format!("{}", value),
+            Unit::Semitones if value == 0 => String::from("0 st"),
+            Unit::Semitones => format!("{:+} st", value),
+            Unit::Cents if value == 0 => String::from("0 ct"),
+            Unit::Cents => format!("{:+} ct", value),
+            Unit::BendRange => format!("{} st", value),
+            Unit::Percent(max) => format!("{}%", (value * 100 + max / 2) / max),
+            Unit::Pan => {
+                let percent = (value.abs() * 100 + 64) / 128;
+                match value {
+                    0 => String::from("C"),
+                    v if v < 0 => format!("L{}", percent),
+                    _ => format!("R{}", percent),
+                }
+            }
+            Unit::Bpm => format!("{} BPM", value),
+            Unit::Time => {
+                let ms = time_ms(value);
+                if ms < 1000.0 {
+                    format!("{:.0} ms", ms)
+                } else {
+                    format!("{:.2} s", ms / 1000.0)
+                }
+            }
+        }
+    }
+
+    /// Return the raw value for a text in the unit, `None` if the text is invalid
+    fn parse(&self, text: &str) -> Option<i32> {
+        let text = text.trim().to_lowercase();
+
+        if let Some(raw) = text.strip_prefix(RAW_PREFIX) {
+            return raw.trim().parse().ok();
+        }
+
+        match self {
+            Unit::Raw => text.parse().ok(),
+            Unit::Semitones | Unit::BendRange => {
+                parse_number(&text, &["st"]).map(|v| v.round() as i32)
+            }
+            Unit::Cents => parse_number(&text, &["ct"]).map(|v| v.round() as i32),
+            Unit::Percent(max) => {
+                parse_number(&text, &["%"]).map(|v| (v * *max as f32 / 100.0).round() as i32)
+            }
+            Unit::Pan => {
+                let (sign, number) = if let Some(number) = text.strip_prefix('l') {
+                    (-1.0, number)
+                } else if let Some(number) = text.strip_prefix('r') {
+                    (1.0, number)
+                } else if text == "c" {
+                    return Some(0);
+                } else {
+                    (1.0, text.as_str())
+                };
+                parse_number(number, &["%"]).map(|v| (sign * v * 128.0 / 100.0).round() as i32)
+            }
+            Unit::Bpm => parse_number(&text, &["bpm"]).map(|v| v.round() as i32),
+            Unit::Time => {
+                let ms = if let Some(number) = text.strip_suffix("ms") {
+                    number.trim().parse::<f32>().ok()?
+                } else if let Some(number) = text.strip_suffix('s') {
+                    number.trim().parse::<f32>().ok()? * 1000.0
+                } else {
+                    text.parse::<f32>().ok()?
+                };
+                Some(time_value(ms))
+            }
+        }
+    }
+}
+
+/// Parse a number with an optional unit suffix
+fn parse_number(text: &str, suffixes: &[&str]) -> Option<f32> {
+    let number = suffixes
+        .iter()
+        .find_map(|suffix| text.strip_suffix(suffix))
+        .unwrap_or(text);
+
+    number.trim().parse().ok()
+}
+
+/// Return the approximate time in milliseconds for a raw envelope value
+fn time_ms(value: i32) -> f32 {
+    MIN_TIME_MS * (MAX_TIME_MS / MIN_TIME_MS).powf(value as f32 / MAX_TIME_VALUE)
+}
+
+/// Return the raw envelope value for a time in milliseconds
+fn time_value(ms: f32) -> i32 {
+    let ms = ms.clamp(MIN_TIME_MS, MAX_TIME_MS);
+    ((ms / MIN_TIME_MS).ln() / (MAX_TIME_MS / MIN_TIME_MS).ln() * MAX_TIME_VALUE).round() as i32
+}
+
+/// Return a sound parameter value in its real unit
+pub fn format_sound_value(param: SoundParameter, value: i32) -> String {
+    Unit::of_sound(param).format(value)
+}
+
+/// Return the raw value of a sound parameter for a text in its real unit
+///
+/// A plain number is taken in the unit of the parameter, milliseconds for times.
+/// A number prefixed with `#` is taken as raw value. The result is limited to
+/// the range of the parameter.
+pub fn parse_sound_value(param: SoundParameter, text: &str) -> Option<i32> {
+    let range = param.get_range();
+    Unit::of_sound(param)
+        .parse(text)
+        .map(|value| value.clamp(*range.start(), *range.end()))
+}
+
+/// Return a multi parameter value in its real unit
+pub fn format_multi_value(param: MultiParameter, value: i32) -> String {
+    Unit::of_multi(param).format(value)
+}
+
+/// Return the raw value of a multi parameter for a text in its real unit
+///
+/// See `parse_sound_value` for the accepted formats.
+pub fn parse_multi_value(param: MultiParameter, text: &str) -> Option<i32> {
+    let range = param.get_range();
+    Unit::of_multi(param)
+        .parse(text)
+        .map(|value| value.clamp(*range.start(), *range.end()))
+}
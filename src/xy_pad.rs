@@ -0,0 +1,250 @@
+//! XY pad controlling two parameters with a single gesture
+//!
+//! Gestures can be recorded and played back in a loop. Positions are
+//! normalized to 0.0-1.0 on both axes, with the origin in the lower left corner.
+
+use std::time::Instant;
+
+use crate::params::{MultiParameter, SoundParameter};
+
+/// Fraction of the distance to the centre covered per animation tick while springing back
+const SPRING_FACTOR: f32 = 0.3;
+
+/// Distance to the centre at which the spring-back ends
+const SPRING_SNAP: f32 = 0.01;
+
+/// Position of the centre on both axes
+const CENTER: f32 = 0.5;
+
+/// Parameter controlled by an axis of the pad
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XYTarget {
+    /// Sound parameter of the current part
+    Sound(SoundParameter),
+
+    /// Multi parameter
+    Multi(MultiParameter),
+}
+
+impl XYTarget {
+    /// Return all parameters that can be assigned to an axis
+    ///
+    /// Only continuous parameters are offered, sweeping lists like the
+    /// preset or channel assignments would be of no use.
+    pub fn all() -> Vec<XYTarget> {
+        SoundParameter::ALL
+            .iter()
+            .filter(|param| param.is_continuous())
+            .map(|param| XYTarget::Sound(*param))
+            .chain(
+                MultiParameter::ALL
+                    .iter()
+                    .filter(|param| param.is_continuous())
+                    .map(|param| XYTarget::Multi(*param)),
+            )
+            .collect()
+    }
+
+    /// Return the parameter value at a position 0.0-1.0
+    pub fn value_at(&self, position: f32) -> i32 {
+        let range = match self {
+            XYTarget::Sound(param) => param.get_range(),
+            XYTarget::Multi(param) => param.get_range(),
+        };
+        let span = (range.end() - range.start()) as f32;
+
+        range.start() + (span * position.clamp(0.0, 1.0)).round() as i32
+    }
+}
+
+impl std::fmt::Display for XYTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            XYTarget::Sound(param) => write!(f, "{}", param.get_name()),
+            XYTarget::Multi(param) => write!(f, "{}", param.get_name()),
+        }
+    }
+}
+
+/// Axis of the pad
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XYAxis {
+    /// Horizontal axis
+    X,
+
+    /// Vertical axis
+    Y,
+}
+
+/// Position of a recorded gesture
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GesturePoint {
+    /// Seconds since the start of the recording
+    pub time: f32,
+
+    /// Horizontal position 0.0-1.0
+    pub x: f32,
+
+    /// Vertical position 0.0-1.0
+    pub y: f32,
+}
+
+/// State of the XY pad
+pub struct XYPad {
+    /// Parameter controlled by the horizontal axis
+    pub x_target: XYTarget,
+
+    /// Parameter controlled by the vertical axis
+    pub y_target: XYTarget,
+
+    /// Return to the centre when the pad is released
+    pub spring: bool,
+
+    /// Current position
+    pub position: (f32, f32),
+
+    /// Spring-back in progress
+    pub returning: bool,
+
+    /// Start time and points of the recording in progress
+    recording: Option<(Instant, Vec<GesturePoint>)>,
+
+    /// Last recorded gesture
+    pub gesture: Vec<GesturePoint>,
+
+    /// Start time of the looping playback
+    playback: Option<Instant>,
+}
+
+impl XYPad {
+    /// Constructs a new instance controlling the filter cutoff and resonance
+    pub fn new() -> Self {
+        Self {
+            x_target: XYTarget::Sound(SoundParameter::FilterCutoff),
+            y_target: XYTarget::Sound(SoundParameter::FilterResonance),
+            spring: false,
+            position: (CENTER, CENTER),
+            returning: false,
+            recording: None,
+            gesture: Vec::new(),
+            playback: None,
+        }
+    }
+
+    /// Set the parameter of an axis
+    pub fn set_target(&mut self, axis: XYAxis, target: XYTarget) {
+        match axis {
+            XYAxis::X => self.x_target = target,
+            XYAxis::Y => self.y_target = target,
+        }
+    }
+
+    /// Move to a position, recording it if a recording is in progress
+    pub fn move_to(&mut self, now: Instant, x: f32, y: f32) {
+        self.position = (x.clamp(0.0, 1.0), y.clamp(0.0, 1.0));
+
+        if let Some((start, points)) = self.recording.as_mut() {
+            points.push(GesturePoint {
+                time: now.duration_since(*start).as_secs_f32(),
+                x: self.position.0,
+                y: self.position.1,
+            });
+        }
+    }
+
+    /// Return the parameter values of both axes at the current position
+    pub fn values(&self) -> [(XYTarget, i32); 2] {
+        [
+            (self.x_target, self.x_target.value_at(self.position.0)),
+            (self.y_target, self.y_target.value_at(self.position.1)),
+        ]
+    }
+
+    /// Return the next position of the spring-back, ending it at the centre
+    pub fn spring_step(&mut self) -> (f32, f32) {
+        let (x, y) = self.position;
+        let step = |v: f32| v + (CENTER - v) * SPRING_FACTOR;
+        let next = (step(x), step(y));
+
+        if (next.0 - CENTER).abs() < SPRING_SNAP && (next.1 - CENTER).abs() < SPRING_SNAP {
+            self.returning = false;
+            (CENTER, CENTER)
+        } else {
+            next
+        }
+    }
+
+    /// Return true if a recording is in progress
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Start a new recording
+    pub fn start_recording(&mut self, now: Instant) {
+        self.playback = None;
+        self.recording = Some((now, Vec::new()));
+    }
+
+    /// Stop the recording and keep the gesture
+    pub fn stop_recording(&mut self) {
+        if let Some((_, points)) = self.recording.take() {
+            self.gesture = points;
+        }
+    }
+
+    /// Return true if the gesture is long enough to be played back
+    pub fn can_play(&self) -> bool {
+        self.duration() > 0.0
+    }
+
+    /// Return true if the gesture is being played back
+    pub fn is_playing(&self) -> bool {
+        self.playback.is_some()
+    }
+
+    /// Start or stop the looping playback
+    pub fn set_playing(&mut self, now: Instant, playing: bool) {
+        self.playback = if playing && self.can_play() {
+            self.recording = None;
+            self.returning = false;
+            Some(now)
+        } else {
+            None
+        };
+    }
+
+    /// Return true if the pad needs animation ticks
+    pub fn is_animated(&self) -> bool {
+        self.returning || self.playback.is_some()
+    }
+
+    /// Return the playback position at a time, `None` if not playing
+    pub fn playback_position(&self, now: Instant) -> Option<(f32, f32)> {
+        let start = self.playback?;
+        let time = now.duration_since(start).as_secs_f32() % self.duration();
+
+        let next = self
+            .gesture
+            .iter()
+            .position(|point| point.time >= time)
+            .unwrap_or(self.gesture.len() - 1);
+        let to = self.gesture[next];
+        let from = self.gesture[next.saturating_sub(1)];
+
+        let fraction = if to.time > from.time {
+            (time - from.time) / (to.time - from.time)
+        } else {
+            1.0
+        };
+
+        Some((
+            from.x + (to.x - from.x) * fraction,
+            from.y + (to.y - from.y) * fraction,
+        ))
+    }
+
+    /// Return the length of the gesture in seconds
+    fn duration(&self) -> f32 {
+        self.gesture.last().map_or(0.0, |point| point.time)
+    }
+}